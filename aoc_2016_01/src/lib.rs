@@ -97,6 +97,14 @@ pub fn aoc_2016_01_b(input: &str) -> usize {
     unreachable!("There is no easter bunny");
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 1,
+    title: "No Time for a Taxicab",
+    a: aoc_2016_01_a,
+    b: aoc_2016_01_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -113,8 +121,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_01_a() {
-        assert_eq!(super::aoc_2016_01_a(INPUT), 234);
+        let input = aoc_utils::input::workspace(2016, 1);
+        aoc_utils::ledger::assert_answer(2016, 1, 'a', super::aoc_2016_01_a(&input));
     }
 
     #[rstest]
@@ -132,9 +142,9 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_01_b() {
-        assert_eq!(super::aoc_2016_01_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2016, 1);
+        assert_eq!(super::aoc_2016_01_b(&input), 0);
     }
-
-    const INPUT: &str = include_str!("input.txt");
 }
@@ -99,6 +99,14 @@ pub fn aoc_2016_02_b(input: &str) -> String {
     codes
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 2,
+    title: "Bathroom Security",
+    a: aoc_2016_02_a,
+    b: aoc_2016_02_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -117,8 +125,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_02_a() {
-        assert_eq!(super::aoc_2016_02_a(INPUT), "48584");
+        let input = aoc_utils::input::workspace(2016, 2);
+        aoc_utils::ledger::assert_answer(2016, 2, 'a', super::aoc_2016_02_a(&input));
     }
 
     #[rstest]
@@ -135,9 +145,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_02_b() {
-        assert_eq!(super::aoc_2016_02_b(INPUT), "");
+        let input = aoc_utils::input::workspace(2016, 2);
+        assert_eq!(super::aoc_2016_02_b(&input), "");
     }
 
-    const INPUT: &str = include_str!("input.txt");
 }
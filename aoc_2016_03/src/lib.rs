@@ -50,6 +50,14 @@ pub fn aoc_2016_03_b(input: &str) -> usize {
         .count()
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 3,
+    title: "Squares With Three Sides",
+    a: aoc_2016_03_a,
+    b: aoc_2016_03_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -62,8 +70,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_03_a() {
-        assert_eq!(super::aoc_2016_03_a(INPUT), 993);
+        let input = aoc_utils::input::workspace(2016, 3);
+        aoc_utils::ledger::assert_answer(2016, 3, 'a', super::aoc_2016_03_a(&input));
     }
 
     #[rstest]
@@ -88,9 +98,9 @@ mod tests {
         assert_eq!(super::aoc_2016_03_b(input), expected);
     }
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_03_b() {
-        assert_eq!(super::aoc_2016_03_b(INPUT), 1849);
+        let input = aoc_utils::input::workspace(2016, 3);
+        aoc_utils::ledger::assert_answer(2016, 3, 'b', super::aoc_2016_03_b(&input));
     }
-
-    const INPUT: &str = include_str!("input.txt");
 }
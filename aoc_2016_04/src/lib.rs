@@ -146,6 +146,14 @@ pub fn aoc_2016_04_b(input: &str) -> usize {
     }
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 4,
+    title: "Security Through Obscurity",
+    a: aoc_2016_04_a,
+    b: aoc_2016_04_b,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,8 +193,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_04_a() {
-        assert_eq!(super::aoc_2016_04_a(INPUT), 278221);
+        let input = aoc_utils::input::workspace(2016, 4);
+        aoc_utils::ledger::assert_answer(2016, 4, 'a', super::aoc_2016_04_a(&input));
     }
 
     #[rstest]
@@ -207,9 +217,9 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_04_b() {
-        assert_eq!(super::aoc_2016_04_b(INPUT), 267);
+        let input = aoc_utils::input::workspace(2016, 4);
+        aoc_utils::ledger::assert_answer(2016, 4, 'b', super::aoc_2016_04_b(&input));
     }
-
-    const INPUT: &str = include_str!("input.txt");
 }
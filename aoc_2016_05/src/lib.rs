@@ -71,6 +71,14 @@ pub fn aoc_2016_05_b(door_id: &str) -> String {
     String::from_utf8(pwd).expect("pwd should be a valid utf-8")
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 5,
+    title: "How About a Nice Game of Chess?",
+    a: aoc_2016_05_a,
+    b: aoc_2016_05_b,
+}
+
 #[cfg(test)]
 mod tests {
     // use rstest::rstest;
@@ -81,8 +89,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_05_a() {
-        assert_eq!(super::aoc_2016_05_a(INPUT), "2414bc77");
+        let input = aoc_utils::input::workspace(2016, 5);
+        aoc_utils::ledger::assert_answer(2016, 5, 'a', super::aoc_2016_05_a(&input));
     }
 
     #[test]
@@ -91,11 +101,11 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_05_b() {
-        assert_eq!(super::aoc_2016_05_b(INPUT), "437e60fc");
+        let input = aoc_utils::input::workspace(2016, 5);
+        aoc_utils::ledger::assert_answer(2016, 5, 'b', super::aoc_2016_05_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "abc";
 }
@@ -71,6 +71,14 @@ pub fn aoc_2016_06_b(input: &str) -> String {
     pwd
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 6,
+    title: "Signals and Noise",
+    a: aoc_2016_06_a,
+    b: aoc_2016_06_b,
+}
+
 #[cfg(test)]
 mod tests {
     // use rstest::rstest;
@@ -81,8 +89,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_06_a() {
-        assert_eq!(super::aoc_2016_06_a(INPUT), "gyvwpxaz");
+        let input = aoc_utils::input::workspace(2016, 6);
+        aoc_utils::ledger::assert_answer(2016, 6, 'a', super::aoc_2016_06_a(&input));
     }
 
     #[test]
@@ -91,12 +101,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_06_b() {
-        assert_eq!(super::aoc_2016_06_b(INPUT), "0");
+        let input = aoc_utils::input::workspace(2016, 6);
+        assert_eq!(super::aoc_2016_06_b(&input), "0");
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "eedadn
 drvtee
 eandsr
@@ -70,6 +70,14 @@ pub fn aoc_2016_07_b(input: &str) -> usize {
     input.trim().lines().filter(|&l| support_ssl(l)).count()
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 7,
+    title: "Internet Protocol Version 7",
+    a: aoc_2016_07_a,
+    b: aoc_2016_07_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -89,8 +97,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_07_a() {
-        assert_eq!(super::aoc_2016_07_a(INPUT), 115);
+        let input = aoc_utils::input::workspace(2016, 7);
+        aoc_utils::ledger::assert_answer(2016, 7, 'a', super::aoc_2016_07_a(&input));
     }
 
     /*
@@ -123,9 +133,9 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_07_b() {
-        assert_eq!(super::aoc_2016_07_b(INPUT), 231);
+        let input = aoc_utils::input::workspace(2016, 7);
+        aoc_utils::ledger::assert_answer(2016, 7, 'b', super::aoc_2016_07_b(&input));
     }
-
-    const INPUT: &str = include_str!("input.txt");
 }
@@ -3,6 +3,7 @@
     Solution idea:
 
 */
+use aoc_utils::ocr::{self, Framebuffer, OcrError};
 use regex::Regex;
 
 #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
@@ -172,8 +173,23 @@ pub fn aoc_2016_08_a(input: &str) -> usize {
     screen.count()
 }
 
-pub fn aoc_2016_08_b(_input: &str) -> usize {
-    0
+pub fn aoc_2016_08_b(input: &str) -> Result<String, OcrError> {
+    let commands = parse(input);
+    let mut screen = Screen::default();
+
+    for cmd in commands {
+        screen.execute(cmd);
+    }
+
+    ocr::read(&Framebuffer::new(&screen.buffer[0], screen.width))
+}
+
+aoc_utils::solution! {
+    year: 2016,
+    day: 8,
+    title: "Two-Factor Authentication",
+    a: aoc_2016_08_a,
+    b: aoc_2016_08_b,
 }
 
 #[cfg(test)]
@@ -265,20 +281,23 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_08_a() {
-        assert_eq!(super::aoc_2016_08_a(INPUT), 123);
+        let input = aoc_utils::input::workspace(2016, 8);
+        aoc_utils::ledger::assert_answer(2016, 8, 'a', super::aoc_2016_08_a(&input));
     }
 
-    #[test]
-    fn aoc_2016_08_b_example() {
-        assert_eq!(super::aoc_2016_08_b(""), 0);
+    #[rstest]
+    #[case("rect 4x1\nrotate column x=1 by 5\nrotate column x=2 by 5\nrotate column x=3 by 5\nrect 1x6", Ok("L".to_string()))]
+    #[case("", Err(OcrError::Empty))]
+    fn aoc_2016_08_b_example(#[case] input: &str, #[case] expected: Result<String, OcrError>) {
+        assert_eq!(super::aoc_2016_08_b(input), expected);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_08_b() {
-        // Display shows: AFBUPZBJPS
-        assert_eq!(super::aoc_2016_08_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2016, 8);
+        aoc_utils::ledger::assert_answer(2016, 8, 'b', super::aoc_2016_08_b(&input));
     }
-
-    const INPUT: &str = include_str!("input.txt");
 }
@@ -107,6 +107,14 @@ pub fn aoc_2016_09_b(input: &str) -> usize {
     get_decompressed_count(input, CompressionVersion::V2)
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 9,
+    title: "Explosives in Cyberspace",
+    a: aoc_2016_09_a,
+    b: aoc_2016_09_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -124,8 +132,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_09_a() {
-        assert_eq!(super::aoc_2016_09_a(INPUT), 112830);
+        let input = aoc_utils::input::workspace(2016, 9);
+        aoc_utils::ledger::assert_answer(2016, 9, 'a', super::aoc_2016_09_a(&input));
     }
 
     #[rstest]
@@ -143,9 +153,9 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_09_b() {
-        assert_eq!(super::aoc_2016_09_b(INPUT), 10931789799);
+        let input = aoc_utils::input::workspace(2016, 9);
+        aoc_utils::ledger::assert_answer(2016, 9, 'b', super::aoc_2016_09_b(&input));
     }
-
-    const INPUT: &str = include_str!("input.txt");
 }
@@ -28,7 +28,7 @@
     Output does not need to be stored (at least for a)
     => bot (low target, high target, low value?, high value?, [layer])
 */
-mod petgraph_solution;
+pub mod petgraph_solution;
 mod plain_solution;
 
 // #[cfg_attr(feature = "graph")]
@@ -46,19 +46,92 @@ pub fn aoc_2016_10_b(input: &str) -> usize {
     bots.get_part_b()
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 10,
+    title: "Balance Bots",
+    a: aoc_2016_10_a,
+    b: aoc_2016_10_b,
+}
+
 #[cfg(test)]
 mod tests {
     // use super::*;
+    #[cfg(feature = "graph")]
+    use aoc_utils::check::{Check, Rng, from_fn, shrink_lines};
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_10_a() {
-        assert_eq!(super::aoc_2016_10_a(INPUT), 98);
+        let input = aoc_utils::input::workspace(2016, 10);
+        aoc_utils::ledger::assert_answer(2016, 10, 'a', super::aoc_2016_10_a(&input));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_10_b() {
-        assert_eq!(super::aoc_2016_10_b(INPUT), 4042);
+        let input = aoc_utils::input::workspace(2016, 10);
+        aoc_utils::ledger::assert_answer(2016, 10, 'b', super::aoc_2016_10_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
+    #[cfg(feature = "graph")]
+    #[test]
+    fn petgraph_solution_should_agree_with_plain() {
+        let plain = |input: &String| {
+            let bots = super::plain_solution::distribute_chips(input);
+            (bots.bot_17_61, bots.get_part_b())
+        };
+        let petgraph = |input: &String| {
+            let chips = super::petgraph_solution::distribute_chips(input);
+            (chips.bot_17_61, chips.get_part_b())
+        };
+
+        Check::new(from_fn(network).shrink_with(shrink_lines))
+            .assert_agree(&[("plain", &plain), ("petgraph", &petgraph)]);
+    }
+
+    /// instructions for size bots in random order. Every bot gets two chips, from a value or
+    /// an earlier bot, and hands them on to later bots or outputs, every output gets one chip.
+    #[cfg(feature = "graph")]
+    fn network(rng: &mut Rng, size: usize) -> String {
+        let mut ids = (0..200).collect::<Vec<_>>();
+        rng.shuffle(&mut ids);
+        let mut values = (1..=100).collect::<Vec<_>>().into_iter();
+        rng.shuffle(values.as_mut_slice());
+
+        // free input slots of the bots in topological order
+        let mut free = vec![2; size];
+        let mut outputs = 0;
+        let mut lines = Vec::new();
+        for bot in 0..size {
+            let mut targets = Vec::new();
+            let mut chosen = Vec::new();
+            for _ in 0..2 {
+                let later = (bot + 1..size)
+                    .filter(|&b| free[b] > 0 && !chosen.contains(&b))
+                    .collect::<Vec<_>>();
+                if !later.is_empty() && rng.chance(0.7) {
+                    let b = *rng.pick(&later);
+                    free[b] -= 1;
+                    chosen.push(b);
+                    targets.push(format!("bot {}", ids[b]));
+                } else {
+                    targets.push(format!("output {outputs}"));
+                    outputs += 1;
+                }
+            }
+            lines.push(format!(
+                "bot {} gives low to {} and high to {}",
+                ids[bot], targets[0], targets[1]
+            ));
+        }
+        for (bot, free) in free.into_iter().enumerate() {
+            for value in values.by_ref().take(free) {
+                lines.push(format!("value {value} goes to bot {}", ids[bot]));
+            }
+        }
+
+        rng.shuffle(&mut lines);
+        lines.join("\n")
+    }
 }
@@ -1,10 +1,10 @@
 #![cfg(feature = "graph")]
-// little experiment how to do it in petgraph after we hat it for a view anyway
-use petgraph::dot::{Config, Dot};
-use petgraph::{Algo, Graph, Incoming};
-use regex::Regex;
+// little experiment how to do it in petgraph after we had it for a view anyway
+// chips flow along the topological order of the graph, no queue like in plain_solution
+use std::{collections::HashMap, fmt::Display};
 
-use crate::solution;
+use petgraph::{Graph, algo::toposort, dot::Dot, graph::NodeIndex, visit::EdgeRef};
+use regex::Regex;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Target {
@@ -18,6 +18,32 @@ enum Token {
     LowHigh(usize, Target, Target),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Node {
+    Value(usize),
+    Bot(usize),
+    Output(usize),
+}
+
+impl From<Target> for Node {
+    fn from(target: Target) -> Self {
+        match target {
+            Target::Output(o) => Node::Output(o),
+            Target::Bot(b) => Node::Bot(b),
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Value(v) => write!(f, "Val {v}"),
+            Node::Bot(b) => write!(f, "Bot {b}"),
+            Node::Output(o) => write!(f, "Output {o}"),
+        }
+    }
+}
+
 fn parse(input: &str) -> Vec<Token> {
     let value_rx = Regex::new(r"\s*value (?P<val>\d+) goes to bot (?P<bot>\d+)").unwrap();
     let lowhigh_rx =
@@ -26,8 +52,6 @@ fn parse(input: &str) -> Vec<Token> {
 
     let mut tokens = Vec::new();
     for line in input.lines() {
-        println!("{line}");
-
         if let Some(caps) = value_rx.captures(line) {
             let val = caps["val"].parse().expect("val should be numeric");
             let bot = caps["bot"].parse().expect("target bot should be numeric");
@@ -57,108 +81,112 @@ fn parse_target(to: &str, id_str: &str) -> Target {
 const HIGH: &str = "high";
 const LOW: &str = "low";
 
-fn to_graph(tokens: Vec<Token>) -> Graph<String, &'static str> {
+fn to_graph(tokens: &[Token]) -> Graph<Node, &'static str> {
     let mut gr = Graph::new();
 
     // remember the nodes
-    let mut values = vec![None; 255];
-    let mut bots = vec![None; 255];
-    let mut outputs = vec![None; 255];
+    let mut nodes = HashMap::new();
+    let mut node = |gr: &mut Graph<Node, &'static str>, n: Node| {
+        *nodes.entry(n).or_insert_with(|| gr.add_node(n))
+    };
 
     for t in tokens {
-        match t {
+        match *t {
             Token::Value(val, bot) => {
-                if values[val].is_none() {
-                    values[val] = Some(gr.add_node(format!("Val {val}")));
-                }
-                if bots[bot].is_none() {
-                    bots[bot] = Some(gr.add_node(format!("Bot {bot}")));
-                }
-                gr.add_edge(values[val].unwrap(), bots[bot].unwrap(), "");
+                let from = node(&mut gr, Node::Value(val));
+                let to = node(&mut gr, Node::Bot(bot));
+                gr.add_edge(from, to, "");
             }
             Token::LowHigh(bot, target_low, target_high) => {
-                if bots[bot].is_none() {
-                    bots[bot] = Some(gr.add_node(format!("Bot {bot}")));
-                }
-
-                match target_low {
-                    Target::Output(o) => {
-                        if outputs[o].is_none() {
-                            outputs[o] = Some(gr.add_node(format!("Output {o}")));
-                        }
-                        gr.add_edge(bots[bot].unwrap(), outputs[o].unwrap(), LOW);
-                    }
-                    Target::Bot(b) => {
-                        if bots[b].is_none() {
-                            bots[b] = Some(gr.add_node(format!("Bot {b}")));
-                        }
-                        gr.add_edge(bots[bot].unwrap(), bots[b].unwrap(), LOW);
-                    }
-                }
-
-                match target_high {
-                    Target::Output(o) => {
-                        if outputs[o].is_none() {
-                            outputs[o] = Some(gr.add_node(format!("Output {o}")));
-                        }
-                        gr.add_edge(bots[bot].unwrap(), outputs[o].unwrap(), HIGH);
-                    }
-                    Target::Bot(b) => {
-                        if bots[b].is_none() {
-                            bots[b] = Some(gr.add_node(format!("Bot {b}")));
-                        }
-                        gr.add_edge(bots[bot].unwrap(), bots[b].unwrap(), HIGH);
-                    }
-                }
+                let from = node(&mut gr, Node::Bot(bot));
+                let low = node(&mut gr, target_low.into());
+                let high = node(&mut gr, target_high.into());
+                gr.add_edge(from, low, LOW);
+                gr.add_edge(from, high, HIGH);
             }
         }
     }
 
-    // Output the tree to `graphviz` `DOT` format
-    // println!("{:?}", Dot::with_config(&gr, &[Config::EdgeNoLabel]));
-    println!("{:?}", Dot::new(&gr));
-
     gr
 }
 
-impl solution {
-    pub fn distribute_chips(input: &str) -> usize {
-        use petgraph::{
-            algo::toposort,
-            graph::Node,
-            visit::{NodeFiltered, Topo},
-        };
-
-        let g = to_graph(parse(input));
-        let topo = Topo::new(&g);
-
-        // start from value nodes
-        match toposort(g, None) {
-            Ok(order) => {
-                print!("Sorted: ");
-                for i in order {
-                    g.node_weight(i).map(|weight| {
-                        print!("{}, ", weight);
-                        weight
-                    });
+/// the bots and outputs in graphviz `DOT` format
+#[allow(dead_code)]
+pub fn to_dot(input: &str) -> String {
+    Dot::new(&to_graph(&parse(input))).to_string()
+}
+
+/// Chips after all bots handed them on
+#[derive(Debug, Default)]
+pub struct Chips {
+    // solution part a
+    pub bot_17_61: usize,
+    outputs: HashMap<usize, usize>,
+}
+
+impl Chips {
+    pub fn get_part_b(&self) -> usize {
+        (0..3).flat_map(|o| self.outputs.get(&o)).product()
+    }
+}
+
+pub fn distribute_chips(input: &str) -> Chips {
+    let g = to_graph(&parse(input));
+    let order = toposort(&g, None)
+        .unwrap_or_else(|cycle| panic!("chips go round in circles at {}", g[cycle.node_id()]));
+
+    let mut held: HashMap<NodeIndex, Vec<usize>> = HashMap::new();
+    let mut chips = Chips::default();
+    // every node comes after all nodes handing chips to it
+    for index in order {
+        match g[index] {
+            Node::Value(val) => {
+                for target in g.neighbors(index) {
+                    held.entry(target).or_default().push(val);
+                }
+            }
+            Node::Output(o) => {
+                if let Some(&chip) = held.get(&index).and_then(|h| h.last()) {
+                    chips.outputs.insert(o, chip);
                 }
             }
-            Err(err) => {
-                g.node_weight(err.node_id())
-                    .map(|weight| println!("Error graph has cycle at node {}", weight));
+            Node::Bot(bot) => {
+                let (low, high) = match held.get(&index).map(Vec::as_slice) {
+                    Some(&[a, b]) => (a.min(b), a.max(b)),
+                    Some(h) if h.len() > 2 => panic!("bot {bot} got more than 2 values: {h:?}"),
+                    // a single chip stays with the bot
+                    _ => continue,
+                };
+                if (low, high) == (17, 61) {
+                    chips.bot_17_61 = bot;
+                }
+
+                let targets = g.edges(index).map(|e| (e.target(), *e.weight()));
+                let targets = targets.collect::<Vec<_>>();
+                assert!(!targets.is_empty(), "bot {bot} has chips but no targets");
+                for (target, weight) in targets {
+                    let chip = if weight == LOW { low } else { high };
+                    held.entry(target).or_default().push(chip);
+                }
             }
         }
-        println!("");
-        0
     }
-}
 
+    chips
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
 
+    const EXAMPLE: &str = "value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2";
+
     #[rstest]
     #[case("value 5 goes to bot 2", Token::Value(5, 2))]
     #[case(
@@ -181,11 +209,17 @@ mod tests {
 
     #[test]
     fn get_graph() {
-        let tokens = parse(INPUT);
-        let gr = to_graph(tokens);
+        let gr = to_graph(&parse(EXAMPLE));
 
-        assert!(gr.node_count() > 0);
+        // 3 values, 3 bots, 3 outputs
+        assert_eq!(gr.node_count(), 9);
+        assert!(to_dot(EXAMPLE).contains("Output 2"));
+    }
 
-        panic!("show it")
+    #[test]
+    fn distribute_chips_should_fill_outputs() {
+        let sut = distribute_chips(EXAMPLE);
+        // output 0: 5, output 1: 2, output 2: 3
+        assert_eq!(sut.get_part_b(), 30);
     }
-}
\ No newline at end of file
+}
@@ -419,6 +419,13 @@ pub fn aoc_2016_11_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2016,
+    day: 11,
+    title: "Radioisotope Thermoelectric Generators",
+
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -540,8 +547,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_11_a() {
-        assert_eq!(super::aoc_2016_11_a(INPUT), 0);
+        let input = aoc_utils::input::workspace(2016, 11);
+        assert_eq!(super::aoc_2016_11_a(&input), 0);
     }
 
     #[rstest]
@@ -551,12 +560,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2016_11_b() {
-        assert_eq!(super::aoc_2016_11_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2016, 11);
+        assert_eq!(super::aoc_2016_11_b(&input), 0);
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
 The second floor contains a hydrogen generator.
 The third floor contains a lithium generator.
@@ -38,6 +38,14 @@ pub fn aoc_2020_01_b(input: &str) -> i32 {
     0
 }
 
+aoc_utils::solution! {
+    year: 2020,
+    day: 1,
+    title: "Report Repair",
+    a: aoc_2020_01_a,
+    b: aoc_2020_01_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -46,8 +54,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2020_01_a() {
-        assert_eq!(super::aoc_2020_01_a(INPUT), 1019371);
+        let input = aoc_utils::input::workspace(2020, 1);
+        aoc_utils::ledger::assert_answer(2020, 1, 'a', super::aoc_2020_01_a(&input));
     }
 
     #[test]
@@ -56,12 +66,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2020_01_b() {
-        assert_eq!(super::aoc_2020_01_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2020, 1);
+        assert_eq!(super::aoc_2020_01_b(&input), 0);
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     1721
     979
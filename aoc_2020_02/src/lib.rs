@@ -37,6 +37,13 @@ pub fn aoc_2020_02_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2020,
+    day: 2,
+    title: "Password Philosophy",
+    a: aoc_2020_02_a,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -45,8 +52,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2020_02_a() {
-        assert_eq!(super::aoc_2020_02_a(INPUT), 0);
+        let input = aoc_utils::input::workspace(2020, 2);
+        assert_eq!(super::aoc_2020_02_a(&input), 0);
     }
 
     #[test]
@@ -55,11 +64,11 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2020_02_b() {
-        assert_eq!(super::aoc_2020_02_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2020, 2);
+        assert_eq!(super::aoc_2020_02_b(&input), 0);
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "";
 }
@@ -32,6 +32,14 @@ fn parse_numbers(input: &str) -> Vec<i32>{
         .collect()
 }
 
+aoc_utils::solution! {
+    year: 2021,
+    day: 1,
+    title: "Sonar Sweep",
+    a: aoc_2021_01_a,
+    b: aoc_2021_01_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -40,8 +48,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_01_a() {
-       assert_eq!(super::aoc_2021_01_a(include_str!("input.txt")), 1400);
+        let input = aoc_utils::input::workspace(2021, 1);
+       aoc_utils::ledger::assert_answer(
+           2021,
+           1,
+           'a',
+           super::aoc_2021_01_a(&input),
+       );
     }
     
     #[test]
@@ -50,8 +65,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_01_b() {
-        assert_eq!(super::aoc_2021_01_b(include_str!("input.txt")), 1429);
+        let input = aoc_utils::input::workspace(2021, 1);
+        aoc_utils::ledger::assert_answer(
+            2021,
+            1,
+            'b',
+            super::aoc_2021_01_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "199
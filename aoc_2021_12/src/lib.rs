@@ -1,24 +1,25 @@
-use std::collections::HashMap;
+use aoc_utils::graph::Graph;
 
 /*
    Solution idea: modify recursive DFS. How to mark lowercase letters as visited? Just remember the path aka stack
    We are only interested in the count of different paths NOT in the length
 */
 // TODO: move this to a struct? Too many parameters
-fn bfs<'a>(
-    edges: &HashMap<&'a str, Vec<&'a str>>,
-    current: &'a str,
-    path: &mut Vec<&'a str>,
+fn bfs(
+    caves: &Graph,
+    current: usize,
+    path: &mut Vec<usize>,
     allow_twice_once: bool,
     mut has_seen_twice: bool,
 ) -> u32 {
-    if current == "end" {
-        // println!("{:?}", path);
+    let name = caves.label(current);
+    if name == "end" {
+        // println!("{:?}", caves.labels(path));
 
         return 1; // We reached end, count this, now unroll the stack
     }
 
-    if current.chars().all(|c| c.is_lowercase()) {
+    if name.chars().all(|c| c.is_lowercase()) {
         // start and all lowercase nodes
         if path.contains(&current) {
             // do not visit it twice
@@ -34,9 +35,10 @@ fn bfs<'a>(
 
     path.push(current);
     // dfs, visit every child node, act on the return
-    let num_successfull_paths = edges[current]
-        .iter()
-        .map(|child_node| bfs(edges, child_node, path, allow_twice_once, has_seen_twice))
+    let num_successfull_paths = caves
+        .neighbours(current)
+        .filter(|&child| caves.label(child) != "start") // never go back to start
+        .map(|child| bfs(caves, child, path, allow_twice_once, has_seen_twice))
         .sum(); // sum  1 for every successfull path, 0 for every ignored one
 
     path.pop();
@@ -45,19 +47,16 @@ fn bfs<'a>(
 }
 
 fn aoc_2021_12(input: &str, allow_twice_once: bool) -> u32 {
-    let mut edges = HashMap::new();
+    let mut caves = Graph::undirected();
     for line in input.trim().lines() {
         if let Some((from, to)) = line.trim().split_once("-") {
-            //    println!("{from} -> {to}");
-            edges.entry(from).or_insert(Vec::new()).push(to);
-            if from != "start" {// easier to model it bidirectional but never go back to start
-                edges.entry(to).or_insert(Vec::new()).push(from); 
-            }
+            caves.add_edge(from, to);
         }
     }
-    // println!("Edges {:?}", edges);
+    // println!("{}", caves.to_dot());
 
-    bfs(&edges, "start", &mut Vec::new(), allow_twice_once, false)
+    let start = caves.id("start").expect("no start cave");
+    bfs(&caves, start, &mut Vec::new(), allow_twice_once, false)
 }
 
 pub fn aoc_2021_12_a(input: &str) -> u32 {
@@ -68,6 +67,14 @@ pub fn aoc_2021_12_b(input: &str) -> u32 {
     aoc_2021_12(input, true)
 }
 
+aoc_utils::solution! {
+    year: 2021,
+    day: 12,
+    title: "Passage Pathing",
+    a: aoc_2021_12_a,
+    b: aoc_2021_12_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -81,8 +88,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_12_a() {
-        assert_eq!(super::aoc_2021_12_a(INPUT), 4413);
+        let input = aoc_utils::input::workspace(2021, 12);
+        aoc_utils::ledger::assert_answer(2021, 12, 'a', super::aoc_2021_12_a(&input));
     }
 
     #[test]
@@ -91,12 +100,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_12_b() {
-        assert_eq!(super::aoc_2021_12_b(INPUT), 118803);
+        let input = aoc_utils::input::workspace(2021, 12);
+        aoc_utils::ledger::assert_answer(2021, 12, 'b', super::aoc_2021_12_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     // 10 Paths
     const TEST_INPUT: &str = "
     start-A
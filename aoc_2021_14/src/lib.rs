@@ -153,6 +153,14 @@ pub fn aoc_2021_14_b(input: &str) -> i64 {
     aoc_2014_14_less_naive(input, 40) // naive busts with memory allocation error. who thought?
 }
 
+aoc_utils::solution! {
+    year: 2021,
+    day: 14,
+    title: "Extended Polymerization",
+    a: aoc_2021_14_a,
+    b: aoc_2021_14_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -161,8 +169,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_14_a() {
-        assert_eq!(super::aoc_2021_14_a(INPUT), 3009);
+        let input = aoc_utils::input::workspace(2021, 14);
+        aoc_utils::ledger::assert_answer(2021, 14, 'a', super::aoc_2021_14_a(&input));
     }
 
     #[test]
@@ -171,20 +181,22 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_14_b() {
-        assert_eq!(super::aoc_2021_14_b(INPUT), 3459822539451);
+        let input = aoc_utils::input::workspace(2021, 14);
+        aoc_utils::ledger::assert_answer(2021, 14, 'b', super::aoc_2021_14_b(&input));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn naive_vs_less_naive() {
+        let input = aoc_utils::input::workspace(2021, 14);
         assert_eq!(
-            super::aoc_2021_14_naive(INPUT, 10),
-            super::aoc_2014_14_less_naive(INPUT, 10)
+            super::aoc_2021_14_naive(&input, 10),
+            super::aoc_2014_14_less_naive(&input, 10)
         );
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     NNCB
 
@@ -115,6 +115,14 @@ pub fn aoc_2021_15_b(input: &str) -> i32 {
     a_star(grid)
 }
 
+aoc_utils::solution! {
+    year: 2021,
+    day: 15,
+    title: "Chiton",
+    a: aoc_2021_15_a,
+    b: aoc_2021_15_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -123,8 +131,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_15_a() {
-        assert_eq!(super::aoc_2021_15_a(INPUT), 741);
+        let input = aoc_utils::input::workspace(2021, 15);
+        aoc_utils::ledger::assert_answer(2021, 15, 'a', super::aoc_2021_15_a(&input));
     }
 
     #[test]
@@ -133,12 +143,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_15_b() {
-        assert_eq!(super::aoc_2021_15_b(INPUT), 2976);
+        let input = aoc_utils::input::workspace(2021, 15);
+        aoc_utils::ledger::assert_answer(2021, 15, 'b', super::aoc_2021_15_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     1163751742
     1381373672
@@ -40,6 +40,12 @@ pub fn aoc_2021_16_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2021,
+    day: 16,
+    title: "Packet Decoder",
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -48,8 +54,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_16_a() {
-        assert_eq!(super::aoc_2021_16_a(INPUT), 0);
+        let input = aoc_utils::input::workspace(2021, 16);
+        assert_eq!(super::aoc_2021_16_a(&input), 0);
     }
 
     #[test]
@@ -58,8 +66,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_16_b() {
-        assert_eq!(super::aoc_2021_16_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2021, 16);
+        assert_eq!(super::aoc_2021_16_b(&input), 0);
     }
 
     // #[test]
@@ -67,7 +77,5 @@ mod tests {
     //     assert_eq!(super::literal("D2FE28"), 2021)
     // }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "";
 }
@@ -18,7 +18,11 @@ pub fn aoc_2021_19_b(_input: &str) -> usize {
     0
 }
 
-
+aoc_utils::solution! {
+    year: 2021,
+    day: 19,
+    title: "Beacon Scanner",
+}
 
 #[cfg(test)]
 mod tests {
@@ -28,8 +32,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_19_a() {
-       assert_eq!(super::aoc_2021_19_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2021, 19);
+       assert_eq!(super::aoc_2021_19_a(&input), 0);
     }
     
     #[test]
@@ -38,8 +44,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2021_19_b() {
-        assert_eq!(super::aoc_2021_19_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2021, 19);
+        assert_eq!(super::aoc_2021_19_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "";
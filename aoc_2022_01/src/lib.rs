@@ -39,7 +39,13 @@ pub fn part_b(input: &str) -> u32 {
     sums.iter().take(3).sum()
 }
 
-
+aoc_utils::solution! {
+    year: 2022,
+    day: 1,
+    title: "Calorie Counting",
+    a: part_a,
+    b: part_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -71,8 +77,10 @@ mod tests {
 
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn part_a() {
-        assert_eq!(super::part_a(include_str!("input.txt")), 73211);
+        let input = aoc_utils::input::workspace(2022, 1);
+        aoc_utils::ledger::assert_answer(2022, 1, 'a', super::part_a(&input));
     }
 
     
@@ -84,8 +92,10 @@ mod tests {
 
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn part_b() {
-        assert_eq!(super::part_b(include_str!("input.txt")), 213958);
+        let input = aoc_utils::input::workspace(2022, 1);
+        aoc_utils::ledger::assert_answer(2022, 1, 'b', super::part_b(&input));
     }
 
 }
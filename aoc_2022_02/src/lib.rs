@@ -50,7 +50,13 @@ pub fn part_b(input: &str) -> u32 {
     score
 }
 
-
+aoc_utils::solution! {
+    year: 2022,
+    day: 2,
+    title: "Rock Paper Scissors",
+    a: part_a,
+    b: part_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -67,9 +73,11 @@ fn aoc_2022_02a_example() {
 
 
     #[test]
+#[ignore = "needs the puzzle input"]
 fn aoc_2022_02a() {
+    let input = aoc_utils::input::workspace(2022, 2);
 
-        assert_eq!(super::part_a(include_str!("input.txt")), 13809);
+        assert_eq!(super::part_a(&input), 13809);
     }
 
     
@@ -81,8 +89,10 @@ fn aoc_2022_02b_example() {
 
 
     #[test]
+#[ignore = "needs the puzzle input"]
 fn aoc_2022_02b() {
-        assert_eq!(super::part_b(include_str!("input.txt")), 12316);
+    let input = aoc_utils::input::workspace(2022, 2);
+        assert_eq!(super::part_b(&input), 12316);
     }
 
     const TEST_INPUT: &str = "A Y
@@ -60,6 +60,14 @@ pub fn part_b(input: &str) -> u32 {
     sum
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 3,
+    title: "Rucksack Reorganization",
+    a: part_a,
+    b: part_b,
+}
+
 #[cfg(test)]
 mod tests {
     // #[test]
@@ -74,8 +82,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_03a() {
-        assert_eq!(super::part_a(include_str!("input.txt")), 8401);
+        let input = aoc_utils::input::workspace(2022, 3);
+        assert_eq!(super::part_a(&input), 8401);
     }
 
     #[test]
@@ -85,8 +95,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_03b() {
-        assert_eq!(super::part_b(include_str!("input.txt")), 2641);
+        let input = aoc_utils::input::workspace(2022, 3);
+        assert_eq!(super::part_b(&input), 2641);
     }
 
     const TEST_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
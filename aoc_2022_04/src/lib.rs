@@ -55,6 +55,13 @@ fn to_tuple(s:&str) ->(u32,u32){
     }
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 4,
+    title: "Camp Cleanup",
+    a: aoc_2022_04_a,
+    b: aoc_2022_04_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -64,8 +71,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_04_a() {
-       assert_eq!(super::aoc_2022_04_a(include_str!("input.txt")), 573);
+        let input = aoc_utils::input::workspace(2022, 4);
+       aoc_utils::ledger::assert_answer(
+           2022,
+           4,
+           'a',
+           super::aoc_2022_04_a(&input),
+       );
     }
     
     #[test]
@@ -74,8 +88,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_04_b() {
-        assert_eq!(super::aoc_2022_04_b(include_str!("input.txt")), 867);
+        let input = aoc_utils::input::workspace(2022, 4);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            4,
+            'b',
+            super::aoc_2022_04_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "2-4,6-8
@@ -89,7 +89,13 @@ fn parse_input(input: &str ) -> (Vec<Vec<char>>, Vec<Cmd>){
     (crates, cmds)
 }
 
-
+aoc_utils::solution! {
+    year: 2022,
+    day: 5,
+    title: "Supply Stacks",
+    a: aoc_2022_05_a,
+    b: aoc_2022_05_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -99,8 +105,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_05_a() {
-       assert_eq!(super::aoc_2022_05_a(include_str!("input.txt")), "CVCWCRTVQ");
+        let input = aoc_utils::input::workspace(2022, 5);
+       aoc_utils::ledger::assert_answer(
+           2022,
+           5,
+           'a',
+           super::aoc_2022_05_a(&input),
+       );
     }
     
     #[test]
@@ -109,8 +122,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_05_b() {
-        assert_eq!(super::aoc_2022_05_b(include_str!("input.txt")), "CNSCZWLVT");
+        let input = aoc_utils::input::workspace(2022, 5);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            5,
+            'b',
+            super::aoc_2022_05_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "
@@ -41,6 +41,14 @@ fn find_distinct_window(input: &str, win_size:usize) -> Option<usize> {
     None
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 6,
+    title: "Tuning Trouble",
+    a: aoc_2022_06_a,
+    b: aoc_2022_06_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -49,8 +57,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_06_a() {
-       assert_eq!(super::aoc_2022_06_a(include_str!("input.txt")), 1356);
+        let input = aoc_utils::input::workspace(2022, 6);
+       aoc_utils::ledger::assert_answer(
+           2022,
+           6,
+           'a',
+           super::aoc_2022_06_a(&input),
+       );
     }
     
     #[test]
@@ -59,8 +74,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_06_b() {
-        assert_eq!(super::aoc_2022_06_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 6);
+        assert_eq!(super::aoc_2022_06_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
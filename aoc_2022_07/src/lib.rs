@@ -95,6 +95,14 @@ pub fn aoc_2022_07_b(input: &str) -> usize {
         .expect("a least one directory must be deleted")    
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 7,
+    title: "No Space Left On Device",
+    a: aoc_2022_07_a,
+    b: aoc_2022_07_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -103,8 +111,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_07_a() {
-        assert_eq!(super::aoc_2022_07_a(include_str!("input.txt")), 1513699);
+        let input = aoc_utils::input::workspace(2022, 7);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            7,
+            'a',
+            super::aoc_2022_07_a(&input),
+        );
     }
 
     #[test]
@@ -113,8 +128,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_07_b() {
-        assert_eq!(super::aoc_2022_07_b(include_str!("input.txt")), 7991939);
+        let input = aoc_utils::input::workspace(2022, 7);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            7,
+            'b',
+            super::aoc_2022_07_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "
@@ -128,6 +128,14 @@ fn parse_heightmap(input: &str) -> (Vec<u32>, usize, usize) {
     (heightmap, width, height)
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 8,
+    title: "Treetop Tree House",
+    a: aoc_2022_08_a,
+    b: aoc_2022_08_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -136,8 +144,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_08_a() {
-        assert_eq!(super::aoc_2022_08_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 8);
+        assert_eq!(super::aoc_2022_08_a(&input), 0);
     }
 
     #[test]
@@ -146,8 +156,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_08_b() {
-        assert_eq!(super::aoc_2022_08_b(include_str!("input.txt")), 209880);
+        let input = aoc_utils::input::workspace(2022, 8);
+        aoc_utils::ledger::assert_answer(2022, 8, 'b', super::aoc_2022_08_b(&input));
     }
 
     const TEST_INPUT: &str = "   30373
@@ -97,6 +97,14 @@ fn parse_moves(input: &str) -> Vec<(i32, i32)>{
     moves
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 9,
+    title: "Rope Bridge",
+    a: aoc_2022_09_a,
+    b: aoc_2022_09_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -105,8 +113,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_09_a() {
-       assert_eq!(super::aoc_2022_09_a(include_str!("input.txt")), 6181);
+        let input = aoc_utils::input::workspace(2022, 9);
+       aoc_utils::ledger::assert_answer(
+           2022,
+           9,
+           'a',
+           super::aoc_2022_09_a(&input),
+       );
     }
     
     #[test]
@@ -120,8 +135,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_09_b() {
-        assert_eq!(super::aoc_2022_09_b(include_str!("input.txt")), 2386);
+        let input = aoc_utils::input::workspace(2022, 9);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            9,
+            'b',
+            super::aoc_2022_09_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "R 4
@@ -5,6 +5,8 @@ use crossterm::{
 };
 use std::io::{stdout, Write};
 
+use aoc_utils::ocr::{self, OcrError};
+
 struct Cpu {
     x: i32,
     cycle: usize,
@@ -111,7 +113,25 @@ pub fn aoc_2022_10_a(input: &str) -> usize {
     cpu.blips.iter().map(|b| b.0 as usize * b.1).sum()
 }
 
-pub fn aoc_2022_10_b(input: &str) -> std::io::Result<()> {
+/// pixels of the crt, one line per row
+pub fn crt(input: &str) -> String {
+    let mut cpu = Cpu::new();
+    cpu.execute(input);
+
+    cpu.crt
+        .chunks(Cpu::COLS)
+        .map(|row| String::from_utf8_lossy(row))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// letters on the crt
+pub fn aoc_2022_10_b(input: &str) -> std::result::Result<String, OcrError> {
+    ocr::read_str(&crt(input))
+}
+
+/// paint the crt on the terminal
+pub fn draw(input: &str) -> std::io::Result<()> {
     let mut cpu = Cpu::new();
     cpu.execute(input);
 
@@ -119,6 +139,14 @@ pub fn aoc_2022_10_b(input: &str) -> std::io::Result<()> {
     cpu.draw_crt()
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 10,
+    title: "Cathode-Ray Tube",
+    a: aoc_2022_10_a,
+    b: aoc_2022_10_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -127,17 +155,30 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_10_a() {
-        assert_eq!(super::aoc_2022_10_a(include_str!("input.txt")), 17940);
+        let input = aoc_utils::input::workspace(2022, 10);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            10,
+            'a',
+            super::aoc_2022_10_a(&input),
+        );
     }
 
     #[test]
     fn aoc_2022_10_b_example() {
-        assert!(super::aoc_2022_10_b(TEST_INPUT).is_ok());
+        // the example draws stripes, no letters
+        assert!(matches!(
+            super::aoc_2022_10_b(TEST_INPUT),
+            Err(super::OcrError::Unknown { .. })
+        ));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_10_b() {
+        let input = aoc_utils::input::workspace(2022, 10);
         /* Print ZCBAJFJZ in 5x6 "Font"
         ####..##..###...##....##.####...##.####.
         ...#.#..#.#..#.#..#....#.#.......#....#.
@@ -146,7 +187,12 @@ mod tests {
         #....#..#.#..#.#..#.#..#.#....#..#.#....
         ####..##..###..#..#..##..#.....##..####.
         */
-        assert!(super::aoc_2022_10_b(include_str!("input.txt")).is_ok());
+        aoc_utils::ledger::assert_answer(
+            2022,
+            10,
+            'b',
+            super::aoc_2022_10_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "addx 15
@@ -147,6 +147,14 @@ pub fn aoc_2022_11_b(input: &str) -> u64 {
     counts[last] * counts[last-1]
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 11,
+    title: "Monkey in the Middle",
+    a: aoc_2022_11_a,
+    b: aoc_2022_11_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -155,8 +163,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_11_a() {
-        assert_eq!(super::aoc_2022_11_a(include_str!("input.txt")), 62491);
+        let input = aoc_utils::input::workspace(2022, 11);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            11,
+            'a',
+            super::aoc_2022_11_a(&input),
+        );
     }
 
     #[test]
@@ -165,8 +180,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_11_b() {
-        assert_eq!(super::aoc_2022_11_b(include_str!("input.txt")), 17408399184);
+        let input = aoc_utils::input::workspace(2022, 11);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            11,
+            'b',
+            super::aoc_2022_11_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "Monkey 0:
@@ -273,7 +273,13 @@ pub fn aoc_2022_12_b(input: &str) -> usize {
     shortest - 1
 }
 
-
+aoc_utils::solution! {
+    year: 2022,
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    a: aoc_2022_12_a,
+    b: aoc_2022_12_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -283,8 +289,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_12_a() {
-       assert_eq!(super::aoc_2022_12_a(include_str!("input.txt")), 534);
+        let input = aoc_utils::input::workspace(2022, 12);
+       aoc_utils::ledger::assert_answer(
+           2022,
+           12,
+           'a',
+           super::aoc_2022_12_a(&input),
+       );
     }
     
     #[test]
@@ -293,8 +306,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_12_b() {
-        assert_eq!(super::aoc_2022_12_b(include_str!("input.txt")), 525);
+        let input = aoc_utils::input::workspace(2022, 12);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            12,
+            'b',
+            super::aoc_2022_12_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = "
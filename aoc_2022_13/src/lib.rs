@@ -12,6 +12,8 @@ use nom::{
 };
 use regex::Regex;
 
+use aoc_utils::error::{AocError, Result};
+
 /* input is pairs of nested lists of lists or int. Possible structure tree of lists.
 Recursive solution? Seems legit JSON, can I exploit it? How does serde_json works?
 
@@ -114,48 +116,42 @@ impl PartialOrd for Package {
     }
 }
 
-
-fn compare_packages(left_str: &str, right_str: &str) -> Ordering {
-    let left = left_str.parse::<Package>().unwrap();
-    let right = right_str.parse::<Package>().unwrap();
-
-    let order = left.cmp(&right);
-
-    // println!("cmp({}, {}) => {:?}", left, right, order);
-    order
+/// the package on line, a slice of input
+fn package(input: &str, line: &str) -> Result<Package> {
+    let line = line.trim();
+    match list(line).finish() {
+        Ok(("", package)) => Ok(package),
+        _ => Err(AocError::parse_at(
+            input,
+            line,
+            "expected a package like [1,[2]]",
+        )),
+    }
 }
 
-pub fn aoc_2022_13_a(input: &str) -> usize {
+pub fn aoc_2022_13_a(input: &str) -> Result<usize> {
     let empty_line = Regex::new(r"(?m)^\s*$").unwrap();
-   
-    let pairs: Vec<_> = empty_line.split(input.trim())
-        .enumerate()
-        .collect();
 
-    // println!("{:?}", pairs);    
-    let orderings: Vec<_> = pairs.iter()
-        .map(|(i,pair)| match pair.trim().split_once("\n") {
-            Some((left, right)) => (i, compare_packages(left, right)),
-            None => (i, Ordering::Greater),
-        })
-        .collect();
-
-    orderings.iter()    
-        .map(|(i, ord)| match ord {
-            Ordering::Less | Ordering::Equal => *i+1,
-            Ordering::Greater => 0,
+    empty_line
+        .split(input.trim())
+        .enumerate()
+        .filter_map(|(i, pair)| pair.trim().split_once('\n').map(|(l, r)| (i, l, r)))
+        .map(|(i, left, right)| {
+            let in_order = package(input, left)? <= package(input, right)?;
+            Ok(if in_order { i + 1 } else { 0 })
         })
         .sum()
 }
 
-pub fn aoc_2022_13_b(input: &str) -> usize {
+pub fn aoc_2022_13_b(input: &str) -> Result<usize> {
     let divider: Vec<Package> = vec!["[[2]]","[[6]]"].iter()
     .filter_map(|l|  l.parse::<Package>().ok())
     .collect();    
 
     let mut orderings: Vec<Package> = input.lines()
-        .filter_map(|l|  l.parse::<Package>().ok())
-        .collect();
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| package(input, l))
+        .collect::<Result<_>>()?;
 
     orderings.append(&mut divider.clone());    
     orderings.sort_unstable();
@@ -164,10 +160,18 @@ pub fn aoc_2022_13_b(input: &str) -> usize {
     //     println!("{}: {:?}", i+1, o);
     // }
 
-   divider.iter()
+   Ok(divider.iter()
         .filter_map(|d| orderings.iter().position(|o| o == d)) 
         .map(|p| p + 1)
-        .product()
+        .product())
+}
+
+aoc_utils::solution! {
+    year: 2022,
+    day: 13,
+    title: "Distress Signal",
+    a: aoc_2022_13_a,
+    b: aoc_2022_13_b,
 }
 
 #[cfg(test)]
@@ -177,31 +181,56 @@ mod tests {
 
     use crate::Package;
 
+    fn compare_packages(left: &str, right: &str) -> Ordering {
+        let left = left.parse::<Package>().unwrap();
+        left.cmp(&right.parse().unwrap())
+    }
+
     #[test]
     fn aoc_2022_13_a_example() {
-        assert_eq!(super::aoc_2022_13_a(TEST_INPUT), 13);
+        assert_eq!(super::aoc_2022_13_a(TEST_INPUT), Ok(13));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_13_a() {
-        assert_eq!(super::aoc_2022_13_a(include_str!("input.txt")), 5506);
+        let input = aoc_utils::input::workspace(2022, 13);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            13,
+            'a',
+            super::aoc_2022_13_a(&input),
+        );
     }
 
     #[test]
     fn aoc_2022_13_b_example() {
-        assert_eq!(super::aoc_2022_13_b(TEST_INPUT), 140);
+        assert_eq!(super::aoc_2022_13_b(TEST_INPUT), Ok(140));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_13_b() {
-        assert_eq!(super::aoc_2022_13_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 13);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            13,
+            'b',
+            super::aoc_2022_13_b(&input),
+        );
+    }
+
+    #[test]
+    fn aoc_2022_13_a_should_locate_broken_package() {
+        let sut = super::aoc_2022_13_a("[1,2]\n[1,x]\n");
+        assert!(matches!(sut, Err(super::AocError::Parse { line: 2, .. })));
     }
 
     // ------------------------------ Unit tests ------------------------------
 
     #[test]
     fn empty_lists_should_equal() {
-        assert_eq!(super::compare_packages("[]", "[]"), 
+        assert_eq!(compare_packages("[]", "[]"), 
                     Ordering::Equal)
     }
     
@@ -209,7 +238,7 @@ mod tests {
     #[test]
     fn flat_list_should_less() {
         assert_eq!(
-            super::compare_packages("[1,1,3,1,1]", "[1,1,5,1,1]"),
+            compare_packages("[1,1,3,1,1]", "[1,1,5,1,1]"),
             Ordering::Less
         );
     }
@@ -217,34 +246,34 @@ mod tests {
     #[test]
     fn nested_list_should_less() {
         assert_eq!(
-            super::compare_packages(" [[1],[2,3,4]]","[[1],4]"),
+            compare_packages(" [[1],[2,3,4]]","[[1],4]"),
             Ordering::Less
         );
     }
 
     #[test]
     fn nested_list_should_less_2() {
-        assert_eq!(super::compare_packages("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[2]]"), Ordering::Less);
+        assert_eq!(compare_packages("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[2]]"), Ordering::Less);
     }
     
     #[test]
     fn nested_list_should_less_3() {
-        assert_eq!(super::compare_packages("[[1],[2,3,4]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]"), Ordering::Less);
+        assert_eq!(compare_packages("[[1],[2,3,4]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]"), Ordering::Less);
     }
     
     #[test]
     fn nested_list_should_less_4() {
-        assert_eq!(super::compare_packages("[[1],[2,3,4]]", "[[2]]"), Ordering::Less);
+        assert_eq!(compare_packages("[[1],[2,3,4]]", "[[2]]"), Ordering::Less);
     }
     
     #[test]
     fn nested_list_should_less_5() {
-        assert_eq!(super::compare_packages("[[8,7,6]]", "[3]"), Ordering::Greater);
+        assert_eq!(compare_packages("[[8,7,6]]", "[3]"), Ordering::Greater);
     }
     
     #[test]
     fn nested_list_should_less_6() {
-        assert_eq!(super::compare_packages("[[6]]", "[3]"), Ordering::Greater);
+        assert_eq!(compare_packages("[[6]]", "[3]"), Ordering::Greater);
     }
     
     #[test]
@@ -311,69 +340,3 @@ mod tests {
     [1,[2,[3,[4,[5,6,7]]]],8,9]
     [1,[2,[3,[4,[5,6,0]]]],8,9]";
 }
-
-
-// old try
-/*
-
-#[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq)]
-enum PackageSyntaxError {
-    Int(ParseIntError),
-    SyntaxError,
-}
-
-impl fmt::Display for PackageSyntaxError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            PackageSyntaxError::SyntaxError => write!(f, "invalid syntax for package"),
-            PackageSyntaxError::Int(ref e) => write!(f, "{}", e),
-        }
-    }
-}
-
-impl error::Error for PackageSyntaxError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match *self {
-            PackageSyntaxError::SyntaxError => None,
-            PackageSyntaxError::Int(ref e) => Some(e),
-        }
-    }
-}
-impl From<ParseIntError> for PackageSyntaxError {
-    fn from(e: ParseIntError) -> Self {
-        PackageSyntaxError::Int(e)
-    }
-}
-
-impl FromStr for Package {
-    type Err = PackageSyntaxError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        if s.starts_with("[") {
-
-            // we have a list
-            let stripped = s
-                .strip_prefix("[").unwrap()
-                .strip_suffix("]").unwrap();
-            if stripped.is_empty() {return Ok(Package::List(Vec::new()))}
-
-            let list = stripped
-                .split(",") // split will splitt inside of lists :-(
-                .map(|p| p.parse::<Package>().unwrap())
-                .collect();
-            Ok(Package::List(list))
-        } else {
-            // must be number only
-            // if let Some((num_str, _)) = s.split_once(|c: char| !c.is_ascii_digit()) {
-                Ok(Package::Number(s.parse()?))
-            // } else {
-            //     Err(PackageSyntaxError::SyntaxError)
-            // }
-        }
-    }
-}
-
-
-*/
\ No newline at end of file
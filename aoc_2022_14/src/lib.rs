@@ -106,6 +106,12 @@ pub fn aoc_2022_14_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 14,
+    title: "Regolith Reservoir",
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -114,8 +120,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_14_a() {
-        assert_eq!(super::aoc_2022_14_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 14);
+        assert_eq!(super::aoc_2022_14_a(&input), 0);
     }
 
     #[test]
@@ -124,8 +132,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_14_b() {
-        assert_eq!(super::aoc_2022_14_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 14);
+        assert_eq!(super::aoc_2022_14_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6
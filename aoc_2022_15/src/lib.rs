@@ -1,5 +1,6 @@
 use std::ops::Range;
 
+use aoc_utils::check::Rng;
 use regex::Regex;
 // use std::collections::HashMap;
 // use std::io::{stdout, Write};
@@ -201,7 +202,65 @@ pub fn aoc_2022_15_b(input: &str, min: i64, max: i64) -> i64 {
     gaps[0].0 + (gaps[0].1[0].end -1) * 4000000  // this is so unreadable :-(
 }
 
+/// sensors around a hidden beacon at a random point of 0..max x 0..max.
+/// Four sensors on the diagonals cover everything but the hidden point, the other ones are decoys
+/// inside the square that never reach it. Gives the input and the hidden point.
+pub fn sensor_field(seed: u64, sensors: usize, max: i64) -> (String, (i64, i64)) {
+    let mut rng = Rng::new(seed);
+    // part b scans the rows min..max, so keep the hidden point off the last one
+    let (px, py) = (rng.range(0..max), rng.range(0..max));
+    let line = |sx: i64, sy: i64, bx: i64, by: i64| {
+        format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+    };
+
+    // distance a + a from the hidden point, reaching one short of it
+    let a = 3 * max.max(1);
+    let mut lines = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .iter()
+        .map(|(dx, dy)| {
+            let (sx, sy) = (px + dx * a, py + dy * a);
+            line(sx, sy, sx - dx * (2 * a - 1), sy)
+        })
+        .collect::<Vec<_>>();
+
+    while lines.len() < sensors.max(4) {
+        let (sx, sy) = (rng.range(0..max + 1), rng.range(0..max + 1));
+        let to_hidden = distance(sx, sy, px, py);
+        if to_hidden < 2 {
+            continue;
+        }
+        let r = rng.range(1..(to_hidden - 1).min(max.max(1)) + 1);
+        let dx = rng.range(-r..r + 1);
+        let dy = if rng.chance(0.5) {
+            r - dx.abs()
+        } else {
+            dx.abs() - r
+        };
+        lines.push(line(sx, sy, sx + dx, sy + dy));
+    }
+    rng.shuffle(&mut lines);
+
+    (lines.concat(), (px, py))
+}
 
+/// scale sensors on a field of 100_000 x 100_000, smaller than the real one to keep the row scan short
+pub fn generate(seed: u64, scale: usize) -> (String, aoc_utils::Params) {
+    const MAX: i64 = 100_000;
+    let (input, (_, y)) = sensor_field(seed, scale, MAX);
+    let params = aoc_utils::Params::new().with("row", y).with("max", MAX);
+    (input, params)
+}
+
+aoc_utils::solution! {
+    year: 2022,
+    day: 15,
+    title: "Beacon Exclusion Zone",
+    a: |input, params| aoc_2022_15_a(input, params.get_or("row", 2_000_000)?),
+    b: |input, params| {
+        aoc_2022_15_b(input, params.get_or("min", 0)?, params.get_or("max", 4_000_000)?)
+    },
+    generate: generate,
+}
 
 #[cfg(test)]
 mod tests {
@@ -216,8 +275,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_15_a() {
-       assert_eq!(super::aoc_2022_15_a(include_str!("input.txt"), 2000000), 0); // 4626838 to low??
+        let input = aoc_utils::input::workspace(2022, 15);
+       assert_eq!(super::aoc_2022_15_a(&input, 2000000), 0); // 4626838 to low??
     }
     
     #[test]
@@ -226,13 +287,22 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_15_b() {
-        assert_eq!(super::aoc_2022_15_b(include_str!("input.txt"), 0, 4000000), 10908230916597);
+        let input = aoc_utils::input::workspace(2022, 15);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            15,
+            'b',
+            super::aoc_2022_15_b(&input, 0, 4000000),
+        );
     }
 
     // ------- Copy cat. Port of u/Metarineo python solution just to get the number. But still got the same number????
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn copy_cat(){
+        let input = aoc_utils::input::workspace(2022, 15);
 /*
         import re
         xPos = set()
@@ -251,7 +321,7 @@ mod tests {
         print("Def not beacon Pos: ", len(xPos))
         */
         const SENSOR_ROW:i64 = 2000000;
-        let data: Vec<SensorArea> = super::parse(include_str!("input.txt"));
+        let data: Vec<SensorArea> = super::parse(&input);
         let mut  x_pos = HashSet::with_capacity(2048);
         for d in data {
             let beacon_dist = (d.beacon.x - d.sensor.x).abs() + (d.beacon.y - d.sensor.y).abs();    
@@ -268,6 +338,15 @@ mod tests {
 }
 
     // ---------------- Unit Tests -------------
+    #[test]
+    fn sensor_field_should_hide_one_beacon() {
+        for seed in 0..5 {
+            let (input, (x, y)) = super::sensor_field(seed, 12, 20);
+            assert_eq!(input.lines().count(), 12);
+            assert_eq!(super::aoc_2022_15_b(&input, 0, 20), x * 4_000_000 + y);
+        }
+    }
+
     #[test]
     fn test_visible(){
         assert_eq!(visible(&SensorArea::new(8,7, 2,10), 10), 2..16);
@@ -301,8 +380,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn parse_should(){ // parse did not parse negative numbers :-(
-        assert_eq!(super::parse(include_str!("input.txt")), vec![
+        let input = aoc_utils::input::workspace(2022, 15);
+        assert_eq!(super::parse(&input), vec![
             SensorArea::new(9450, 2172986, -657934, 1258930),
             SensorArea::new(96708, 1131866, -657934, 1258930),
             SensorArea::new(1318282, 3917725, -39403, 3757521),
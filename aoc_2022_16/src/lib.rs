@@ -1,140 +1,186 @@
-use core::fmt;
 use std::collections::HashMap;
 
-
-use petgraph::prelude::*;
-use petgraph::dot::{Dot, Config};
+use aoc_utils::graph::{CompressedGraph, Graph};
 use regex::Regex;
 // #[allow(dead_code)]
 
+/*
+    Solution idea:
+    Most valves are stuck at rate 0, they are only corridors. Compress the tunnels to the valves
+    with rate > 0 (plus the start) with the distances between them.
+    Now DFS over the order in which valves are opened, moving and opening takes distance + 1 minutes.
+
+    b: The elephant and I open disjoint sets of valves. Remember the best pressure for every set
+    of opened valves in 26 minutes and combine the best pair of disjoint sets.
+*/
 
 #[derive(Debug, Clone)]
-struct Node {
-    label: String,
-    rate: u32,
-    open: bool,
-    visited: bool,
+struct Valve<'a> {
+    label: &'a str,
+    rate: u64,
+    tunnels: Vec<&'a str>,
 }
 
-impl Node {
-    fn new(label: &str, rate: u32) -> Self { Self { label: label.to_string(), rate, open: false, visited: false } }
+fn parse(input: &str) -> Vec<Valve<'_>> {
+    let rx =
+        Regex::new(r"Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.+)").unwrap();
+    rx.captures_iter(input)
+        .map(|caps| Valve {
+            label: caps.get(1).unwrap().as_str(),
+            rate: caps.get(2).unwrap().as_str().parse().unwrap(),
+            tunnels: caps.get(3).unwrap().as_str().trim().split(", ").collect(),
+        })
+        .collect()
 }
 
-impl fmt::Display for Node {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("{} {} {}", 
-            self.label, 
-            self.rate, 
-            if self.open {"| |"} else {">+<"}))
-    }
+#[derive(Debug)]
+struct Network<'a> {
+    valves: CompressedGraph<&'a str>,
+    /// rate per id of the compressed graph
+    rates: Vec<u64>,
+    start: usize,
 }
 
-pub fn aoc_2022_16_a(input: &str) -> usize {
-    // I know how to implement a graph, I want to know how to use petgraph
-    let mut graph = Graph::<Node, u16>::new(); // u16 has Display, () not
-    let mut map = HashMap::new(); // temporary storage for index and label
-    let rx = Regex::new(r"Valve (.+) has flow rate=(\d+); tunnels lead to valves (.+)").unwrap();
-    for caps in rx.captures_iter(input) {
-        let label = caps.get(1).unwrap().as_str(); 
-        let rate = caps.get(2).unwrap().as_str().parse().unwrap(); 
-        let index = *map.entry(label).or_insert_with(|| 
-            graph.add_node(Node::new(label, rate))
-        );
-        if let Some(n) = graph.node_weight_mut(index) {
-            n.rate = rate; // update rate if already inserted via edge
-        }
+impl<'a> Network<'a> {
+    fn new(input: &'a str) -> Self {
+        let valves = parse(input);
 
-        for t in caps.get(3).unwrap().as_str().split(", ")  {
-            let target = *map.entry(t).or_insert_with(|| 
-                graph.add_node(Node::new(t,0))
-            );
-            graph.add_edge(index, target, 0);
+        let mut tunnels = Graph::directed();
+        for valve in &valves {
+            for t in &valve.tunnels {
+                tunnels.add_edge(valve.label, t);
+            }
         }
-    };
-
-    // let mut dfs = Dfs::new(&graph, start);
-    // while let Some(nx) = dfs.next(&graph) {
-    //     if graph[nx].rate > 0 {
-    //         graph[nx].open = true;
-    //     }
-    // }
-    // DFS  is nice but we need to be in  control of the stack
-    let max_minutes = 30;
-    let mut flow = 0;
-    let mut max_flow = 0;
-    let mut path = Vec::with_capacity(max_minutes);
-    // let mut max_path: = Vec::new();    
-    let mut opened = 0;
-    let mut stack = vec![map[&"AA"].clone(); max_minutes];
-
-    while let Some(current) = stack.pop() {
-        path.push(current.clone());
-        if graph[current].rate > 0 {
-            opened += 1;
-            flow += graph[current].rate;
-        }  
-
-        if flow > max_flow {
-            max_flow = flow;
-            // max_path = path.clone();
-            //println!("Flow {} Path {}", max_flow, path.iter().map(|i| graph[i].label).collect::<Vec<_>>().join(", "));
+        // println!("{}", tunnels.to_dot());
+
+        let interesting = valves
+            .iter()
+            .filter(|v| v.rate > 0 || v.label == "AA")
+            .map(|v| v.label);
+        let compressed = CompressedGraph::new(interesting, |v| {
+            tunnels
+                .successors(v)
+                .into_iter()
+                .map(|t| (t, 1))
+                .collect::<Vec<_>>()
+        });
+
+        let rate_of = valves
+            .iter()
+            .map(|v| (v.label, v.rate))
+            .collect::<HashMap<_, _>>();
+        let rates = compressed.nodes.iter().map(|v| rate_of[v]).collect();
+        let start = compressed.id(&"AA").expect("no valve AA");
+
+        Self {
+            valves: compressed,
+            rates,
+            start,
         }
+    }
 
-        if !graph[current].visited {
-            graph[current].visited = true;
+    /// best pressure released for every set of opened valves (bitmask of compressed ids)
+    fn best_per_opened(&self, minutes: u64) -> HashMap<u64, u64> {
+        let mut best = HashMap::new();
+        self.explore(self.start, minutes, 0, 0, &mut best);
+        best
+    }
 
-            // only go further if we have time left
-            if path.len() + opened < max_minutes{
-                for n in graph.neighbors(current) {
-                    stack.push(n);
-                }
-            } 
+    fn explore(
+        &self,
+        current: usize,
+        time_left: u64,
+        opened: u64,
+        released: u64,
+        best: &mut HashMap<u64, u64>,
+    ) {
+        let entry = best.entry(opened).or_default();
+        *entry = released.max(*entry);
+
+        for next in 0..self.rates.len() {
+            if self.rates[next] == 0 || opened & (1 << next) != 0 {
+                continue;
+            }
+            // move there and open it
+            let Some(distance) = self.valves.distances.distance(current, next) else {
+                continue;
+            };
+            if distance + 1 >= time_left {
+                continue;
+            }
+            let remaining = time_left - distance - 1;
+            self.explore(
+                next,
+                remaining,
+                opened | 1 << next,
+                released + remaining * self.rates[next],
+                best,
+            );
         }
     }
-    
-
-
+}
 
-    println!("{}", Dot::with_attr_getters(&graph, &[Config::EdgeNoLabel],
-        &|_, _| String::new(),
-        &|_, (_, n)| String::from(if n.visited {"style=filled fillcolor=\"#6891fc\" fontcolor=\"white\""} else {""})
-    ));
+pub fn aoc_2022_16_a(input: &str) -> u64 {
+    let network = Network::new(input);
 
-    // idea: do a BFS, restrict paths to length 30 - #Valves
-    
-    0
+    network
+        .best_per_opened(30)
+        .into_values()
+        .max()
+        .unwrap_or_default()
 }
 
-pub fn aoc_2022_16_b(_input: &str) -> usize {
-    0
+pub fn aoc_2022_16_b(input: &str) -> u64 {
+    let network = Network::new(input);
+    let best = network.best_per_opened(26).into_iter().collect::<Vec<_>>();
+
+    // me and the elephant, never open a valve twice
+    let mut max = 0;
+    for (i, &(mine, my_pressure)) in best.iter().enumerate() {
+        for &(elephants, elephant_pressure) in &best[i..] {
+            if mine & elephants == 0 {
+                max = max.max(my_pressure + elephant_pressure);
+            }
+        }
+    }
+    max
 }
 
-
+aoc_utils::solution! {
+    year: 2022,
+    day: 16,
+    title: "Proboscidea Volcanium",
+    a: aoc_2022_16_a,
+    b: aoc_2022_16_b,
+}
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn aoc_2022_16_a_example() {
-        assert_eq!(super::aoc_2022_16_a(TEST_INPUT), 0);
+        assert_eq!(super::aoc_2022_16_a(TEST_INPUT), 1651);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_16_a() {
-       assert_eq!(super::aoc_2022_16_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 16);
+        aoc_utils::ledger::assert_answer(2022, 16, 'a', super::aoc_2022_16_a(&input));
     }
-    
+
     #[test]
     fn aoc_2022_16_b_example() {
-        assert_eq!(super::aoc_2022_16_b(TEST_INPUT), 0);
+        assert_eq!(super::aoc_2022_16_b(TEST_INPUT), 1707);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_16_b() {
-        assert_eq!(super::aoc_2022_16_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 16);
+        aoc_utils::ledger::assert_answer(2022, 16, 'b', super::aoc_2022_16_b(&input));
     }
 
-    const TEST_INPUT: &str = 
-    "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    const TEST_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
     Valve BB has flow rate=13; tunnels lead to valves CC, AA
     Valve CC has flow rate=2; tunnels lead to valves DD, BB
     Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
@@ -145,6 +191,3 @@ mod tests {
     Valve II has flow rate=0; tunnels lead to valves AA, JJ
     Valve JJ has flow rate=21; tunnel leads to valve II";
 }
-
-
-
@@ -5,6 +5,8 @@ use crossterm::{
 };
 use std::io::{stdout, Write};
 
+use aoc_utils::cycle::CycleDetector;
+
 
 const LEFT_WALL:u8 =  0b01000000; 
 const RIGHT_WALL:u8 = 0b00000001; 
@@ -44,118 +46,150 @@ fn draw_chimney(chimmney: &Vec<u8>, _rock: &Vec<u8>, y_pos: usize) -> Result<()>
     Ok(())
 }
 
-pub fn aoc_2022_17_a(input: &str) -> usize {
-    // tiles are stored lowest line first
-    let tile_defs :Vec<Vec<u8>>= vec![
-        vec![0b00011110,],
-        vec![0b00001000, 
-             0b00011100,
-             0b00001000,],
-        vec![0b00011100,
-             0b00000100,
-             0b00000100,], 
-        vec![0b00010000,
-             0b00010000,
-             0b00010000,
-             0b00010000,],
-        vec![0b00011000,
-             0b00011000,]     
-    ];
-    
+// tiles are stored lowest line first
+const TILES: [&[u8]; 5] = [
+    &[0b00011110,],
+    &[0b00001000, 
+      0b00011100,
+      0b00001000,],
+    &[0b00011100,
+      0b00000100,
+      0b00000100,], 
+    &[0b00010000,
+      0b00010000,
+      0b00010000,
+      0b00010000,],
+    &[0b00011000,
+      0b00011000,]     
+];
+
+/// rows of the chimney used to recognize a repeating situation
+const FINGERPRINT_ROWS: usize = 32;
+
+struct Chamber<'a> {
+    jets: &'a [u8],
+    current_jet: usize,
+    tile_index: usize,
+    chimney: Vec<u8>,
+    highest: usize,
+}
 
-    let jets = input.trim().as_bytes();
-    let mut current_jet = 0;
-    let mut tile_index = 0;
-    
-    let mut chimney = vec![0u8; 4096];
-    chimney[0] = 0b01111111; // Start with floor as guardian
-    let mut highest = 0;
+impl<'a> Chamber<'a> {
+    fn new(input: &'a str) -> Self {
+        let mut chimney = vec![0u8; 4096];
+        chimney[0] = 0b01111111; // Start with floor as guardian
+
+        Self {
+            jets: input.trim().as_bytes(),
+            current_jet: 0,
+            tile_index: 0,
+            chimney,
+            highest: 0,
+        }
+    }
 
-    for _rock in 0..2022 { // TODO: check for 1 off
-        let mut tile :Vec<u8> = tile_defs[tile_index].clone(); 
-        tile_index += 1;
-        if tile_index >= tile_defs.len() {tile_index = 0;}
+    /// does tile collide with rocks or floor if its lowest line is at index y
+    fn collides(&self, tile: &[u8], y: usize) -> bool {
+        tile.iter().enumerate().any(|(i, line)| self.chimney[y + i] & line != 0)
+    }
+
+    fn drop_rock(&mut self) {
+        let mut tile :Vec<u8> = TILES[self.tile_index].to_vec(); 
+        self.tile_index += 1;
+        if self.tile_index >= TILES.len() {self.tile_index = 0;}
+
+        // room for 3 space above + tile
+        if self.chimney.len() < self.highest + 16 {
+            self.chimney.resize(self.chimney.len() * 2, 0);
+        }
 
         // lowest line of tile is index y
-        let mut y = highest + 3 +1 +1; // 3 space above + 1 start + 1 decrease at beginning  TODO: check for 1 off  ✔️
+        let mut y = self.highest + 3 +1 +1; // 3 space above + 1 start + 1 decrease at beginning  TODO: check for 1 off  ✔️
 
         // rock falling down
         let mut falling = true;
         while falling {
             y -= 1;
 
-            // draw_chimney(&chimney, &tile, y).unwrap_or_default();
+            // draw_chimney(&self.chimney, &tile, y).unwrap_or_default();
 
             // shift by jet
-            if jets[current_jet] == b'>'{ // shift right
+            let shifted = if self.jets[self.current_jet] == b'>'{ // shift right
                 // test for wall collisions
-                if tile.iter().all(|line| line & RIGHT_WALL == 0) {
-                    // move all bits right
-                    for line in tile.iter_mut(){
-                        *line >>= 1; 
-                    }
-                }
-                // print!(">");   
-            } else if jets[current_jet] == b'<'{ // shift left
+                tile.iter().all(|line| line & RIGHT_WALL == 0)
+                    .then(|| tile.iter().map(|line| line >> 1).collect::<Vec<_>>())
+            } else { // shift left
                 // test for wall collisions
-                if tile.iter().all(|line| line & LEFT_WALL == 0) {
-                    // move all bits right
-                    for line in tile.iter_mut(){
-                        *line <<= 1; 
-                    }
-                }
-                // print!("<");   
-            } 
-            // println!("shift:{} \ttile: {}", jets[current_jet] as char, &tile.iter().map(|l| format!("{l:08b}")).collect::<Vec<_>>().join(", "));          
-            current_jet += 1;
-            if current_jet >= jets.len() {current_jet = 0;}
+                tile.iter().all(|line| line & LEFT_WALL == 0)
+                    .then(|| tile.iter().map(|line| line << 1).collect::<Vec<_>>())
+            };
+            // rocks at rest block the shift too
+            if let Some(shifted) = shifted && !self.collides(&shifted, y) {
+                tile = shifted;
+            }
+            // println!("shift:{} \ttile: {}", self.jets[self.current_jet] as char, &tile.iter().map(|l| format!("{l:08b}")).collect::<Vec<_>>().join(", "));          
+            self.current_jet += 1;
+            if self.current_jet >= self.jets.len() {self.current_jet = 0;}
 
             // fall down
-            // do we have a collison in next line? start test with lowest line
-            for (i, line) in tile.iter().enumerate(){
-                let index = y + i -1; // check for 1 off ✔️
-
-                // println!("y: {} index: {} chimney: {:07b} i: {} line: {:07b}", y, index, chimney[index],  i, line);          
-
-                if chimney[index] & line != 0 { 
-                    falling = false; 
-                    break;
-                }
+            // do we have a collison in next line? check for 1 off ✔️
+            if self.collides(&tile, y - 1) {
+                falling = false; 
             }
-            // print!("{}", if falling {"v"} else {"#\n"})
-            // println!("h:{} y:{} fall:{} \tchimney: {} tile: {}", highest, y, falling, 
-            //     &chimney[..5].into_iter().map(|l| format!("{l:#08b}")).collect::<Vec<_>>().join(", ")
-            //     &tile.into_iter().map(|l| format!("{l:#08b}")).collect::<Vec<_>>().join(", "));
         }
 
         // rest
         for(i, line) in tile.iter().enumerate(){
             let index = y + i; // check for 1 off ✔️
 
-            chimney[index] |= line; 
+            self.chimney[index] |= line; 
         }
         // correct state
-        while chimney[highest+1] != 0 {
-            highest += 1;
+        while self.chimney[self.highest+1] != 0 {
+            self.highest += 1;
         }
 
-                
-        println!("h:{}\tchimney: {}", highest, &chimney[..20].into_iter().map(|l| format!("{l:07b}")).collect::<Vec<_>>().join(", "));
+        // println!("h:{}\tchimney: {}", self.highest, &self.chimney[..20].iter().map(|l| format!("{l:07b}")).collect::<Vec<_>>().join(", "));
+    }
 
-        // println!("h:{} y:{} fall:{} \tchimney: {} tile: {}", highest, y, falling, 
-        //     &chimney[..10].into_iter().map(|l| format!("{l:08b}")).collect::<Vec<_>>().join(", "),
-        //     &tile.iter().map(|l| format!("{l:08b}")).collect::<Vec<_>>().join(", "));
-        // print!("{:04}, ", highest);
+    /// everything which decides how the next rocks fall: next tile, next jet and the surface
+    fn fingerprint(&self) -> (usize, usize, Vec<u8>) {
+        let bottom = (self.highest + 1).saturating_sub(FINGERPRINT_ROWS);
+        (self.tile_index, self.current_jet, self.chimney[bottom..=self.highest].to_vec())
     }
-    
-    highest
 }
 
-pub fn aoc_2022_17_b(_input: &str) -> usize {
-    0
+pub fn aoc_2022_17_a(input: &str) -> usize {
+    let mut chamber = Chamber::new(input);
+
+    for _rock in 0..2022 { 
+        chamber.drop_rock();
+    }
+    
+    chamber.highest
 }
 
+/// Tower grows periodic after a while. Find the cycle and skip whole cycles.
+pub fn aoc_2022_17_b(input: &str) -> usize {
+    let mut chamber = Chamber::new(input);
+    let mut detector = CycleDetector::new();
+
+    loop {
+        // state after n rocks is pushed as step n
+        if let Some(cycle) = detector.push(chamber.fingerprint(), chamber.highest) {
+            return cycle.nth_value(1_000_000_000_000, |&h| h as i64) as usize;
+        }
+        chamber.drop_rock();
+    }
+}
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 17,
+    title: "Pyroclastic Flow",
+    a: aoc_2022_17_a,
+    b: aoc_2022_17_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -165,18 +199,32 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_17_a() {
-       assert_eq!(super::aoc_2022_17_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 17);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            17,
+            'a',
+            super::aoc_2022_17_a(&input),
+        );
     }
     
     #[test]
     fn aoc_2022_17_b_example() {
-        assert_eq!(super::aoc_2022_17_b(TEST_INPUT), 0);
+        assert_eq!(super::aoc_2022_17_b(TEST_INPUT), 1514285714288);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_17_b() {
-        assert_eq!(super::aoc_2022_17_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 17);
+        aoc_utils::ledger::assert_answer(
+            2022,
+            17,
+            'b',
+            super::aoc_2022_17_b(&input),
+        );
     }
 
     const TEST_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
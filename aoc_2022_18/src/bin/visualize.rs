@@ -1,4 +1,4 @@
-use aoc_2022_18::{parse_voxel, Voxel, INPUT};
+use aoc_2022_18::{parse_voxel, Voxel};
 use bevy::pbr::CascadeShadowConfigBuilder;
 use bevy::prelude::*;
 use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
@@ -69,7 +69,8 @@ fn setup(
     // Voxel
     let cube = meshes.add(Cuboid::new(1.0, 1.0, 1.0));
     let hsla = Hsla::new(24.0, 1.0, 0.5, 0.5);
-    let voxels = parse_voxel(INPUT);
+    let input = aoc_utils::input::workspace(2022, 18);
+    let voxels = parse_voxel(&input);
 
     for Voxel { x, y, z } in voxels {
         commands.spawn(PbrBundle {
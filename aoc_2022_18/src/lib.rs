@@ -189,7 +189,13 @@ pub fn aoc_2022_18_b(input: &str) -> usize {
     count_sides(input, 64)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2022,
+    day: 18,
+    title: "Boiling Boulders",
+    a: aoc_2022_18_a,
+    b: aoc_2022_18_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -199,8 +205,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_18_a() {
-        assert_eq!(super::aoc_2022_18_a(super::INPUT), 4482);
+        let input = aoc_utils::input::workspace(2022, 18);
+        aoc_utils::ledger::assert_answer(2022, 18, 'a', super::aoc_2022_18_a(&input));
     }
 
     #[test]
@@ -209,14 +217,18 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_18_b() {
-        assert_eq!(super::aoc_2022_18_b(super::INPUT), 2576);
+        let input = aoc_utils::input::workspace(2022, 18);
+        aoc_utils::ledger::assert_answer(2022, 18, 'b', super::aoc_2022_18_b(&input));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn max_coord() {
+        let input = aoc_utils::input::workspace(2022, 18);
         assert_eq!(
-            include_str!("input.txt")
+            input
                 .split(",")
                 .filter_map(|s| s.trim().parse::<i16>().ok())
                 .max(),
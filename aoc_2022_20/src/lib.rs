@@ -81,6 +81,13 @@ pub fn aoc_2022_20_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 20,
+    title: "Grove Positioning System",
+    a: aoc_2022_20_a,
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -91,8 +98,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_20_a() {
-        assert_eq!(super::aoc_2022_20_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 20);
+        assert_eq!(super::aoc_2022_20_a(&input), 0);
     }
 
     #[test]
@@ -101,8 +110,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_20_b() {
-        assert_eq!(super::aoc_2022_20_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 20);
+        assert_eq!(super::aoc_2022_20_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "1
@@ -115,8 +126,9 @@ mod tests {
 
     // ------------------------ Unit Tests ------------------------------------------
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn input_has_doubles() {
-        let input = include_str!("input.txt");
+        let input = aoc_utils::input::workspace(2022, 20);
         assert_ne!(
             input
                 .trim()
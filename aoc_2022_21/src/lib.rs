@@ -183,6 +183,14 @@ pub fn aoc_2022_21_b(input: &str) -> i64 {
     eval("root", &inverter)
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 21,
+    title: "Monkey Math",
+    a: aoc_2022_21_a,
+    b: aoc_2022_21_b,
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -195,8 +203,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_21_a() {
-        assert_eq!(super::aoc_2022_21_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 21);
+        assert_eq!(super::aoc_2022_21_a(&input), 0);
     }
 
     #[test]
@@ -205,8 +215,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_21_b() {
-        assert_eq!(super::aoc_2022_21_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 21);
+        assert_eq!(super::aoc_2022_21_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "
@@ -252,8 +264,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn humn_is_used_only_once() {
-        let ast = super::parse(include_str!("input.txt"), true);
+        let input = aoc_utils::input::workspace(2022, 21);
+        let ast = super::parse(&input, true);
 
         let humn_ops = find_ops_on(&ast, "humn");
 
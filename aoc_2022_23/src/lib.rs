@@ -109,7 +109,12 @@ pub fn aoc_2022_23_b(_input: &str) -> usize {
     0
 }
 
-
+// left out of the registries, there is no part yet and a only opens a window
+aoc_utils::solution! {
+    year: 2022,
+    day: 23,
+    title: "Unstable Diffusion",
+}
 
 #[cfg(test)]
 mod tests {
@@ -119,8 +124,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_23_a() {
-       assert_eq!(super::aoc_2022_23_a(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 23);
+       assert_eq!(super::aoc_2022_23_a(&input), 0);
     }
     
     #[test]
@@ -129,8 +136,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_23_b() {
-        assert_eq!(super::aoc_2022_23_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 23);
+        assert_eq!(super::aoc_2022_23_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "
@@ -259,6 +259,13 @@ pub fn aoc_2022_24_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2022,
+    day: 24,
+    title: "Blizzard Basin",
+    a: aoc_2022_24_a,
+}
+
 #[cfg(test)]
 mod tests {
     // use test_case::test_case;
@@ -270,8 +277,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_24_a() {
-        assert_eq!(super::aoc_2022_24_a(include_str!("input.txt")), 18);
+        let input = aoc_utils::input::workspace(2022, 24);
+        aoc_utils::ledger::assert_answer(2022, 24, 'a', super::aoc_2022_24_a(&input));
     }
 
     #[test]
@@ -280,8 +289,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2022_24_b() {
-        assert_eq!(super::aoc_2022_24_b(include_str!("input.txt")), 0);
+        let input = aoc_utils::input::workspace(2022, 24);
+        assert_eq!(super::aoc_2022_24_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "
@@ -83,6 +83,14 @@ pub fn aoc_2023_01_b(input: &str) -> i32 {
         .sum()
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 1,
+    title: "Trebuchet?!",
+    a: aoc_2023_01_a,
+    b: aoc_2023_01_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -91,8 +99,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_01_a() {
-        assert_eq!(super::aoc_2023_01_a(INPUT), 54697);
+        let input = aoc_utils::input::workspace(2023, 1);
+        aoc_utils::ledger::assert_answer(2023, 1, 'a', super::aoc_2023_01_a(&input));
     }
 
     #[test]
@@ -101,8 +111,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_01_b() {
-        assert_eq!(super::aoc_2023_01_b(INPUT), 54885);
+        let input = aoc_utils::input::workspace(2023, 1);
+        aoc_utils::ledger::assert_answer(2023, 1, 'b', super::aoc_2023_01_b(&input));
     }
 
     #[test]
@@ -116,8 +128,6 @@ mod tests {
         );
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     1abc2
     pqr3stu8vwx
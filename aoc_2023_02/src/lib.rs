@@ -55,6 +55,14 @@ pub fn aoc_2023_02_b(input: &str) -> i32 {
     power
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 2,
+    title: "Cube Conundrum",
+    a: aoc_2023_02_a,
+    b: aoc_2023_02_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -63,8 +71,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_02_a() {
-        assert_eq!(super::aoc_2023_02_a(INPUT), 2076);
+        let input = aoc_utils::input::workspace(2023, 2);
+        aoc_utils::ledger::assert_answer(2023, 2, 'a', super::aoc_2023_02_a(&input));
     }
 
     #[test]
@@ -73,12 +83,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_02_b() {
-        assert_eq!(super::aoc_2023_02_b(INPUT), 70950);
+        let input = aoc_utils::input::workspace(2023, 2);
+        aoc_utils::ledger::assert_answer(2023, 2, 'b', super::aoc_2023_02_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
     Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
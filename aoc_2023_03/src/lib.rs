@@ -189,6 +189,14 @@ pub fn aoc_2023_03_b(input: &str) -> u32 {
     gears
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 3,
+    title: "Gear Ratios",
+    a: aoc_2023_03_a,
+    b: aoc_2023_03_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -199,8 +207,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_03_a() {
-        assert_eq!(super::aoc_2023_03_a(INPUT), 544433);
+        let input = aoc_utils::input::workspace(2023, 3);
+        aoc_utils::ledger::assert_answer(2023, 3, 'a', super::aoc_2023_03_a(&input));
     }
 
     #[test]
@@ -209,8 +219,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_03_b() {
-        assert_eq!(super::aoc_2023_03_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 3);
+        assert_eq!(super::aoc_2023_03_b(&input), 0);
     }
 
     #[rstest]
@@ -224,8 +236,6 @@ mod tests {
     }
 
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     467..114..
     ...*......
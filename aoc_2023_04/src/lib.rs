@@ -57,6 +57,13 @@ pub fn aoc_2023_04_b(input: &str) -> u32 {
         counter.values().sum()
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 4,
+    title: "Scratchcards",
+    a: aoc_2023_04_a,
+    b: aoc_2023_04_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -66,8 +73,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_04_a() {
-        assert_eq!(super::aoc_2023_04_a(INPUT), 22674);
+        let input = aoc_utils::input::workspace(2023, 4);
+        aoc_utils::ledger::assert_answer(2023, 4, 'a', super::aoc_2023_04_a(&input));
     }
 
     #[test]
@@ -76,12 +85,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_04_b() {
-        assert_eq!(super::aoc_2023_04_b(INPUT), 5747443);
+        let input = aoc_utils::input::workspace(2023, 4);
+        aoc_utils::ledger::assert_answer(2023, 4, 'b', super::aoc_2023_04_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
     Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -136,6 +136,14 @@ pub fn aoc_2023_05_b(input: &str) -> u64 {
     lookup_locations(input, true)
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 5,
+    title: "If You Give A Seed A Fertilizer",
+    a: aoc_2023_05_a,
+    b: aoc_2023_05_b,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{LookupRange, NamedMap};
@@ -147,8 +155,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_05_a() {
-        assert_eq!(super::aoc_2023_05_a(INPUT), 199602917);
+        let input = aoc_utils::input::workspace(2023, 5);
+        aoc_utils::ledger::assert_answer(2023, 5, 'a', super::aoc_2023_05_a(&input));
     }
 
     #[test]
@@ -157,8 +167,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_05_b() {
-        assert_eq!(super::aoc_2023_05_b(INPUT), 2254686);
+        let input = aoc_utils::input::workspace(2023, 5);
+        aoc_utils::ledger::assert_answer(2023, 5, 'b', super::aoc_2023_05_b(&input));
     }
 
     #[test]
@@ -244,8 +256,6 @@ mod tests {
     //     // sut.map.keys().for_each(|k| assert_eq!(sut.map[k], expect.map[k]));
     // }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
 seeds: 79 14 55 13
 
@@ -63,7 +63,13 @@ pub fn aoc_2023_06_b(input: &str) -> usize {
     calc_winning_moves(times, dist)
 }
 
-
+aoc_utils::solution! {
+    year: 2023,
+    day: 6,
+    title: "Wait For It",
+    a: aoc_2023_06_a,
+    b: aoc_2023_06_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -73,8 +79,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_06_a() {
-       assert_eq!(super::aoc_2023_06_a(INPUT), 1195150);
+        let input = aoc_utils::input::workspace(2023, 6);
+       aoc_utils::ledger::assert_answer(2023, 6, 'a', super::aoc_2023_06_a(&input));
     }
     
     #[test]
@@ -83,12 +91,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_06_b() {
-        assert_eq!(super::aoc_2023_06_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 6);
+        assert_eq!(super::aoc_2023_06_b(&input), 0);
     }
 
-    
-    const INPUT: &str = include_str!("input.txt");
 
     const TEST_INPUT: &str = "
 Time:      7  15   30
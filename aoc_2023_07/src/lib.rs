@@ -162,6 +162,14 @@ pub fn aoc_2023_07_b(input: &str) -> usize {
     score(input, true)
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 7,
+    title: "Camel Cards",
+    a: aoc_2023_07_a,
+    b: aoc_2023_07_b,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::HandType::*;
@@ -173,8 +181,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_07_a() {
-        assert_eq!(super::aoc_2023_07_a(INPUT), 252295678);
+        let input = aoc_utils::input::workspace(2023, 7);
+        aoc_utils::ledger::assert_answer(2023, 7, 'a', super::aoc_2023_07_a(&input));
     }
 
     #[test]
@@ -183,8 +193,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_07_b() {
-        assert_eq!(super::aoc_2023_07_b(INPUT), 250577259);
+        let input = aoc_utils::input::workspace(2023, 7);
+        aoc_utils::ledger::assert_answer(2023, 7, 'b', super::aoc_2023_07_b(&input));
     }
 
     #[rstest]
@@ -245,8 +257,6 @@ mod tests {
         );
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     32T3K 765
     T55J5 684
@@ -5,11 +5,8 @@
     How many endpoints passes a path?
 
     make dot from input:
-    Replace instructions by "digraph {", append "}"
-    node labels off with  node[label=""];
-    Replace =,(,) with ->, {, }
-    for every node ending A add a line "..A [style=filled, fillcolor=green ]"
-    for every node ending Z add a line "..Z [style=filled, fillcolor=red ]"
+    cargo test -p aoc_2023_08 -- --ignored write_input_dot
+    writes to_dot(INPUT) to input.dot, start nodes green, end nodes red, node labels off
 
     dot -Tsvg -O -Kneato input.dot
 
@@ -18,6 +15,7 @@
     num::integer::lcm :D
     Do we need an offset for start?
 */
+use aoc_utils::graph::Graph;
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 use std::collections::HashMap;
@@ -83,6 +81,27 @@ fn walk<'a>(
         .into_inner()
 }
 
+/// graphviz source of the network, nodes ending with A green, nodes ending with Z red, no labels
+pub fn to_dot(input: &str) -> String {
+    let (_, nodes) = parse(input);
+
+    let mut graph = Graph::directed();
+    for (&label, node) in nodes.iter().sorted_by_key(|(label, _)| **label) {
+        graph.add_edge(label, node.left);
+        if node.right != node.left {
+            graph.add_edge(label, node.right);
+        }
+    }
+
+    graph.to_dot_named("AOC_2023_08", "label=\"\"", |label| {
+        match label.chars().last() {
+            Some('A') => Some("style=filled, fillcolor=green".to_string()),
+            Some('Z') => Some("style=filled, fillcolor=red".to_string()),
+            _ => None,
+        }
+    })
+}
+
 pub fn aoc_2023_08_a(input: &str) -> usize {
     let (instuctions, nodes) = parse(input);
 
@@ -114,6 +133,14 @@ pub fn aoc_2023_08_b(input: &str) -> usize {
     count
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 8,
+    title: "Haunted Wasteland",
+    a: aoc_2023_08_a,
+    b: aoc_2023_08_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -127,8 +154,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_08_a() {
-        assert_eq!(super::aoc_2023_08_a(INPUT), 19667);
+        let input = aoc_utils::input::workspace(2023, 8);
+        aoc_utils::ledger::assert_answer(2023, 8, 'a', super::aoc_2023_08_a(&input));
     }
 
     #[test]
@@ -137,11 +166,29 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_08_b() {
-        assert_eq!(super::aoc_2023_08_b(INPUT), 19185263738117);
+        let input = aoc_utils::input::workspace(2023, 8);
+        aoc_utils::ledger::assert_answer(2023, 8, 'b', super::aoc_2023_08_b(&input));
+    }
+
+    #[test]
+    fn to_dot_example() {
+        let dot = super::to_dot(TEST_INPUT2);
+        assert!(dot.starts_with("digraph AOC_2023_08 {\n    node[label=\"\"];\n"));
+        assert!(dot.contains("\"AAA\" [style=filled, fillcolor=green];"));
+        assert!(dot.contains("\"ZZZ\" [style=filled, fillcolor=red];"));
+        assert!(dot.contains("\"BBB\" -> \"AAA\";"));
+        assert_eq!(dot.matches("->").count(), 4);
     }
 
-    const INPUT: &str = include_str!("input.txt");
+    #[test]
+    #[ignore = "writes src/input.dot from the input"]
+    fn write_input_dot() {
+        let input = aoc_utils::input::workspace(2023, 8);
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.dot");
+        std::fs::write(path, super::to_dot(&input)).unwrap();
+    }
 
     const TEST_INPUT: &str = "
     RL
@@ -52,6 +52,14 @@ pub fn aoc_2023_09_b(input: &str) -> i64 {
     parse(input).iter().map(|seq| predict(seq).0).sum()
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 9,
+    title: "Mirage Maintenance",
+    a: aoc_2023_09_a,
+    b: aoc_2023_09_b,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -60,8 +68,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_09_a() {
-        assert_eq!(super::aoc_2023_09_a(INPUT), 1806615041);
+        let input = aoc_utils::input::workspace(2023, 9);
+        aoc_utils::ledger::assert_answer(2023, 9, 'a', super::aoc_2023_09_a(&input));
     }
 
     #[test]
@@ -70,8 +80,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_09_b() {
-        assert_eq!(super::aoc_2023_09_b(INPUT), 1211);
+        let input = aoc_utils::input::workspace(2023, 9);
+        aoc_utils::ledger::assert_answer(2023, 9, 'b', super::aoc_2023_09_b(&input));
     }
 
     #[test]
@@ -82,8 +94,6 @@ mod tests {
         );
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     0 3 6 9 12 15
     1 3 6 10 15 21
@@ -372,6 +372,14 @@ fn get_tile_for_start(grid: &Vec<Vec<char>>, row: usize, col: usize) -> char {
     }
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 10,
+    title: "Pipe Maze",
+    a: aoc_2023_10_a,
+    b: aoc_2023_10_b,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Direction;
@@ -383,8 +391,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_10_a() {
-        assert_eq!(super::aoc_2023_10_a(INPUT), 6815);
+        let input = aoc_utils::input::workspace(2023, 10);
+        aoc_utils::ledger::assert_answer(2023, 10, 'a', super::aoc_2023_10_a(&input));
     }
 
     #[rstest]
@@ -396,8 +406,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_10_b() {
-        assert_eq!(super::aoc_2023_10_b(INPUT), 269);
+        let input = aoc_utils::input::workspace(2023, 10);
+        aoc_utils::ledger::assert_answer(2023, 10, 'b', super::aoc_2023_10_b(&input));
     }
 
     #[test]
@@ -461,14 +473,20 @@ mod tests {
     #[case(TEST_INPUT, 'F')]
     #[case(TEST_INPUT2, 'F')]
     #[case(TEST_INPUT3, 'F')]
-    #[case(INPUT, '7')]
     fn tile_for_start_should(#[case] input: &str, #[case] expected: char) {
         let (grid, start_row, start_col) = super::parse(input);
 
         assert_eq!(super::get_tile_for_start(&grid, start_row, start_col), expected);
     }
 
-    const INPUT: &str = include_str!("input.txt");
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn tile_for_start_should_be_7_in_input() {
+        let input = aoc_utils::input::workspace(2023, 10);
+        let (grid, start_row, start_col) = super::parse(&input);
+
+        assert_eq!(super::get_tile_for_start(&grid, start_row, start_col), '7');
+    }
 
     // Start at [2][0],
     const TEST_INPUT: &str = "
@@ -88,6 +88,14 @@ pub fn aoc_2023_11_b(input: &str) -> usize {
     calculate_expansion(input, 1_000_000)
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 11,
+    title: "Cosmic Expansion",
+    a: aoc_2023_11_a,
+    b: aoc_2023_11_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -98,8 +106,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_11_a() {
-        assert_eq!(super::aoc_2023_11_a(INPUT), 9799681);
+        let input = aoc_utils::input::workspace(2023, 11);
+        aoc_utils::ledger::assert_answer(2023, 11, 'a', super::aoc_2023_11_a(&input));
     }
 
     #[rstest]
@@ -114,12 +124,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_11_b() {
-        assert_eq!(super::aoc_2023_11_b(INPUT), 513171773355);
+        let input = aoc_utils::input::workspace(2023, 11);
+        aoc_utils::ledger::assert_answer(2023, 11, 'b', super::aoc_2023_11_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
     ...#......
     .......#..
@@ -43,6 +43,8 @@
     So much easier than DP
 */
 
+use aoc_utils::dp::Memo;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct GroupPattern {
     groups: Vec<usize>,
@@ -108,41 +110,37 @@ fn parse(input: &str) -> Vec<(&str, GroupPattern)> {
 }
 
 // #[memoize] // memoize is not working with lifetimes?
-//
-fn possibilities<'a>(report: String, pattern: &'a GroupPattern) -> usize {
-    // println!("report: {}, pattern: {:?}", report, pattern);
-
-    // if let Some(&p) = cache.get(&report) {
-    //     print!(".");
-    //     return p;
-    // }
-
-    // all groups are satisfied? all remaining ? must be  . => return 1
-    if pattern.is_valid(&report) {
-        // println!("report: {}, groups: {:?}, wildcards: {}", report, pattern.groups, pattern.positions.len());
-        return 1;
-    }
-    // not satisfied but no more ? => return 0
-    if report.find('?').is_none() {
-        return 0;
-    }
+// explicit cache from aoc_utils::dp works with any key
+fn possibilities(report: String, pattern: &GroupPattern) -> usize {
+    Memo::new()
+        .solve(report, &|report: &String, possibilities| {
+            // println!("report: {}, pattern: {:?}", report, pattern);
+
+            // all groups are satisfied? all remaining ? must be  . => return 1
+            if pattern.is_valid(report) {
+                // println!("report: {}, groups: {:?}, wildcards: {}", report, pattern.groups, pattern.positions.len());
+                return Ok(1);
+            }
+            // not satisfied but no more ? => return 0
+            if report.find('?').is_none() {
+                return Ok(0);
+            }
 
-    // don't overcomplicate, just use str::replacen to replace ? with . and # and count matches
-    // replace first ? with . and # and call recursively with one ? less. We worry about allocations later
-    // ignore leading .
-    let report_dot = report
-        .replacen('?', ".", 1)
-        .trim_start_matches(".")
-        .to_string();
-    let report_hash = report
-        .replacen('?', "#", 1)
-        .trim_start_matches(".")
-        .to_string();
-
-    let count_possibilitiies =
-        possibilities(report_dot, pattern) + possibilities(report_hash, pattern);
-    // cache.insert(report, count_possibilitiies);
-    count_possibilitiies
+            // don't overcomplicate, just use str::replacen to replace ? with . and # and count matches
+            // replace first ? with . and # and call recursively with one ? less. We worry about allocations later
+            // ignore leading .
+            let report_dot = report
+                .replacen('?', ".", 1)
+                .trim_start_matches(".")
+                .to_string();
+            let report_hash = report
+                .replacen('?', "#", 1)
+                .trim_start_matches(".")
+                .to_string();
+
+            Ok(possibilities(report_dot)? + possibilities(report_hash)?)
+        })
+        .expect("every step replaces a ?, reports can not repeat")
 }
 
 //NFA
@@ -215,7 +213,7 @@ fn count_matches(report: &str, nfa: &Vec<State>) -> usize {
     &tokens[len - 2] + &tokens[len - 1]
 }
 
-pub fn aoc_2023_12_a(input: &'static str) -> usize {
+pub fn aoc_2023_12_a(input: &str) -> usize {
     let condition_records = parse(input);
     condition_records
         .iter()
@@ -228,7 +226,7 @@ pub fn aoc_2023_12_a(input: &'static str) -> usize {
         .sum()
 }
 
-pub fn aoc_2023_12_a_nfa(input: &'static str) -> usize {
+pub fn aoc_2023_12_a_nfa(input: &str) -> usize {
     let condition_records = parse(input);
     condition_records
         .iter()
@@ -266,8 +264,17 @@ pub fn aoc_2023_12_b(input: &str) -> usize {
         .sum()
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 12,
+    title: "Hot Springs",
+    a: aoc_2023_12_a,
+    b: aoc_2023_12_b,
+}
+
 #[cfg(test)]
 mod tests {
+    use aoc_utils::check::{Check, Rng, from_fn, shrink_lines};
     use rstest::rstest;
 
     #[test]
@@ -276,14 +283,18 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_12_a() {
+        let input = aoc_utils::input::workspace(2023, 12);
         // 7645 is too high
-        assert_eq!(super::aoc_2023_12_a(INPUT), 7173);
+        aoc_utils::ledger::assert_answer(2023, 12, 'a', super::aoc_2023_12_a(&input));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_12_a_nfa() {
-        assert_eq!(super::aoc_2023_12_a_nfa(INPUT), 7173);
+        let input = aoc_utils::input::workspace(2023, 12);
+        assert_eq!(super::aoc_2023_12_a_nfa(&input), 7173);
     }
 
     #[test]
@@ -292,8 +303,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_12_b() {
-        assert_eq!(super::aoc_2023_12_b(INPUT), 29826669191291);
+        let input = aoc_utils::input::workspace(2023, 12);
+        aoc_utils::ledger::assert_answer(2023, 12, 'b', super::aoc_2023_12_b(&input));
     }
 
     #[rstest]
@@ -390,7 +403,41 @@ mod tests {
     //     })
     // }
 
-    const INPUT: &str = include_str!("input.txt");
+    #[test]
+    fn possibilities_should_agree_with_nfa() {
+        let possibilities = |input: &String| super::aoc_2023_12_a(input);
+        let nfa = |input: &String| super::aoc_2023_12_a_nfa(input);
+
+        Check::new(from_fn(records).shrink_with(shrink_lines))
+            .assert_agree(&[("possibilities", &possibilities), ("nfa", &nfa)]);
+    }
+
+    /// size records of random springs. The groups are taken from the springs before some
+    /// of them become unknown, so every record has at least one arrangement.
+    fn records(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let len = 1 + rng.below(15);
+                let mut springs = (0..len)
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect::<Vec<_>>();
+                // at least one group
+                springs[rng.below(len)] = '#';
+
+                let groups = springs
+                    .split(|c| *c == '.')
+                    .filter(|g| !g.is_empty())
+                    .map(|g| g.len().to_string())
+                    .collect::<Vec<_>>();
+                let report = springs
+                    .iter()
+                    .map(|&c| if rng.chance(0.4) { '?' } else { c })
+                    .collect::<String>();
+                format!("{report} {}", groups.join(","))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
     const TEST_INPUT: &str = "
     ???.### 1,1,3
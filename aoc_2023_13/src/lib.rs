@@ -171,6 +171,13 @@ pub fn aoc_2023_13_b(_input: &str) -> usize {
     0
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 13,
+    title: "Point of Incidence",
+    a: aoc_2023_13_a,
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -179,8 +186,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_13_a() {
-        assert_eq!(super::aoc_2023_13_a(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 13);
+        assert_eq!(super::aoc_2023_13_a(&input), 0);
     }
 
     #[test]
@@ -189,12 +198,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_13_b() {
-        assert_eq!(super::aoc_2023_13_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 13);
+        assert_eq!(super::aoc_2023_13_b(&input), 0);
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "
 #.##..##.
 ..#.##.#.
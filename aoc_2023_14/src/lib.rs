@@ -2,19 +2,77 @@
 /* Find the task under https://adventofcode.com/2023/day/14
     Solution idea:
     Just roll with it :)
+    Part b: a billion spin cycles will not finish. The stone positions repeat,
+    find the cycle with Brent and only spin the remainder.
 */
 
+use aoc_utils::cycle::brent;
 use std::{collections::BTreeSet, str::FromStr, string::ParseError};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tilt {
+    North,
+    West,
+    South,
+    East,
+}
+
 struct Panel {
     grid: Vec<Vec<char>>,
     rolling_stones: BTreeSet<(usize, usize)>,
 }
 
+type Stones = BTreeSet<(usize, usize)>;
+
 impl Panel {
+    #[allow(dead_code)]
     fn get(&self, row: usize, col: usize) -> Option<&char> {
         self.grid.get(row).and_then(|r| r.get(col))
     }
+
+    /// roll all stones as far as possible in direction of tilt
+    fn tilt(&self, stones: &Stones, tilt: Tilt) -> Stones {
+        let height = self.grid.len();
+        let width = self.grid.first().map_or(0, |r| r.len());
+        let (lines, len) = match tilt {
+            Tilt::North | Tilt::South => (width, height),
+            Tilt::West | Tilt::East => (height, width),
+        };
+
+        let mut rolled = BTreeSet::new();
+        for line in 0..lines {
+            // i counts from the wall the stones roll to
+            let pos = |i: usize| match tilt {
+                Tilt::North => (i, line),
+                Tilt::South => (height - 1 - i, line),
+                Tilt::West => (line, i),
+                Tilt::East => (line, width - 1 - i),
+            };
+
+            let mut next_free = 0;
+            for i in 0..len {
+                let (row, col) = pos(i);
+                if self.grid[row][col] == '#' {
+                    next_free = i + 1;
+                } else if stones.contains(&(row, col)) {
+                    rolled.insert(pos(next_free));
+                    next_free += 1;
+                }
+            }
+        }
+        rolled
+    }
+
+    fn spin_cycle(&self, stones: &Stones) -> Stones {
+        [Tilt::North, Tilt::West, Tilt::South, Tilt::East]
+            .iter()
+            .fold(stones.clone(), |stones, &tilt| self.tilt(&stones, tilt))
+    }
+
+    /// load on north support beams
+    fn load(&self, stones: &Stones) -> usize {
+        stones.iter().map(|(row, _)| self.grid.len() - row).sum()
+    }
 }
 
 impl FromStr for Panel {
@@ -23,6 +81,7 @@ impl FromStr for Panel {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut rolling_stones = BTreeSet::new();
         let grid = input
+            .trim()
             .lines()
             .enumerate()
             .map(|(row, l)| {
@@ -53,36 +112,70 @@ impl FromStr for Panel {
 
 pub fn aoc_2023_14_a(input: &str) -> usize {
     let panel: Panel = input.parse().expect("valid input");
-    todo!("aoc 2023 14");
+    let stones = panel.tilt(&panel.rolling_stones, Tilt::North);
+    panel.load(&stones)
 }
 
-pub fn aoc_2023_14_b(_input: &str) -> usize {
-    0
+pub fn aoc_2023_14_b(input: &str) -> usize {
+    const SPIN_CYCLES: usize = 1_000_000_000;
+
+    let panel: Panel = input.parse().expect("valid input");
+    let spin = |stones: &Stones| panel.spin_cycle(stones);
+
+    let (mu, lambda) = brent(panel.rolling_stones.clone(), spin);
+    let remaining = if SPIN_CYCLES < mu {
+        SPIN_CYCLES
+    } else {
+        mu + (SPIN_CYCLES - mu) % lambda
+    };
+
+    let stones = (0..remaining).fold(panel.rolling_stones.clone(), |stones, _| spin(&stones));
+    panel.load(&stones)
+}
+
+aoc_utils::solution! {
+    year: 2023,
+    day: 14,
+    title: "Parabolic Reflector Dish",
+    a: aoc_2023_14_a,
+    b: aoc_2023_14_b,
 }
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn aoc_2023_14_a_example() {
-        assert_eq!(super::aoc_2023_14_a(TEST_INPUT), 0);
+        assert_eq!(super::aoc_2023_14_a(TEST_INPUT), 136);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_14_a() {
-        assert_eq!(super::aoc_2023_14_a(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 14);
+        aoc_utils::ledger::assert_answer(2023, 14, 'a', super::aoc_2023_14_a(&input));
     }
 
     #[test]
     fn aoc_2023_14_b_example() {
-        assert_eq!(super::aoc_2023_14_b(TEST_INPUT), 0);
+        assert_eq!(super::aoc_2023_14_b(TEST_INPUT), 64);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_14_b() {
-        assert_eq!(super::aoc_2023_14_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 14);
+        aoc_utils::ledger::assert_answer(2023, 14, 'b', super::aoc_2023_14_b(&input));
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
-    const TEST_INPUT: &str = "";
+    const TEST_INPUT: &str = "
+    O....#....
+    O.OO#....#
+    .....##...
+    OO.#O....O
+    .O.....O#.
+    O.#..O.#.#
+    ..O..#O..O
+    .......O..
+    #....###..
+    #OO..#....";
 }
@@ -38,6 +38,14 @@ pub fn aoc_2023_15_b(input: &str) -> u32 {
     // input.trim().split(',').map(|s| )
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 15,
+    title: "Lens Library",
+    a: aoc_2023_15_a,
+    b: aoc_2023_15_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -48,8 +56,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_15_a() {
-        assert_eq!(super::aoc_2023_15_a(INPUT), 517965);
+        let input = aoc_utils::input::workspace(2023, 15);
+        aoc_utils::ledger::assert_answer(2023, 15, 'a', super::aoc_2023_15_a(&input));
     }
 
     #[test]
@@ -58,8 +68,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_15_b() {
-        assert_eq!(super::aoc_2023_15_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 15);
+        assert_eq!(super::aoc_2023_15_b(&input), 0);
     }
 
     #[rstest]
@@ -79,7 +91,5 @@ mod tests {
         assert_eq!(super::hash(s), expected);
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 }
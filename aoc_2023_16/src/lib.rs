@@ -424,24 +424,44 @@ pub fn aoc_2023_16_b(input: &str) -> u32 {
     max.unwrap()
 }
 
+aoc_utils::solution! {
+    year: 2023,
+    day: 16,
+    title: "The Floor Will Be Lava",
+    a: aoc_2023_16_a,
+    b: aoc_2023_16_b,
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     #[rstest]
     #[case(TEST_INPUT, 46)]
-    #[case(INPUT, 6883)]
-    fn aoc_2023_16_a(#[case] input: &str, #[case] expected: u32) {
+    fn aoc_2023_16_a_example(#[case] input: &str, #[case] expected: u32) {
         assert_eq!(super::aoc_2023_16_a(input), expected);
     }
 
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn aoc_2023_16_a() {
+        let input = aoc_utils::input::workspace(2023, 16);
+        assert_eq!(super::aoc_2023_16_a(&input), 6883);
+    }
+
     #[rstest]
     #[case(TEST_INPUT, 51)]
-    #[case(INPUT, 7228)]
-    fn aoc_2023_16_b(#[case] input: &str, #[case] expected: u32) {
+    fn aoc_2023_16_b_example(#[case] input: &str, #[case] expected: u32) {
         assert_eq!(super::aoc_2023_16_b(input), expected);
     }
 
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn aoc_2023_16_b() {
+        let input = aoc_utils::input::workspace(2023, 16);
+        assert_eq!(super::aoc_2023_16_b(&input), 7228);
+    }
+
     #[test]
     fn should_parse() {
         assert_eq!(
@@ -501,8 +521,6 @@ mod tests {
         assert_eq!(super::get_next_directions(object, dir), expected);
     }
 
-    const INPUT: &str = include_str!("input.txt");
-
     const TEST_INPUT: &str = r#"
     .|...\....
     |.-.\.....
@@ -89,7 +89,12 @@ pub fn aoc_2023_21_b(_input: &str) -> usize {
     0
 }
 
-
+aoc_utils::solution! {
+    year: 2023,
+    day: 21,
+    title: "Step Counter",
+    a: aoc_2023_21_a,
+}
 
 #[cfg(test)]
 mod tests {
@@ -101,8 +106,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_21_a() {
-       assert_eq!(super::aoc_2023_21_a(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 21);
+       assert_eq!(super::aoc_2023_21_a(&input), 0);
     }
     
     #[test]
@@ -111,12 +118,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2023_21_b() {
-        assert_eq!(super::aoc_2023_21_b(INPUT), 0);
+        let input = aoc_utils::input::workspace(2023, 21);
+        assert_eq!(super::aoc_2023_21_b(&input), 0);
     }
 
-    
-    const INPUT: &str = include_str!("input.txt");
 
     const TEST_INPUT: &str = "
     ...........
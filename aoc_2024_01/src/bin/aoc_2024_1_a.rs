@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2024_01::{aoc_2024_01_a, INPUT};
+use aoc_2024_01::aoc_2024_01_a;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 1);
 
-    println!("Part A {}", aoc_2024_01_a(INPUT));
+    println!(
+        "Part A {}",
+        aoc_utils::heap::profile(2024, 1, 'a', || aoc_2024_01_a(&input))
+    );
 }
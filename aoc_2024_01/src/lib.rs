@@ -61,7 +61,13 @@ pub fn aoc_2024_01_b(input: &str) -> i32 {
     })
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 1,
+    title: "Historian Hysteria",
+    a: aoc_2024_01_a,
+    b: aoc_2024_01_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -74,8 +80,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_01_a() {
-        assert_eq!(super::aoc_2024_01_a(super::INPUT), 1506483);
+        let input = aoc_utils::input::workspace(2024, 1);
+        aoc_utils::ledger::assert_answer(2024, 1, 'a', super::aoc_2024_01_a(&input));
     }
 
     #[rstest]
@@ -85,8 +93,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_01_b() {
-        assert_eq!(super::aoc_2024_01_b(super::INPUT), 23126924);
+        let input = aoc_utils::input::workspace(2024, 1);
+        aoc_utils::ledger::assert_answer(2024, 1, 'b', super::aoc_2024_01_b(&input));
     }
 
     const TEST_INPUT: &str = "3   4
@@ -83,7 +83,13 @@ pub fn aoc_2024_02_b(input: &str) -> usize {
     count
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 2,
+    title: "Red-Nosed Reports",
+    a: aoc_2024_02_a,
+    b: aoc_2024_02_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -114,8 +120,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_02_a() {
-        assert_eq!(super::aoc_2024_02_a(super::INPUT), 660);
+        let input = aoc_utils::input::workspace(2024, 2);
+        aoc_utils::ledger::assert_answer(2024, 2, 'a', super::aoc_2024_02_a(&input));
     }
 
     #[rstest]
@@ -135,8 +143,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_02_b() {
-        assert_eq!(super::aoc_2024_02_b(super::INPUT), 689);
+        let input = aoc_utils::input::workspace(2024, 2);
+        aoc_utils::ledger::assert_answer(2024, 2, 'b', super::aoc_2024_02_b(&input));
     }
 
     const TEST_INPUT: &str = "7 6 4 2 1
@@ -72,7 +72,13 @@ pub fn aoc_2024_03_b(input: &str) -> i64 {
     res
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 3,
+    title: "Mull It Over",
+    a: aoc_2024_03_a,
+    b: aoc_2024_03_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -84,8 +90,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_03_a() {
-        assert_eq!(super::aoc_2024_03_a(super::INPUT), 153469856);
+        let input = aoc_utils::input::workspace(2024, 3);
+        aoc_utils::ledger::assert_answer(2024, 3, 'a', super::aoc_2024_03_a(&input));
     }
 
     #[rstest]
@@ -96,8 +104,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_03_b() {
-        assert_eq!(super::aoc_2024_03_b(super::INPUT), 77055967);
+        let input = aoc_utils::input::workspace(2024, 3);
+        aoc_utils::ledger::assert_answer(2024, 3, 'b', super::aoc_2024_03_b(&input));
     }
 
     const TEST_INPUT: &str =
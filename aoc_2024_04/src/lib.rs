@@ -141,7 +141,13 @@ pub fn aoc_2024_04_b(input: &str) -> usize {
     count
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 4,
+    title: "Ceres Search",
+    a: aoc_2024_04_a,
+    b: aoc_2024_04_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -154,8 +160,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_04_a() {
-        assert_eq!(super::aoc_2024_04_a(super::INPUT), 2358);
+        let input = aoc_utils::input::workspace(2024, 4);
+        aoc_utils::ledger::assert_answer(2024, 4, 'a', super::aoc_2024_04_a(&input));
     }
 
     #[rstest]
@@ -165,8 +173,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_04_b() {
-        assert_eq!(super::aoc_2024_04_b(super::INPUT), 1737);
+        let input = aoc_utils::input::workspace(2024, 4);
+        aoc_utils::ledger::assert_answer(2024, 4, 'b', super::aoc_2024_04_b(&input));
     }
 
     const TEST_INPUT: &str = "MMMSXXMASM
@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2024_05::{aoc_2024_05_a, INPUT};
+use aoc_2024_05::aoc_2024_05_a;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 5);
 
-    println!("Part A {}", aoc_2024_05_a(INPUT));
+    println!(
+        "Part A {:?}",
+        aoc_utils::heap::profile(2024, 5, 'a', || aoc_2024_05_a(&input))
+    );
 }
@@ -9,80 +9,69 @@ for sorting in b we need topological order
 
 input rules seems to have cycles 😱
 Lots of cycles
+but the rules between the pages of a single print are acyclic, sort only that subset
 
  make dot from input:
-    Insert by "digraph {", remove prints,  append "}"
-    node labels off with  node[label=""];
-    Replace | with ->, insert ; at end of lines
+    cargo test -p aoc_2024_05 -- --ignored write_rules_dot
+    writes rules_to_dot(INPUT) to rules.dot, node labels off
 
     dot -Tsvg -O -Kcirco rules.dot
 
 
 */
 
-use std::{
-    cmp::Ordering,
-    collections::{HashSet, VecDeque},
+use aoc_utils::{
+    error::{AocError, Result},
+    graph::{Graph, toposort_subset},
 };
 
-fn parse(input: &str) -> (Vec<(u32, u32)>, Vec<Vec<u32>>) {
+/// page l must be printed before page r
+type Rules = Vec<(u32, u32)>;
+
+fn parse(input: &str) -> Result<(Rules, Vec<Vec<u32>>)> {
     let Some((rules_def, pages_def)) = input.split_once("\n\n") else {
-        panic!("invalid input")
+        return Err(AocError::parse(
+            input,
+            input.len(),
+            0,
+            "expected an empty line between rules and prints",
+        ));
     };
 
     let rules = rules_def
         .lines()
-        .flat_map(|l| l.trim().split_once('|'))
-        .flat_map(|(l, r)| match (l.parse::<u32>(), r.parse::<u32>()) {
-            (Ok(a), Ok(b)) => Some((a, b)),
-            _ => None, // yes we ignore the error in AoC
+        .map(|l| {
+            l.trim()
+                .split_once('|')
+                .and_then(|(l, r)| Some((l.parse::<u32>().ok()?, r.parse::<u32>().ok()?)))
+                .ok_or_else(|| AocError::parse_at(input, l, "expected a rule like 47|53"))
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
 
     let prints = pages_def
         .lines()
         .map(|l| {
             l.split(',')
-                .flat_map(|n| n.parse::<u32>())
-                .collect::<Vec<_>>()
+                .map(|n| {
+                    n.trim()
+                        .parse::<u32>()
+                        .map_err(|_| AocError::parse_at(input, n, "expected a page number"))
+                })
+                .collect::<Result<Vec<_>>>()
         })
-        .collect::<Vec<_>>();
-
-    (rules, prints)
-}
+        .collect::<Result<Vec<_>>>()?;
 
-#[allow(dead_code)]
-fn get_without_incoming(g: &[(u32, u32)]) -> Vec<u32> {
-    let on_right = g.iter().map(|(_, r)| r).collect::<HashSet<_>>();
-    g.iter()
-        .filter(|(l, _)| !on_right.contains(l))
-        .map(|(l, _)| *l)
-        .collect()
+    Ok((rules, prints))
 }
 
-#[allow(dead_code)]
-fn topo_sort(rules: &[(u32, u32)]) -> Vec<u32> {
-    // Kahn' algorithm https://en.wikipedia.org/wiki/Topological_sorting
-
-    let mut g = rules.to_owned();
-    let mut sorted = Vec::with_capacity(rules.len() * 2);
-    let mut no_incoming = VecDeque::from(get_without_incoming(&g));
-
-    while let Some(n) = no_incoming.pop_front() {
-        if !sorted.contains(&n) {
-            sorted.push(n);
-        }
-
-        // remove outgoing edges of n from graph
-        g.retain(|&(l, _)| l != n);
-        // add new top level to queue
-        no_incoming.extend(get_without_incoming(&g));
-    }
-
-    // the real input does contain a cycle! Do we need to care? Unfoutunately ayes
-    //assert!(g.len() == 0, "graph contains cycle!");
-    println!("sorted: {}  {:?} \nrest: {:?}", sorted.len(), sorted, g);
-    sorted
+/// graphviz source of the ordering rules without node labels
+pub fn rules_to_dot(input: &str) -> String {
+    let mut graph = Graph::directed();
+    input
+        .lines()
+        .map_while(|l| l.trim().split_once('|'))
+        .for_each(|(l, r)| graph.add_edge(l, r));
+    graph.to_dot_named("AOC_2024_05", "label=\"\"", |_| None)
 }
 
 #[allow(dead_code)]
@@ -132,8 +121,8 @@ fn check_rule(rule: &(u32, u32), print: &Vec<u32>) -> bool {
 }
 
 #[tracing::instrument]
-pub fn aoc_2024_05_a(input: &str) -> u32 {
-    let (rules, prints) = parse(input);
+pub fn aoc_2024_05_a(input: &str) -> Result<u32> {
+    let (rules, prints) = parse(input)?;
 
     // check which prints are already in correct order
     // todo: how?
@@ -147,7 +136,7 @@ pub fn aoc_2024_05_a(input: &str) -> u32 {
     }
 
     // sum of middle pagenumbers
-    res
+    Ok(res)
 }
 
 // #[tracing::instrument]
@@ -177,9 +166,8 @@ pub fn aoc_2024_05_a(input: &str) -> u32 {
 // }
 
 #[tracing::instrument]
-pub fn aoc_2024_05_b(input: &str) -> u32 {
-    let (rules, prints) = parse(input);
-    let rule_set = rules.iter().cloned().collect::<HashSet<_>>();
+pub fn aoc_2024_05_b(input: &str) -> Result<u32> {
+    let (rules, prints) = parse(input)?;
 
     let mut res = 0;
     for print in prints {
@@ -188,25 +176,24 @@ pub fn aoc_2024_05_b(input: &str) -> u32 {
             continue;
         }
 
-        let mut print = print;
-        print.sort_by(|a, b| {
-            // is there a rule that says a must be before b? The rules are just sorting rules 🤦
-            // optimizing candidate, make contains test faster, eg. hashset.
-            // just about double the performance with standard HashSet (aka HashBrown) vs linear search (1.349 ms vs 3.689 ms)
-            if rule_set.contains(&(*a, *b)) {
-                Ordering::Greater
-            } else {
-                Ordering::Less
-            }
-        });
+        // the rules of the input contain cycles, but not between pages of the same print
+        let sorted = toposort_subset(&print, &rules)
+            .map_err(|_| AocError::precondition("rules of a print contain a cycle"))?;
+        let print = sorted.order;
 
         // was faulted but now it is corrected
         res += print[print.len() / 2];
     }
-    res
+    Ok(res)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 5,
+    title: "Print Queue",
+    a: aoc_2024_05_a,
+    b: aoc_2024_05_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -215,23 +202,46 @@ mod tests {
     #[rstest]
     #[case(TEST_INPUT, 143)]
     fn aoc_2024_05_a_example(#[case] input: &str, #[case] expected: u32) {
-        assert_eq!(super::aoc_2024_05_a(input), expected);
+        assert_eq!(super::aoc_2024_05_a(input), Ok(expected));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_05_a() {
-        assert_eq!(super::aoc_2024_05_a(super::INPUT), 6242);
+        let input = aoc_utils::input::workspace(2024, 5);
+        aoc_utils::ledger::assert_answer(2024, 5, 'a', super::aoc_2024_05_a(&input));
     }
 
     #[rstest]
     #[case(TEST_INPUT, 123)]
     fn aoc_2024_05_b_example(#[case] input: &str, #[case] expected: u32) {
-        assert_eq!(super::aoc_2024_05_b(input), expected);
+        assert_eq!(super::aoc_2024_05_b(input), Ok(expected));
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_05_b() {
-        assert_eq!(super::aoc_2024_05_b(super::INPUT), 5169);
+        let input = aoc_utils::input::workspace(2024, 5);
+        aoc_utils::ledger::assert_answer(2024, 5, 'b', super::aoc_2024_05_b(&input));
+    }
+
+    #[test]
+    fn rules_to_dot_example() {
+        let dot = super::rules_to_dot(TEST_INPUT);
+        assert!(
+            dot.starts_with(
+                "digraph AOC_2024_05 {\n    node[label=\"\"];\n    \"47\" -> \"53\";\n"
+            )
+        );
+        assert_eq!(dot.matches("->").count(), 21);
+    }
+
+    #[test]
+    #[ignore = "writes src/rules.dot from the input"]
+    fn write_rules_dot() {
+        let input = aoc_utils::input::workspace(2024, 5);
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/rules.dot");
+        std::fs::write(path, super::rules_to_dot(&input)).unwrap();
     }
 
     const TEST_INPUT: &str = "47|53
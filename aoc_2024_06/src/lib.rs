@@ -102,7 +102,13 @@ pub fn aoc_2024_06_b(input: &str) -> usize {
     loops
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 6,
+    title: "Guard Gallivant",
+    a: aoc_2024_06_a,
+    b: aoc_2024_06_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -116,8 +122,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_06_a() {
-        assert_eq!(super::aoc_2024_06_a(super::INPUT), 5101);
+        let input = aoc_utils::input::workspace(2024, 6);
+        aoc_utils::ledger::assert_answer(2024, 6, 'a', super::aoc_2024_06_a(&input));
     }
 
     #[rstest]
@@ -127,8 +135,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_06_b() {
-        assert_eq!(super::aoc_2024_06_b(super::INPUT), 1951);
+        let input = aoc_utils::input::workspace(2024, 6);
+        aoc_utils::ledger::assert_answer(2024, 6, 'b', super::aoc_2024_06_b(&input));
     }
 
     // #[test]
@@ -247,7 +247,13 @@ pub fn aoc_2024_07_b(input: &str) -> u64 {
         .sum()
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 7,
+    title: "Bridge Repair",
+    a: aoc_2024_07_a,
+    b: aoc_2024_07_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -332,8 +338,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_07_a() {
-        assert_eq!(super::aoc_2024_07_a(super::INPUT), 975671981569);
+        let input = aoc_utils::input::workspace(2024, 7);
+        aoc_utils::ledger::assert_answer(2024, 7, 'a', super::aoc_2024_07_a(&input));
     }
 
     #[rstest]
@@ -343,8 +351,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_07_b() {
-        assert_eq!(super::aoc_2024_07_b(super::INPUT), 223472064194845);
+        let input = aoc_utils::input::workspace(2024, 7);
+        aoc_utils::ledger::assert_answer(2024, 7, 'b', super::aoc_2024_07_b(&input));
     }
 
     const TEST_INPUT: &str = "190: 10 19
@@ -222,7 +222,13 @@ pub fn aoc_2024_08_b(input: &str) -> usize {
     all_antinodes.len()
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 8,
+    title: "Resonant Collinearity",
+    a: aoc_2024_08_a,
+    b: aoc_2024_08_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -263,8 +269,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_08_a() {
-        assert_eq!(super::aoc_2024_08_a(super::INPUT), 413);
+        let input = aoc_utils::input::workspace(2024, 8);
+        aoc_utils::ledger::assert_answer(2024, 8, 'a', super::aoc_2024_08_a(&input));
     }
 
     #[rstest]
@@ -274,8 +282,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_08_b() {
-        assert_eq!(super::aoc_2024_08_b(super::INPUT), 1417);
+        let input = aoc_utils::input::workspace(2024, 8);
+        aoc_utils::ledger::assert_answer(2024, 8, 'b', super::aoc_2024_08_b(&input));
     }
 
     #[test]
@@ -1,16 +1,22 @@
+use std::sync::LazyLock;
+
 use aoc_2024_09::*;
 
+/// the input of the day, read before the benches
+static INPUT: LazyLock<String> = LazyLock::new(|| aoc_utils::input::workspace(2024, 9));
+
 fn main() {
+    LazyLock::force(&INPUT);
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
 fn part1() {
-    aoc_2024_09_a(divan::black_box(INPUT));
+    aoc_2024_09_a(divan::black_box(&INPUT));
 }
 
 #[divan::bench]
 fn part2() {
-    aoc_2024_09_b(divan::black_box(INPUT));
+    aoc_2024_09_b(divan::black_box(&INPUT));
 }
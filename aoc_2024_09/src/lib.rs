@@ -151,6 +151,8 @@ pub fn aoc_2024_09_a(input: &str) -> usize {
         } else if free_len == 0 {
             // println!("rm empty space");
             runs.remove(first_free);
+            // the file moved one to the front
+            last_file -= 1;
         } else if file_len < free_len {
             // println!("insert file in space");
             let d = free_len - file_len;
@@ -174,6 +176,7 @@ pub fn aoc_2024_09_a(input: &str) -> usize {
 
             // removing at the end does not change the first free
             runs.remove(last_file);
+            last_file -= 1;
 
             //   replace empty with file
             if let Some(free) = runs.get_mut(first_free) {
@@ -285,7 +288,28 @@ pub fn aoc_2024_09_b(input: &str) -> usize {
     checksum(&runs)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+/// disk map of scale files of 1 to 9 blocks with 0 to 9 free blocks between them,
+/// the real one has 10000 files
+pub fn generate(seed: u64, scale: usize) -> String {
+    let mut rng = aoc_utils::check::Rng::new(seed);
+    let mut map = String::with_capacity(2 * scale);
+    for file in 0..scale.max(1) {
+        if file > 0 {
+            map.push(char::from(b'0' + rng.below(10) as u8));
+        }
+        map.push(char::from(b'1' + rng.below(9) as u8));
+    }
+    map
+}
+
+aoc_utils::solution! {
+    year: 2024,
+    day: 9,
+    title: "Disk Fragmenter",
+    a: aoc_2024_09_a,
+    b: aoc_2024_09_b,
+    generate: generate,
+}
 
 #[cfg(test)]
 mod tests {
@@ -298,8 +322,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_09_a() {
-        assert_eq!(super::aoc_2024_09_a(super::INPUT), 6432869891895);
+        let input = aoc_utils::input::workspace(2024, 9);
+        aoc_utils::ledger::assert_answer(2024, 9, 'a', super::aoc_2024_09_a(&input));
     }
 
     #[rstest]
@@ -309,8 +335,47 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_09_b() {
-        assert_eq!(super::aoc_2024_09_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 9);
+        assert_eq!(super::aoc_2024_09_b(&input), 0);
+    }
+
+    /// expand the blocks and move them one by one
+    fn compact_blocks(map: &str) -> usize {
+        let mut blocks = Vec::new();
+        for (i, len) in map.trim().bytes().map(|b| (b - b'0') as usize).enumerate() {
+            let id = (i % 2 == 0).then_some(i / 2);
+            blocks.extend(std::iter::repeat_n(id, len));
+        }
+        let mut free = 0;
+        while let Some(last) = blocks.pop() {
+            while free < blocks.len() && blocks[free].is_some() {
+                free += 1;
+            }
+            if free < blocks.len() {
+                blocks[free] = last;
+            } else {
+                blocks.push(last);
+                break;
+            }
+        }
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(pos, id)| pos * id.unwrap_or(0))
+            .sum()
+    }
+
+    #[test]
+    fn aoc_2024_09_a_should_agree_with_blocks() {
+        assert_eq!(compact_blocks(TEST_INPUT), 1928);
+
+        let maps = aoc_utils::check::from_fn(|rng, size| super::generate(rng.next_u64(), size));
+        aoc_utils::check::Check::new(maps).assert_agree(&[
+            ("runs", &|map: &String| super::aoc_2024_09_a(map)),
+            ("blocks", &|map: &String| compact_blocks(map)),
+        ]);
     }
 
     const TEST_INPUT: &str = "2333133121414131402";
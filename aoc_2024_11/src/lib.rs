@@ -109,7 +109,13 @@ pub fn aoc_2024_11_b(input: &str) -> usize {
     blink_recursive(0, 75, &mut numbers, &mut known)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 11,
+    title: "Plutonian Pebbles",
+    a: aoc_2024_11_a,
+    b: aoc_2024_11_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -123,13 +129,14 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_11_a() {
-        assert_eq!(super::aoc_2024_11_a(super::INPUT), 224529);
+        let input = aoc_utils::input::workspace(2024, 11);
+        aoc_utils::ledger::assert_answer(2024, 11, 'a', super::aoc_2024_11_a(&input));
     }
 
     #[rstest]
     #[case(TEST_INPUT, 25, 55312)]
-    #[case(INPUT, 25, 224529)]
     fn blink_recursive_should(#[case] input: &str, #[case] blinks: u64, #[case] expected: usize) {
         let mut numbers = parse(input);
         let mut known = HashMap::with_capacity(10000);
@@ -141,8 +148,20 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
+    fn blink_recursive_should_count_input() {
+        let input = aoc_utils::input::workspace(2024, 11);
+        let mut numbers = parse(&input);
+        let mut known = HashMap::with_capacity(10000);
+
+        assert_eq!(blink_recursive(0, 25, &mut numbers, &mut known), 224529);
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_11_b() {
-        assert_eq!(super::aoc_2024_11_b(super::INPUT), 266820198587914);
+        let input = aoc_utils::input::workspace(2024, 11);
+        aoc_utils::ledger::assert_answer(2024, 11, 'b', super::aoc_2024_11_b(&input));
     }
 
     // const TEST_INPUT2: &str = "0 1 10 99 999";
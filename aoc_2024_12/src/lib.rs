@@ -119,7 +119,12 @@ pub fn aoc_2024_12_b(_input: &str) -> usize {
     0
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 12,
+    title: "Garden Groups",
+    a: aoc_2024_12_a,
+}
 
 #[cfg(test)]
 mod tests {
@@ -134,8 +139,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_12_a() {
-        assert_eq!(super::aoc_2024_12_a(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 12);
+        assert_eq!(super::aoc_2024_12_a(&input), 0);
     }
 
     #[rstest]
@@ -145,8 +152,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_12_b() {
-        assert_eq!(super::aoc_2024_12_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 12);
+        assert_eq!(super::aoc_2024_12_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "
@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2024_13::{aoc_2024_13_b, INPUT};
+use aoc_2024_13::aoc_2024_13_b;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 13);
 
-    println!("Part B {}", aoc_2024_13_b(INPUT));
+    println!(
+        "Part B {}",
+        aoc_utils::heap::profile(2024, 13, 'b', || aoc_2024_13_b(&input))
+    );
 }
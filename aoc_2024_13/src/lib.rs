@@ -5,6 +5,7 @@ use std::mem::swap;
     Solution idea:
 
 */
+use aoc_utils::linalg;
 use glam::I64Vec2;
 use nom::{
     branch::alt,
@@ -37,38 +38,13 @@ impl Machine {
 
         // Some(self.prize / gcd)
 
-        // just solve with Cramersche Rule and ensure integer solutions
+        // just solve the linear system exactly and ensure integer solutions
         let (r1, r2) = self.buttons;
         let p = self.prize;
 
-        //      (r1.x r1.y)        (a c)
-        //  det (r2.x r2.y)  = det (b d) = ad-bc
-        let det_a = r1.x * r2.y - (r2.x * r1.y);
-        if det_a == 0 {
-            return None;
-        }
-
-        // (p.x r1.y)
-        // (p.y r2.y)
-        let det_a1 = p.x * r2.y - (p.y * r1.y);
-        // (r1.x p.x)
-        // (r2.x p.y)
-        let det_a2 = r1.x * p.y - (r2.x * p.x);
-
-        // println!("A: {r1} B: {r2} P: {p} det A1 {det_a1} det A2 {det_a2} det {det_a}");
-
-        let x = det_a1 / det_a;
-        let y = det_a2 / det_a;
-
-        // is this an integer solution to our system?
-        if r1.x * x + r1.y * y != p.x {
-            return None;
-        }
-        if r2.x * x + r2.y * y != p.y {
-            return None;
-        }
-
-        Some(I64Vec2::new(x, y))
+        linalg::solve_integer(&[vec![r1.x, r1.y], vec![r2.x, r2.y]], &[p.x, p.y])
+            .unique_integer()
+            .map(|x| I64Vec2::new(x[0] as i64, x[1] as i64))
     }
 }
 
@@ -174,7 +150,13 @@ pub fn aoc_2024_13_b(input: &str) -> i64 {
         .fold(0, |accu, (_m, cost)| accu + cost)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 13,
+    title: "Claw Contraption",
+    a: aoc_2024_13_a,
+    b: aoc_2024_13_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -188,8 +170,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_13_a() {
-        assert_eq!(super::aoc_2024_13_a(super::INPUT), 36250);
+        let input = aoc_utils::input::workspace(2024, 13);
+        aoc_utils::ledger::assert_answer(2024, 13, 'a', super::aoc_2024_13_a(&input));
     }
 
     // #[rstest]
@@ -199,8 +183,10 @@ mod tests {
     // }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_13_b() {
-        assert_eq!(super::aoc_2024_13_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 13);
+        assert_eq!(super::aoc_2024_13_b(&input), 0);
     }
 
     #[rstest]
@@ -1,17 +1,23 @@
+use std::sync::LazyLock;
+
 use aoc_2024_14::*;
+
+/// the input of the day, read before the benches
+static INPUT: LazyLock<String> = LazyLock::new(|| aoc_utils::input::workspace(2024, 14));
 use glam::IVec2;
 
 fn main() {
+    LazyLock::force(&INPUT);
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
 fn part1() {
-    aoc_2024_14_a(divan::black_box(INPUT), IVec2::new(101, 103));
+    aoc_2024_14_a(divan::black_box(&INPUT), IVec2::new(101, 103));
 }
 
 #[divan::bench]
 fn part2() {
-    aoc_2024_14_b(divan::black_box(INPUT), IVec2::new(101, 103));
+    aoc_2024_14_b(divan::black_box(&INPUT), IVec2::new(101, 103));
 }
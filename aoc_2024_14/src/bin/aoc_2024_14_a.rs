@@ -1,10 +1,17 @@
 // binary for perf tests etc.
-use aoc_2024_14::{aoc_2024_14_a, INPUT};
+use aoc_2024_14::aoc_2024_14_a;
 use glam::IVec2;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 14);
 
-    println!("Part A {}", aoc_2024_14_a(INPUT, IVec2::new(101, 103)));
+    println!(
+        "Part A {}",
+        aoc_utils::heap::profile(2024, 14, 'a', || aoc_2024_14_a(
+            &input,
+            IVec2::new(101, 103)
+        ))
+    );
 }
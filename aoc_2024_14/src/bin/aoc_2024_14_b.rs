@@ -1,10 +1,17 @@
 // binary for perf tests etc.
-use aoc_2024_14::{aoc_2024_14_b, INPUT};
+use aoc_2024_14::aoc_2024_14_b;
 use glam::IVec2;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 14);
 
-    println!("Part B {}", aoc_2024_14_b(INPUT, IVec2::new(101, 103)));
+    println!(
+        "Part B {}",
+        aoc_utils::heap::profile(2024, 14, 'b', || aoc_2024_14_b(
+            &input,
+            IVec2::new(101, 103)
+        ))
+    );
 }
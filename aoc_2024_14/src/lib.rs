@@ -180,7 +180,21 @@ pub fn aoc_2024_14_b(input: &str, bounds: IVec2) -> i32 {
     7584
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+/// size of the bathroom, 101 x 103 for the real input
+fn bounds(params: &aoc_utils::Params) -> Result<IVec2, aoc_utils::solution::Error> {
+    Ok(IVec2::new(
+        params.get_or("width", 101)?,
+        params.get_or("height", 103)?,
+    ))
+}
+
+aoc_utils::solution! {
+    year: 2024,
+    day: 14,
+    title: "Restroom Redoubt",
+    a: |input, params| aoc_2024_14_a(input, bounds(params)?),
+    b: |input, params| aoc_2024_14_b(input, bounds(params)?),
+}
 
 #[cfg(test)]
 mod tests {
@@ -210,21 +224,27 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_14_a() {
-        assert_eq!(
-            super::aoc_2024_14_a(super::INPUT, IVec2::new(101, 103)),
-            236628054
+        let input = aoc_utils::input::workspace(2024, 14);
+        aoc_utils::ledger::assert_answer(
+            2024,
+            14,
+            'a',
+            super::aoc_2024_14_a(&input, IVec2::new(101, 103)),
         );
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn how_many_in_small_box() {
+        let input = aoc_utils::input::workspace(2024, 14);
         // probably a small image, check if everything is inside of a small bounding box
         // Result: not a single hit in i32 range, so probably wrong metric
 
         let bounds = IVec2::new(101, 103);
         let mut in_box = 0;
-        let (_, bots) = parse(super::INPUT).expect("invalid input");
+        let (_, bots) = parse(&input).expect("invalid input");
 
         // let x = (20..30).map(|i| IVec2::new(i, i)).collect::<Vec<_>>();
         // let b = get_bounding_box(x);
@@ -252,10 +272,12 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn find_unusual() {
+        let input = aoc_utils::input::workspace(2024, 14);
         let bounds = IVec2::new(101, 103);
         // let center = bounds / 2;
-        let (_, bots) = parse(super::INPUT).expect("invalid input");
+        let (_, bots) = parse(&input).expect("invalid input");
         let len = bots.len() as i32;
 
         let mut found = 0;
@@ -292,11 +314,15 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_14_b() {
+        let input = aoc_utils::input::workspace(2024, 14);
         // just write a unittest for christmas tree!?!?!?!?
-        assert_eq!(
-            super::aoc_2024_14_b(super::INPUT, IVec2::new(101, 103)),
-            7584
+        aoc_utils::ledger::assert_answer(
+            2024,
+            14,
+            'b',
+            super::aoc_2024_14_b(&input, IVec2::new(101, 103)),
         );
     }
 
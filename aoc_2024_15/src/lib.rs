@@ -229,7 +229,13 @@ pub fn aoc_2024_15_b(input: &str) -> i32 {
     calculate_score(&grid)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 15,
+    title: "Warehouse Woes",
+    a: aoc_2024_15_a,
+    b: aoc_2024_15_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -242,8 +248,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_15_a() {
-        assert_eq!(super::aoc_2024_15_a(super::INPUT), 1514353);
+        let input = aoc_utils::input::workspace(2024, 15);
+        aoc_utils::ledger::assert_answer(2024, 15, 'a', super::aoc_2024_15_a(&input));
     }
 
     #[rstest]
@@ -255,8 +263,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_15_b() {
-        assert_eq!(super::aoc_2024_15_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 15);
+        assert_eq!(super::aoc_2024_15_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "
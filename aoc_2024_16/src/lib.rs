@@ -5,7 +5,11 @@ use std::collections::{BinaryHeap, HashMap};
     Solution idea:
 
 */
-use aoc_utils::grid::{Grid, EAST};
+use aoc_utils::{
+    check::Rng,
+    grid::{EAST, Grid},
+    synth,
+};
 use glam::IVec2;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -41,64 +45,57 @@ impl Ord for PriorityEntry {
 }
 
 // based on https://www.redblobgames.com/pathfinding/a-star/introduction.html better readable than wikipedia article
-fn a_star(grid: Grid<char>) -> i32 {
+// Dijkstra over position and heading, the heading changes the cost of the next step
+fn lowest_score(grid: &Grid<char>, start: IVec2, end: IVec2) -> Option<i32> {
     let mut frontier = BinaryHeap::new();
-    frontier.push(PriorityEntry::new(0, grid.cursor, EAST)); // prio of start doesn't matter
-    let mut came_from = HashMap::new();
-    // start has no came_from, no need to insert
+    frontier.push(PriorityEntry::new(0, start, EAST));
     let mut cost_so_far = HashMap::new();
-    cost_so_far.insert(grid.cursor, 0);
-    let end = grid.find('E').expect("No end found");
+    cost_so_far.insert((start, EAST), 0);
 
-    // estimated cost/priority most be first in tuple as it is the ordering criteria of the queue
     while let Some(PriorityEntry {
-        priority: _,
+        priority: cost,
         pos,
         dir: direction,
     }) = frontier.pop()
     {
         // end reached, path must be minimal
         if pos == end {
-            // calculate cost of cheapest path
-            // is this just cost_so_far[predecessor] + last?
-            // or do we need to go back the whole chain?
+            return Some(cost);
+        }
+        // found a better way to this node after queueing it
+        if cost_so_far[&(pos, direction)] < cost {
+            continue;
         }
 
         // for all axial neighbours
         for (next, c) in grid.iter_axis_neighbours_with_positions(pos) {
             let new_dir = next - pos;
-            // back is not a legal move
-            if new_dir == direction * -1 {
+            // back is not a legal move, walls neither
+            if new_dir == -direction || *c == '#' {
                 continue;
             }
-            // straight ahead costs 1, turn 90° costs 1000
-            let next_cost = if new_dir == direction { 1 } else { 1000 };
-            let new_cost = cost_so_far[&next] + next_cost;
-            if match cost_so_far.get(&next) {
+            // straight ahead costs 1, turn 90° costs 1000 before the step
+            let new_cost = cost + if new_dir == direction { 1 } else { 1001 };
+            if match cost_so_far.get(&(next, new_dir)) {
                 Some(c) => &new_cost < c, // found a better way to this node?
                 None => true,             // there was no known way yet
             } {
-                cost_so_far.insert(next.clone(), new_cost);
-                // grid without diagonal movement, so use manhattan distance next to end as heuristic
-                // assume a single turn
-                let heuristic = end.x - next.x + end.y - next.y + 1000;
-                let prio = new_cost + heuristic;
-                frontier.push(PriorityEntry::new(prio, next, new_dir)); //-prio because min heap needed
-                came_from.insert(next, pos);
+                cost_so_far.insert((next, new_dir), new_cost);
+                frontier.push(PriorityEntry::new(new_cost, next, new_dir));
             }
         }
     }
-    // println!("Frontier: {:?}", frontier);
 
-    unreachable!()
+    None
 }
 
 #[tracing::instrument]
 pub fn aoc_2024_16_a(input: &str) -> usize {
     let mut grid = input.parse::<Grid<char>>().expect("valid grid");
-    grid.find_cursor('S', '.');
+    let start = grid.find_cursor('S', '.');
+    let end = grid.find('E').expect("No end found");
 
-    0
+    lowest_score(&grid, start, end).expect("a way to the end") as usize
 }
 
 #[tracing::instrument]
@@ -106,21 +103,59 @@ pub fn aoc_2024_16_b(_input: &str) -> usize {
     0
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+/// maze of scale x scale cells with a wall around, start bottom left, end top right.
+/// Some extra openings give the reindeer a choice, the real maze has 70 x 70 cells.
+pub fn generate(seed: u64, scale: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let cells = scale.max(2);
+    let mut maze = synth::maze(&mut rng, cells, cells);
+    synth::open_walls(&mut rng, &mut maze, cells * cells / 8);
+
+    let side = maze.width + 2;
+    let (start, end) = (
+        IVec2::new(1, side as i32 - 2),
+        IVec2::new(side as i32 - 2, 1),
+    );
+    let mut out = String::with_capacity(side * (side + 1));
+    for y in 0..side as i32 {
+        for x in 0..side as i32 {
+            let pos = IVec2::new(x, y);
+            out.push(match maze.get(pos - IVec2::ONE) {
+                _ if pos == start => 'S',
+                _ if pos == end => 'E',
+                Some(true) => '.',
+                _ => '#',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+aoc_utils::solution! {
+    year: 2024,
+    day: 16,
+    title: "Reindeer Maze",
+    a: aoc_2024_16_a,
+    generate: generate,
+}
 
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case(TEST_INPUT, 0)]
+    #[case(TEST_INPUT, 7036)]
+    #[case(TEST_INPUT_2, 11048)]
     fn aoc_2024_16_a_example(#[case] input: &str, #[case] expected: usize) {
         assert_eq!(super::aoc_2024_16_a(input), expected);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_16_a() {
-        assert_eq!(super::aoc_2024_16_a(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 16);
+        aoc_utils::ledger::assert_answer(2024, 16, 'a', super::aoc_2024_16_a(&input));
     }
 
     #[rstest]
@@ -130,9 +165,65 @@ mod tests {
     }
 
     #[test]
+    fn generate_should_give_walled_maze() {
+        let sut = super::generate(1, 4);
+
+        let grid = sut.parse::<aoc_utils::grid::Grid<char>>().unwrap();
+        assert_eq!((grid.width, grid.height), (9, 9));
+        assert_eq!(grid.find('S'), Some(glam::IVec2::new(1, 7)));
+        assert_eq!(grid.find('E'), Some(glam::IVec2::new(7, 1)));
+        assert!(sut.lines().next().unwrap().chars().all(|c| c == '#'));
+    }
+
+    #[test]
+    fn generate_should_give_maze_with_a_way_out() {
+        let sut = super::generate(7, 16);
+
+        assert!(super::aoc_2024_16_a(&sut) > 0);
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_16_b() {
-        assert_eq!(super::aoc_2024_16_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 16);
+        assert_eq!(super::aoc_2024_16_b(&input), 0);
     }
 
-    const TEST_INPUT: &str = "";
+    const TEST_INPUT: &str = "
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const TEST_INPUT_2: &str = "
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
 }
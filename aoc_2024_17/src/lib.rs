@@ -319,7 +319,13 @@ pub fn aoc_2024_17_b(input: &str) -> u64 {
     99
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 17,
+    title: "Chronospatial Computer",
+    a: aoc_2024_17_a,
+    b: aoc_2024_17_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -335,8 +341,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_17_a() {
-        assert_eq!(super::aoc_2024_17_a(super::INPUT), "6,2,7,2,3,1,6,0,5");
+        let input = aoc_utils::input::workspace(2024, 17);
+        aoc_utils::ledger::assert_answer(2024, 17, 'a', super::aoc_2024_17_a(&input));
     }
 
     #[rstest]
@@ -346,14 +354,18 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_17_b() {
-        assert_eq!(super::aoc_2024_17_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 17);
+        assert_eq!(super::aoc_2024_17_b(&input), 0);
     }
 
-    #[rstest]
-    #[case(crate::INPUT, "6,2,7,2,3,1,6,0,5?")]
-    fn algo_in_rust(#[case] input: &str, #[case] expected: String) {
-        let device = super::parse(input);
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn algo_in_rust() {
+        let input = aoc_utils::input::workspace(2024, 17);
+        let expected = "6,2,7,2,3,1,6,0,5?";
+        let device = super::parse(&input);
         println!("{device}");
 
         // A 0o263240543  B 0o0  C 0o0
@@ -401,10 +413,12 @@ mod tests {
         assert_eq!(result, expected);
     }
 
-    #[rstest]
-    #[case(crate::INPUT, 0)]
-    fn brute_force_in_rust(#[case] input: &str, #[case] expected: u64) {
-        let device = super::parse(input);
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn brute_force_in_rust() {
+        let input = aoc_utils::input::workspace(2024, 17);
+        let expected = 0;
+        let device = super::parse(&input);
         println!("{device}");
 
         // solution probably arount 2^(16 digits *3 bits) = 48 bit
@@ -457,10 +471,12 @@ mod tests {
         assert_eq!(register_a, expected);
     }
 
-    #[rstest]
-    #[case(crate::INPUT, 236548287712877)]
-    fn follow_the_road(#[case] input: &str, #[case] expected: u64) {
-        let device = super::parse(input);
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn follow_the_road() {
+        let input = aoc_utils::input::workspace(2024, 17);
+        let expected = 236548287712877;
+        let device = super::parse(&input);
         println!("{device}");
 
         let mut seeds = Vec::with_capacity(1000);
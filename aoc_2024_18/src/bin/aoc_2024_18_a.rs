@@ -1,10 +1,18 @@
 // binary for perf tests etc.
-use aoc_2024_18::{aoc_2024_18_a, INPUT};
+use aoc_2024_18::aoc_2024_18_a;
 use glam::IVec2;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 18);
 
-    println!("Part A {}", aoc_2024_18_a(INPUT, IVec2::new(70, 70), 1024));
+    println!(
+        "Part A {}",
+        aoc_utils::heap::profile(2024, 18, 'a', || aoc_2024_18_a(
+            &input,
+            IVec2::new(70, 70),
+            1024
+        ))
+    );
 }
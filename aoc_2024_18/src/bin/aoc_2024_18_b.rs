@@ -1,10 +1,18 @@
 // binary for perf tests etc.
-use aoc_2024_18::{aoc_2024_18_b, INPUT};
+use aoc_2024_18::aoc_2024_18_b;
 use glam::IVec2;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 18);
 
-    println!("Part B {}", aoc_2024_18_b(INPUT, IVec2::new(70, 70), 1024));
+    println!(
+        "Part B {}",
+        aoc_utils::heap::profile(2024, 18, 'b', || aoc_2024_18_b(
+            &input,
+            IVec2::new(70, 70),
+            1024
+        ))
+    );
 }
@@ -10,7 +10,7 @@ use std::{
 
     The misunderstanding that the bytes will fall one by one WHILE we are going will cost a lot of time...
 */
-use aoc_utils::grid::Grid;
+use aoc_utils::{check::Rng, grid::Grid, synth};
 use glam::IVec2;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -173,7 +173,55 @@ pub fn aoc_2024_18_b(input: &str, end: IVec2, fallen: i32) -> String {
         .unwrap()
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+/// bytes falling into a maze of cells x cells, first all walls, then the passages one by one.
+/// Gives the input, the size (last coordinate) and the number of walls.
+pub fn falling_maze(seed: u64, cells: usize) -> (String, i32, usize) {
+    let mut rng = Rng::new(seed);
+    let maze = synth::maze(&mut rng, cells.max(2), cells.max(2));
+    let end = maze.upper_bound;
+
+    let (mut walls, mut passages): (Vec<_>, Vec<_>) = maze
+        .iter_with_positions()
+        .filter(|(pos, _)| *pos != IVec2::ZERO && *pos != end)
+        .partition(|(_, open)| !**open);
+    rng.shuffle(&mut walls);
+    rng.shuffle(&mut passages);
+
+    let input = walls
+        .iter()
+        .chain(&passages)
+        .map(|(pos, _)| format!("{},{}\n", pos.x, pos.y))
+        .collect();
+    (input, end.x, walls.len())
+}
+
+/// input of scale x scale maze cells, the real memory space has 36 x 36
+pub fn generate(seed: u64, scale: usize) -> (String, aoc_utils::Params) {
+    let (input, size, walls) = falling_maze(seed, scale);
+    let params = aoc_utils::Params::new()
+        .with("size", size)
+        .with("fallen", walls);
+    (input, params)
+}
+
+/// exit in the bottom right corner, 70,70 for the real input
+fn end(params: &aoc_utils::Params) -> Result<IVec2, aoc_utils::solution::Error> {
+    let size = params.get_or("size", 70)?;
+    Ok(IVec2::new(size, size))
+}
+
+aoc_utils::solution! {
+    year: 2024,
+    day: 18,
+    title: "RAM Run",
+    a: |input, params| {
+        aoc_2024_18_a(input, end(params)?, params.get_or("fallen", 1024)?)
+    },
+    b: |input, params| {
+        aoc_2024_18_b(input, end(params)?, params.get_or("fallen", 1024)?)
+    },
+    generate: generate,
+}
 
 #[cfg(test)]
 mod tests {
@@ -192,10 +240,14 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_18_a() {
-        assert_eq!(
-            super::aoc_2024_18_a(super::INPUT, IVec2::new(70, 70), 1024),
-            306
+        let input = aoc_utils::input::workspace(2024, 18);
+        aoc_utils::ledger::assert_answer(
+            2024,
+            18,
+            'a',
+            super::aoc_2024_18_a(&input, IVec2::new(70, 70), 1024),
         );
     }
 
@@ -211,10 +263,42 @@ mod tests {
     }
 
     #[test]
-    fn aoc_2024_18_b() {
+    fn puzzle_should_use_example_params() {
+        use aoc_utils::{Answer, Params, Solution};
+
+        let params = Params::new().with("size", 6).with("fallen", 12);
+        assert_eq!(super::Puzzle.part_a(TEST_INPUT, &params), Ok(Answer::Int(22)));
         assert_eq!(
-            super::aoc_2024_18_b(super::INPUT, IVec2::new(70, 70), 1024),
-            "38,63"
+            super::Puzzle.part_b(TEST_INPUT, &params),
+            Ok(Answer::Text("6,1".into()))
+        );
+    }
+
+    #[rstest]
+    #[case(2)]
+    #[case(5)]
+    #[case(20)]
+    fn falling_maze_should_close_on_a_passage(#[case] cells: usize) {
+        let (input, size, walls) = super::falling_maze(cells as u64, cells);
+        let end = IVec2::new(size, size);
+
+        // the only path through a perfect maze is at least as long as the manhattan distance
+        assert!(super::aoc_2024_18_a(&input, end, walls as i32) >= 2 * size as usize);
+
+        let sut = super::aoc_2024_18_b(&input, end, walls as i32);
+        let passages = input.lines().skip(walls).collect::<Vec<_>>();
+        assert!(passages.contains(&sut.as_str()), "{sut} is no passage");
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input"]
+    fn aoc_2024_18_b() {
+        let input = aoc_utils::input::workspace(2024, 18);
+        aoc_utils::ledger::assert_answer(
+            2024,
+            18,
+            'b',
+            super::aoc_2024_18_b(&input, IVec2::new(70, 70), 1024),
         );
     }
 
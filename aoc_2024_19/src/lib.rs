@@ -1,5 +1,3 @@
-use std::collections::BTreeMap;
-
 // #[allow(dead_code)]
 /* Find the task under https://adventofcode.com/2024/day/19
     Solution idea:
@@ -10,6 +8,7 @@ use std::collections::BTreeMap;
         Counting possible ways. Build an NFA from the patterns.
 */
 // use aoc_utils::grid::Grid;
+use aoc_utils::dp::Memo;
 use regex::Regex;
 
 // get regex and list of valid words
@@ -43,48 +42,28 @@ fn parse<'a>(input: &'a str) -> (Vec<&'a str>, Vec<&'a str>) {
 
 /// count possibilities recursivly
 fn count_possibilitiies<'a>(word: &'a str, patterns: &Vec<&'a str>) -> usize {
-    fn memoized_count<'a>(
-        w: &'a str,
-        patterns: &Vec<&'a str>,
-        cache: &mut BTreeMap<&'a str, usize>,
-    ) -> usize {
-        if w.len() == 0 {
-            return 0;
-        }
-        if let Some(&n) = cache.get(w) {
-            return n;
-        }
-
-        patterns
-            .iter()
-            .map(|pattern| {
-                if w.starts_with(pattern) {
+    // let the cache warm up on its own. The rest of a word is a slice of it, no allocations.
+    let mut cache = Memo::new();
+    cache
+        .solve(word, &|w: &&'a str, count| {
+            let w = *w;
+            patterns
+                .iter()
+                .filter(|pattern| w.starts_with(*pattern))
+                .map(|pattern| {
                     // strip pattern
                     let rest = &w[pattern.len()..];
                     // println!("Stripped '{pattern}' from '{w}' Rest: '{rest}'");
-                    if rest.len() == 0 {
+                    if rest.is_empty() {
                         // we are at the end of a possible construction, count this
-                        1
+                        Ok(1)
                     } else {
-                        let c = memoized_count(rest, patterns, cache);
-                        cache.insert(rest, c);
-                        c
+                        count(rest)
                     }
-                } else {
-                    0
-                }
-            })
-            .sum()
-    }
-
-    // prime cache with patterns itself. 1 is wrong because some pattern can be composited by others
-    // let mut cache = patterns.iter().map(|w| (*w, 1)).collect::<BTreeMap<_, _>>();
-    // let the cache warm up on its own
-    let mut cache = BTreeMap::new();
-    let count = memoized_count(word, patterns, &mut cache);
-    // println!("{cache:?}");
-
-    count
+                })
+                .sum()
+        })
+        .expect("words only get shorter")
 }
 
 #[tracing::instrument]
@@ -107,7 +86,13 @@ pub fn aoc_2024_19_b(input: &str) -> usize {
         .sum()
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 19,
+    title: "Linen Layout",
+    a: aoc_2024_19_a,
+    b: aoc_2024_19_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -120,8 +105,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_19_a() {
-        assert_eq!(super::aoc_2024_19_a(super::INPUT), 353);
+        let input = aoc_utils::input::workspace(2024, 19);
+        aoc_utils::ledger::assert_answer(2024, 19, 'a', super::aoc_2024_19_a(&input));
     }
 
     #[rstest]
@@ -144,8 +131,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_19_b() {
-        assert_eq!(super::aoc_2024_19_b(super::INPUT), 880877787214477);
+        let input = aoc_utils::input::workspace(2024, 19);
+        aoc_utils::ledger::assert_answer(2024, 19, 'b', super::aoc_2024_19_b(&input));
     }
 
     const TEST_INPUT: &str = "
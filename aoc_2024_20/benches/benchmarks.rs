@@ -1,16 +1,22 @@
+use std::sync::LazyLock;
+
 use aoc_2024_20::*;
 
+/// the input of the day, read before the benches
+static INPUT: LazyLock<String> = LazyLock::new(|| aoc_utils::input::workspace(2024, 20));
+
 fn main() {
+    LazyLock::force(&INPUT);
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
 fn part1() {
-    aoc_2024_20_a(divan::black_box(INPUT), 100);
+    aoc_2024_20_a(divan::black_box(&INPUT), 100);
 }
 
 #[divan::bench]
 fn part2() {
-    aoc_2024_20_b(divan::black_box(INPUT), 100);
+    aoc_2024_20_b(divan::black_box(&INPUT), 100);
 }
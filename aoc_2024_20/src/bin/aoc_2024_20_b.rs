@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2024_20::{aoc_2024_20_b, INPUT};
+use aoc_2024_20::aoc_2024_20_b;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2024, 20);
 
-    println!("Part B {}", aoc_2024_20_b(INPUT, 100));
+    println!(
+        "Part B {}",
+        aoc_utils::heap::profile(2024, 20, 'b', || aoc_2024_20_b(&input, 100))
+    );
 }
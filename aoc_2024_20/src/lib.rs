@@ -216,7 +216,13 @@ pub fn aoc_2024_20_b(input: &str, threshold: i32) -> usize {
     find_cheats(input, 20, threshold)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 20,
+    title: "Race Condition",
+    a: |input, params| aoc_2024_20_a(input, params.get_or("threshold", 100)?),
+    b: |input, params| aoc_2024_20_b(input, params.get_or("threshold", 100)?),
+}
 
 #[cfg(test)]
 mod tests {
@@ -229,8 +235,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_20_a() {
-        assert_eq!(super::aoc_2024_20_a(super::INPUT, 100), 1293);
+        let input = aoc_utils::input::workspace(2024, 20);
+        aoc_utils::ledger::assert_answer(2024, 20, 'a', super::aoc_2024_20_a(&input, 100));
     }
 
     #[rstest]
@@ -242,8 +250,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_20_b() {
-        assert_eq!(super::aoc_2024_20_b(super::INPUT, 100), 977747);
+        let input = aoc_utils::input::workspace(2024, 20);
+        aoc_utils::ledger::assert_answer(2024, 20, 'b', super::aoc_2024_20_b(&input, 100));
     }
 
     const TEST_INPUT: &str = "
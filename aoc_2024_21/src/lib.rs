@@ -114,11 +114,12 @@
 */
 // use aoc_utils::grid::Grid;
 
+use aoc_utils::graph::DistanceMatrix;
 use std::{collections::BTreeMap, fmt::Write, iter::once};
 
 struct KeypadSeries<'a> {
     keypads: Vec<Graph<'a>>,
-    distances: Vec<DistanceMatrix>,
+    distances: Vec<KeyDistances>,
 }
 
 impl<'a> std::fmt::Debug for KeypadSeries<'a> {
@@ -191,15 +192,14 @@ impl<'a> Graph<'a> {
     }
 }
 
+/// distances between the keys of a keypad, ids are the index in keys
 #[derive(Clone, PartialEq, Eq)]
-struct DistanceMatrix {
-    matrix: Vec<u32>,
+struct KeyDistances {
     keys: Vec<char>,
-    prev: Vec<usize>,
-    len: usize,
+    distances: DistanceMatrix,
 }
 
-impl std::fmt::Debug for DistanceMatrix {
+impl std::fmt::Debug for KeyDistances {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Distances")?;
         self.format_matrix(f)?;
@@ -209,11 +209,8 @@ impl std::fmt::Debug for DistanceMatrix {
     }
 }
 
-impl DistanceMatrix {
+impl KeyDistances {
     fn format_matrix(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let len = self.keys.len();
-        let index = |from: usize, to: usize| from * len + to;
-
         // writeln!(f, "{}", title)?;
         writeln!(
             f,
@@ -229,9 +226,9 @@ impl DistanceMatrix {
                 write!(
                     f,
                     "{}|",
-                    match self.matrix[index(ni, nj)] {
-                        u32::MAX => "  ∞  ".to_string(),
-                        _ => format!("{:5}", self.matrix[index(ni, nj)]),
+                    match self.distances.distance(ni, nj) {
+                        None => "  ∞  ".to_string(),
+                        Some(d) => format!("{:5}", d),
                     }
                 )?;
             }
@@ -255,9 +252,9 @@ impl DistanceMatrix {
                 write!(
                     f,
                     "{}|",
-                    match self.prev[self.index(ni, nj)] {
-                        usize::MAX => " ∞ ".to_string(),
-                        _ => format!("{:^3}", self.keys[self.prev[self.index(ni, nj)]]),
+                    match self.distances.predecessor(ni, nj) {
+                        None => " ∞ ".to_string(),
+                        Some(p) => format!("{:^3}", self.keys[p]),
                     }
                 )?;
             }
@@ -267,105 +264,41 @@ impl DistanceMatrix {
     }
 }
 
-fn floyd_warshall(graph: &Graph) -> DistanceMatrix {
+fn floyd_warshall(graph: &Graph) -> KeyDistances {
     let g = &graph.graph;
 
     let mut keys: Vec<_> = g.keys().copied().collect();
     keys.sort();
-    let len = keys.len();
-
-    // distance matrix
-    let mut matrix = vec![u32::MAX; len * len];
-
-    // previous node on path (contains index of node in keys)
-    let mut prev = vec![usize::MAX; matrix.len()];
-
-    let index = |from: usize, to: usize| from * len + to;
-
-    // fill distance to self
-    for i in 0..len {
-        let idx = index(i, i);
-        matrix[idx] = 0;
-        prev[idx] = i;
-    }
-
-    // for each edge, initial weight is 1, predecessor is node we came from
-    for k in 0..len {
-        for i in &g[&keys[k]] {
-            if let Some(n) = keys.iter().position(|v| v == i) {
-                let idx = index(k, n);
-                // println!("'{}' -> '{}' ({}->{}) [{}]", &keys[k], i, k, n, idx);
 
-                matrix[idx] = 1;
-                prev[idx] = k;
-            }
-        }
-    }
-
-    // print_matrix("Flood Distances", &matrix, &keys);
-    // now calculate the transitive distance/weight
-    for k in 0..len {
-        for i in 0..len {
-            for j in 0..len {
-                let idx = index(i, j);
-                assert!(
-                    idx < matrix.len(),
-                    "matrix index out of bounds for {},{}",
-                    i,
-                    j
-                );
-
-                // do not add to infiniity
-                if matrix[index(i, k)] < u32::MAX && matrix[index(k, j)] < u32::MAX {
-                    if matrix[idx] > matrix[index(i, k)] + matrix[index(k, j)] {
-                        matrix[idx] = matrix[index(i, k)] + matrix[index(k, j)];
-                        prev[idx] = prev[index(k, j)];
-                    }
-                }
-            }
-        }
-    }
-
-    let dm = DistanceMatrix {
-        matrix,
-        keys,
-        prev,
-        len,
-    };
+    // for each edge, initial weight is 1
+    let edges = keys.iter().enumerate().flat_map(|(k, key)| {
+        g[key]
+            .iter()
+            .flat_map(|i| keys.iter().position(|v| v == i))
+            .map(move |n| (k, n, 1))
+            .collect::<Vec<_>>()
+    });
+    let distances = DistanceMatrix::floyd_warshall(keys.len(), edges);
 
     // println!("{}: {:?}", graph.name, dm);
-    dm
+    KeyDistances { keys, distances }
 }
 
-impl DistanceMatrix {
-    #[inline(always)]
-    fn index(&self, u: usize, v: usize) -> usize {
-        u * self.len + v
-    }
-
+impl KeyDistances {
     fn get_path(&self, from: char, to: char) -> Vec<char> {
         let Some(u) = self.keys.iter().position(|k| k == &from) else {
             panic!("unknown key {}", from)
         };
-        let Some(mut v) = self.keys.iter().position(|k| k == &to) else {
+        let Some(v) = self.keys.iter().position(|k| k == &to) else {
             panic!("unknown key {}", to)
         };
 
-        let idx = self.index(u, v);
-
-        if self.prev[idx] == usize::MAX {
+        let Some(path) = self.distances.path(u, v) else {
             // with our graphs here, it is an error if there is no path
             unreachable!("All nodes should be reachable from each other");
-            // return [];
-        }
+        };
 
-        let mut path = Vec::from([self.keys[v]]);
-        while u != v {
-            v = self.prev[self.index(u, v)];
-            path.push(self.keys[v]);
-        }
-        path.reverse();
-        path
+        path.into_iter().map(|i| self.keys[i]).collect()
     }
 
     // convert a shortest path between two vertices to a list of keypad directions
@@ -486,7 +419,11 @@ pub fn aoc_2024_21_b(_input: &str) -> usize {
     0
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 21,
+    title: "Keypad Conundrum",
+}
 
 #[cfg(test)]
 mod tests {
@@ -501,8 +438,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_21_a() {
-        assert_eq!(super::aoc_2024_21_a(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 21);
+        assert_eq!(super::aoc_2024_21_a(&input), 0);
     }
 
     #[rstest]
@@ -513,8 +452,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_21_b() {
-        assert_eq!(super::aoc_2024_21_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 21);
+        assert_eq!(super::aoc_2024_21_b(&input), 0);
     }
 
     #[test]
@@ -523,25 +464,34 @@ mod tests {
 
         let dm = floyd_warshall(&g);
 
-        #[rustfmt::skip]
-        assert_eq!(
-            dm.matrix,
-            vec![0, 2, 2, 3, 1,
-                 2, 0, 2, 1, 1,
-                 2, 2, 0, 1, 1,
-                 3, 1, 1, 0, 2,
-                 1, 1, 1, 2, 0]
-        );
+        let matrix = (0..dm.keys.len())
+            .flat_map(|i| (0..dm.keys.len()).map(move |j| (i, j)))
+            .map(|(i, j)| dm.distances.distance(i, j).unwrap())
+            .collect::<Vec<_>>();
 
         #[rustfmt::skip]
         assert_eq!(
-            dm.prev,
+            matrix,
             vec![0, 2, 2, 3, 1,
                  2, 0, 2, 1, 1,
                  2, 2, 0, 1, 1,
                  3, 1, 1, 0, 2,
                  1, 1, 1, 2, 0]
         );
+
+        // every shortest path has as many steps as its distance
+        for from in dm.keys.clone() {
+            for to in dm.keys.clone() {
+                let (i, j) = (
+                    dm.keys.iter().position(|&k| k == from).unwrap(),
+                    dm.keys.iter().position(|&k| k == to).unwrap(),
+                );
+                assert_eq!(
+                    dm.get_path(from, to).len() as u64 - 1,
+                    dm.distances.distance(i, j).unwrap()
+                );
+            }
+        }
     }
 
     #[test]
@@ -155,19 +155,13 @@ pub fn record(seeds: Vec<i64>) -> i64 {
         let mut window = VecDeque::from_iter(generator.take(4));
 
         while i < CYCLES {
-            // we always stop at first encounter
-            // can we break if we see a sequence the second time?
-            if seen.contains(&window) {
-                continue;
-            }
-
+            // we always stop at first encounter, later ones of the same sequence are ignored
+            // (skipping them with continue never moved the window on)
             // would it be better to convert the sequence to a number first? (inverse of sequence)
             // probably less memory but more runtime. Benchmark it.
-            seen.insert(window.clone());
-            record
-                .entry(window.clone())
-                .and_modify(|sum| *sum *= generator.price)
-                .or_insert(generator.price);
+            if seen.insert(window.clone()) {
+                *record.entry(window.clone()).or_insert(0) += generator.price;
+            }
 
             i += 1;
 
@@ -219,10 +213,17 @@ pub fn aoc_2024_22_b_all_sequences(input: &str) -> i64 {
     brute_force_all_sequences(seeds)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 22,
+    title: "Monkey Market",
+    a: aoc_2024_22_a,
+    b: aoc_2024_22_b,
+}
 
 #[cfg(test)]
 mod tests {
+    use aoc_utils::check::{Check, Rng, from_fn, shrink_lines};
     use rstest::rstest;
 
     #[rstest]
@@ -257,8 +258,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_22_a() {
-        assert_eq!(super::aoc_2024_22_a(super::INPUT), 17960270302);
+        let input = aoc_utils::input::workspace(2024, 22);
+        aoc_utils::ledger::assert_answer(2024, 22, 'a', super::aoc_2024_22_a(&input));
     }
 
     #[rstest]
@@ -330,8 +333,30 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_22_b() {
-        assert_eq!(super::aoc_2024_22_b(super::INPUT), 2042);
+        let input = aoc_utils::input::workspace(2024, 22);
+        aoc_utils::ledger::assert_answer(2024, 22, 'b', super::aoc_2024_22_b(&input));
+    }
+
+    #[test]
+    fn record_should_agree_with_brute_force() {
+        let brute_force = |input: &String| super::brute_force_all_sequences(super::parse(input));
+        let record = |input: &String| super::record(super::parse(input));
+
+        // brute force tries all 130321 sequences per buyer, keep it small
+        Check::new(from_fn(buyers).shrink_with(shrink_lines))
+            .cases(4)
+            .max_size(3)
+            .assert_agree(&[("brute force", &brute_force), ("record", &record)]);
+    }
+
+    /// initial secrets of size buyers
+    fn buyers(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| rng.range(1..super::RNG_MOD).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     const TEST_INPUT: &str = "1
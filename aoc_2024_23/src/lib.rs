@@ -1,47 +1,27 @@
-use std::collections::BTreeSet;
-
 // #[allow(dead_code)]
 /* Find the task under https://adventofcode.com/2024/day/23
     Solution idea:
-
+    a: all triangles with at least one computer starting with t
+    b: maximum clique (Bron–Kerbosch)
 */
-// use petgraph::dot::{Config, Dot};
-use petgraph::graphmap::UnGraphMap;
+use aoc_utils::graph::{BitGraph, Graph};
 
-fn parse(input: &str) -> UnGraphMap<&str, ()> {
-    UnGraphMap::<_, ()>::from_edges(input.trim().lines().flat_map(|l| l.trim().split_once('-')))
+fn parse(input: &str) -> Graph<'_> {
+    let mut g = Graph::undirected();
+    for (a, b) in input.trim().lines().flat_map(|l| l.trim().split_once('-')) {
+        g.add_edge(a, b);
+    }
+    g
 }
 
 #[tracing::instrument]
 pub fn aoc_2024_23_a(input: &str) -> usize {
     let g = parse(input);
-    // println!("{:?}", Dot::with_config(&g, &[Config::EdgeNoLabel]));
-
-    let t_nodes = &g.nodes().filter(|n| n.starts_with('t')).collect::<Vec<_>>();
-
-    let mut t_sets = BTreeSet::new();
-    for t in t_nodes {
-        // general form of cross product:  let cross = ys.flat_map(|y| xs.clone().map(move |x| (x, y)));
-        let sets = g
-            .neighbors(t)
-            .flat_map(|n1| g.neighbors(t).map(move |n2| (n1, n2)))
-            // every pair only once and pairs must be different and both must be neighbors
-            // how to use is_adjacent?
-            .filter(|pair| g.neighbors(pair.0).any(|b| b == pair.1))
-            .map(|p| {
-                let mut set = vec![t, p.0, p.1];
-                set.sort();
-                set
-            })
-            .collect::<Vec<_>>();
-
-        println!("Sets from {t}: {sets:?}");
-
-        t_sets.extend(sets);
-    }
-    // println!("{t_sets:?}");
+    // println!("{}", g.to_dot());
 
-    t_sets.len()
+    BitGraph::from(&g)
+        .k_cliques(3, |n| g.label(n).starts_with('t'))
+        .len()
 }
 
 #[tracing::instrument]
@@ -49,38 +29,20 @@ pub fn aoc_2024_23_b(input: &str) -> String {
     let g = parse(input);
 
     // tarjan is not the right tool, it finds all strongly connected components, not a clique
+    // growing a clique greedily from each node is not guaranteed to find the maximum one
 
-    // find  maximum clique, do not limit to nodes starting with t
-    // BTreeSet keeps the sets sorted
-    let mut max_clique = BTreeSet::new();
-    let nodes = &g.nodes().collect::<Vec<_>>();
-
-    for t in nodes {
-        let clique = g
-            .neighbors(t)
-            // starting with t, add neighbor n if all current members of clique are neighbors of n
-            .fold(BTreeSet::from([*t]), |mut clique, n| {
-                if clique.iter().all(|in_clique| {
-                    g.neighbors(n)
-                        .any(|neighbor_of_n| *in_clique == neighbor_of_n)
-                }) {
-                    clique.insert(n);
-                }
-                clique
-            });
-
-        // println!("Clique from {t}: {clique:?}");
-
-        if clique.len() > max_clique.len() {
-            max_clique = clique;
-        }
-    }
-    // println!("{t_sets:?}");
-
-    max_clique.iter().cloned().collect::<Vec<&str>>().join(",")
+    let mut max_clique = g.labels(&BitGraph::from(&g).maximum_clique());
+    max_clique.sort();
+    max_clique.join(",")
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 23,
+    title: "LAN Party",
+    a: aoc_2024_23_a,
+    b: aoc_2024_23_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -93,8 +55,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_23_a() {
-        assert_eq!(super::aoc_2024_23_a(super::INPUT), 1284);
+        let input = aoc_utils::input::workspace(2024, 23);
+        aoc_utils::ledger::assert_answer(2024, 23, 'a', super::aoc_2024_23_a(&input));
     }
 
     #[rstest]
@@ -104,12 +68,11 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_23_b() {
+        let input = aoc_utils::input::workspace(2024, 23);
         // not "ac,ag,jd,jl,jz,kq,lw,nf,pp,sj,tc,ua", do not limit to t_nodes as start
-        assert_eq!(
-            super::aoc_2024_23_b(super::INPUT),
-            "bv,cm,dk,em,gs,jv,ml,oy,qj,ri,uo,xk,yw"
-        );
+        aoc_utils::ledger::assert_answer(2024, 23, 'b', super::aoc_2024_23_b(&input));
     }
 
     const TEST_INPUT: &str = "
@@ -22,9 +22,11 @@
         (1,1) => z=0, Carry=1
 
 */
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::fmt::{Display, Write};
 
+use aoc_utils::{check::Rng, graph::toposort};
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -186,61 +188,40 @@ impl System<'_> {
 
     fn execute(&mut self) {
         // execute_in_topological_order
-        // kind of Kahn' algorithm https://en.wikipedia.org/wiki/Topological_sorting
-
-        // nodes with a value are incoming without dependency
-        let mut has_value = VecDeque::from_iter(self.values.keys().cloned());
-        let mut order_no = 0;
-
-        while let Some(n) = has_value.pop_front() {
-            // does the value exists or do we need to calculate it
-            if !self.values.contains_key(n) {
-                let gate = &self.gates[n];
-                // both inputs must exist now
-                let in1 = self.values[gate.input1];
-                let in2 = self.values[gate.input2];
-
-                let value = match gate.op {
-                    Op::And => in1 & in2,
-                    Op::Or => in1 | in2,
-                    Op::Xor => in1 ^ in2,
-                };
-
-                self.values.insert(gate.name, value);
-
-                let rank1 = self
-                    .gates
-                    .get(gate.input1)
-                    .map(|g| g.rank)
-                    .unwrap_or_default();
-                let rank2 = self
-                    .gates
-                    .get(gate.input2)
-                    .map(|g| g.rank)
-                    .unwrap_or_default();
-
-                let Some(gate) = self.gates.get_mut(n) else {
-                    unreachable!()
-                };
-
-                // remember topological order
-                gate.order = order_no;
-                gate.rank = rank1.max(rank2) + 1;
-
-                order_no += 1;
-            }
-
-            // find all gates where the current is input. If both inputs are known, add gate to queue
-            // (prune graph in Kahn's algrorithmus)
-            for gate in self.gates.values() {
-                // do we know both values now?
-                if gate.input1 == n && self.values.contains_key(&gate.input2) {
-                    has_value.push_back(gate.name);
-                }
-                if self.values.contains_key(gate.input1) && gate.input2 == n {
-                    has_value.push_back(gate.name);
-                }
-            }
+        // nodes with a value are incoming without dependency, every gate depends on both inputs
+        let wires = self
+            .gates
+            .values()
+            .flat_map(|g| [(g.input1, g.name), (g.input2, g.name)]);
+        let sorted = toposort(self.values.keys().cloned(), wires).expect("circuit contains a loop");
+
+        let gate_names = sorted
+            .order
+            .iter()
+            .filter(|n| self.gates.contains_key(*n))
+            .cloned()
+            .collect::<Vec<_>>();
+        for (order_no, n) in gate_names.into_iter().enumerate() {
+            let gate = &self.gates[n];
+            // both inputs must exist now
+            let in1 = self.values[gate.input1];
+            let in2 = self.values[gate.input2];
+
+            let value = match gate.op {
+                Op::And => in1 & in2,
+                Op::Or => in1 | in2,
+                Op::Xor => in1 ^ in2,
+            };
+
+            self.values.insert(n, value);
+
+            let Some(gate) = self.gates.get_mut(n) else {
+                unreachable!()
+            };
+
+            // remember topological order
+            gate.order = order_no as u32;
+            gate.rank = sorted.rank[n] as u32;
         }
 
         println!("Topological order: {:?}", self.gates);
@@ -356,7 +337,110 @@ pub fn aoc_2024_24_b(input: &str) -> u64 {
     z
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+/// ripple carry adder of bits full adders with the outputs of swaps gate pairs crossed.
+/// Gives the input and the sorted crossed wires, the answer of part b.
+pub fn generate_swapped(seed: u64, bits: usize, swaps: usize) -> (String, Vec<String>) {
+    let mut rng = Rng::new(seed);
+    let bits = bits.clamp(1, 63);
+
+    // 3 letter wire names, x.., y.. and z.. are taken by values and outputs
+    let mut taken = HashSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let name = [rng.range(0..23), rng.range(0..26), rng.range(0..26)]
+            .map(|c| char::from(b'a' + c as u8))
+            .iter()
+            .collect::<String>();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut gates: Vec<(String, &str, String, String)> = Vec::new();
+    let mut carry = String::new();
+    for bit in 0..bits {
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let carry_out = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            wire(&mut rng)
+        };
+        if bit == 0 {
+            gates.push((x.clone(), "XOR", y.clone(), z));
+            gates.push((x, "AND", y, carry_out.clone()));
+        } else {
+            let (sum, carry_1, carry_2) = (wire(&mut rng), wire(&mut rng), wire(&mut rng));
+            gates.push((x.clone(), "XOR", y.clone(), sum.clone()));
+            gates.push((x, "AND", y, carry_1.clone()));
+            gates.push((sum.clone(), "XOR", carry.clone(), z));
+            gates.push((sum, "AND", carry, carry_2.clone()));
+            gates.push((carry_1, "OR", carry_2, carry_out.clone()));
+        }
+        carry = carry_out;
+    }
+
+    let values = (0..bits)
+        .flat_map(|bit| ['x', 'y'].map(|v| format!("{v}{bit:02}")))
+        .collect::<Vec<_>>();
+    let mut crossed = Vec::new();
+    let mut attempts = 0;
+    while crossed.len() < 2 * swaps.min(gates.len() / 2) && attempts < 100 * gates.len() {
+        attempts += 1;
+        let (a, b) = (rng.below(gates.len()), rng.below(gates.len()));
+        if a == b || crossed.contains(&gates[a].3) || crossed.contains(&gates[b].3) {
+            continue;
+        }
+
+        let (out_a, out_b) = (gates[a].3.clone(), gates[b].3.clone());
+        gates[a].3 = out_b.clone();
+        gates[b].3 = out_a.clone();
+        let wires = gates.iter().flat_map(|(in1, _, in2, out)| {
+            [(in1.clone(), out.clone()), (in2.clone(), out.clone())]
+        });
+        if toposort(values.iter().cloned(), wires).is_ok() {
+            crossed.extend([out_a, out_b]);
+        } else {
+            // crossing made a loop, undo it
+            gates[a].3 = out_a;
+            gates[b].3 = out_b;
+        }
+    }
+    crossed.sort();
+
+    let mut input = values
+        .iter()
+        .map(|v| format!("{v}: {}\n", rng.below(2)))
+        .collect::<String>();
+    input.push('\n');
+    rng.shuffle(&mut gates);
+    for (in1, op, in2, out) in gates {
+        let (in1, in2) = if rng.chance(0.5) {
+            (in1, in2)
+        } else {
+            (in2, in1)
+        };
+        input.push_str(&format!("{in1} {op} {in2} -> {out}\n"));
+    }
+
+    (input, crossed)
+}
+
+/// adder of scale bits with 4 crossed pairs like the real one of 45 bits
+pub fn generate(seed: u64, scale: usize) -> String {
+    generate_swapped(seed, scale, 4).0
+}
+
+aoc_utils::solution! {
+    year: 2024,
+    day: 24,
+    title: "Crossed Wires",
+    a: aoc_2024_24_a,
+    b: aoc_2024_24_b,
+    generate: generate,
+}
 
 #[cfg(test)]
 mod tests {
@@ -370,8 +454,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_24_a() {
-        assert_eq!(super::aoc_2024_24_a(super::INPUT), 43559017878162);
+        let input = aoc_utils::input::workspace(2024, 24);
+        aoc_utils::ledger::assert_answer(2024, 24, 'a', super::aoc_2024_24_a(&input));
     }
 
     #[rstest]
@@ -381,8 +467,33 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_24_b() {
-        assert_eq!(super::aoc_2024_24_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 24);
+        assert_eq!(super::aoc_2024_24_b(&input), 0);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(8)]
+    #[case(45)]
+    fn generate_swapped_should_add_without_swaps(#[case] bits: usize) {
+        let (input, crossed) = generate_swapped(bits as u64, bits, 0);
+        assert!(crossed.is_empty());
+
+        let (_, system) = parse(&input).unwrap();
+        let sum = system.value_of("x") + system.value_of("y");
+        assert_eq!(super::aoc_2024_24_a(&input), sum);
+    }
+
+    #[test]
+    fn generate_swapped_should_cross_pairs() {
+        let (input, crossed) = generate_swapped(3, 45, 4);
+
+        assert_eq!(crossed.len(), 8);
+        assert!(crossed.iter().all(|w| input.contains(&format!("-> {w}\n"))));
+        // still a circuit without loops
+        super::aoc_2024_24_a(&input);
     }
 
     #[test]
@@ -144,7 +144,12 @@ pub fn aoc_2024_25_b(_input: &str) -> usize {
     0
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2024,
+    day: 25,
+    title: "Code Chronicle",
+    a: aoc_2024_25_a,
+}
 
 #[cfg(test)]
 mod tests {
@@ -245,8 +250,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_25_a() {
-        assert_eq!(super::aoc_2024_25_a(super::INPUT), 3301);
+        let input = aoc_utils::input::workspace(2024, 25);
+        aoc_utils::ledger::assert_answer(2024, 25, 'a', super::aoc_2024_25_a(&input));
     }
 
     #[rstest]
@@ -256,8 +263,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2024_25_b() {
-        assert_eq!(super::aoc_2024_25_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2024, 25);
+        assert_eq!(super::aoc_2024_25_b(&input), 0);
     }
 
     const TEST_INPUT: &str = "
@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2025_01::{aoc_2025_01_b, INPUT};
+use aoc_2025_01::aoc_2025_01_b;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2025, 1);
 
-    println!("Part B {}", aoc_2025_01_b(INPUT));
+    println!(
+        "Part B {}",
+        aoc_utils::heap::profile(2025, 1, 'b', || aoc_2025_01_b(&input))
+    );
 }
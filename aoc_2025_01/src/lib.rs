@@ -77,7 +77,13 @@ pub fn aoc_2025_01_b(input: &str) -> usize {
     x
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 1,
+    title: "Secret Entrance",
+    a: aoc_2025_01_a,
+    b: aoc_2025_01_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -90,8 +96,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_01_a() {
-        assert_eq!(super::aoc_2025_01_a(super::INPUT), 1152);
+        let input = aoc_utils::input::workspace(2025, 1);
+        aoc_utils::ledger::assert_answer(2025, 1, 'a', super::aoc_2025_01_a(&input));
     }
 
     // dial starts at 50
@@ -112,8 +120,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_01_b() {
-        assert_eq!(super::aoc_2025_01_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2025, 1);
+        assert_eq!(super::aoc_2025_01_b(&input), 0);
     }
 
     #[test]
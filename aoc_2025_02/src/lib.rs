@@ -125,7 +125,13 @@ fn test_pattern(i: i64) -> bool {
     false
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 2,
+    title: "Gift Shop",
+    a: aoc_2025_02_a,
+    b: aoc_2025_02_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -138,8 +144,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_02_a() {
-        assert_eq!(super::aoc_2025_02_a(super::INPUT), 28146997880);
+        let input = aoc_utils::input::workspace(2025, 2);
+        aoc_utils::ledger::assert_answer(2025, 2, 'a', super::aoc_2025_02_a(&input));
     }
 
     #[rstest]
@@ -149,8 +157,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_02_b() {
-        assert_eq!(super::aoc_2025_02_b(super::INPUT), 40028128307);
+        let input = aoc_utils::input::workspace(2025, 2);
+        aoc_utils::ledger::assert_answer(2025, 2, 'b', super::aoc_2025_02_b(&input));
     }
 
     #[rstest]
@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2025_03::{aoc_2025_03_a, INPUT};
+use aoc_2025_03::aoc_2025_03_a;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2025, 3);
 
-    println!("Part A {}", aoc_2025_03_a(INPUT));
+    println!(
+        "Part A {}",
+        aoc_utils::heap::profile(2025, 3, 'a', || aoc_2025_03_a(&input))
+    );
 }
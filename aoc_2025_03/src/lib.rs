@@ -69,7 +69,13 @@ pub fn aoc_2025_03_b(input: &str) -> usize {
         .sum()
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 3,
+    title: "Lobby",
+    a: aoc_2025_03_a,
+    b: aoc_2025_03_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -82,8 +88,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_03_a() {
-        assert_eq!(super::aoc_2025_03_a(super::INPUT), 17452);
+        let input = aoc_utils::input::workspace(2025, 3);
+        aoc_utils::ledger::assert_answer(2025, 3, 'a', super::aoc_2025_03_a(&input));
     }
 
     #[rstest]
@@ -93,8 +101,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_03_b() {
-        assert_eq!(super::aoc_2025_03_b(super::INPUT), 173300819005913);
+        let input = aoc_utils::input::workspace(2025, 3);
+        aoc_utils::ledger::assert_answer(2025, 3, 'b', super::aoc_2025_03_b(&input));
     }
 
     #[rstest]
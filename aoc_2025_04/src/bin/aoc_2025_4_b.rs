@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2025_04::{aoc_2025_04_b, INPUT};
+use aoc_2025_04::aoc_2025_04_b;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2025, 4);
 
-    println!("Part B {}", aoc_2025_04_b(INPUT));
+    println!(
+        "Part B {}",
+        aoc_utils::heap::profile(2025, 4, 'b', || aoc_2025_04_b(&input))
+    );
 }
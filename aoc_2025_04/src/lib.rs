@@ -48,7 +48,13 @@ pub fn aoc_2025_04_b(input: &str) -> usize {
     removed
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 4,
+    title: "Printing Department",
+    a: aoc_2025_04_a,
+    b: aoc_2025_04_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -61,8 +67,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_04_a() {
-        assert_eq!(super::aoc_2025_04_a(super::INPUT), 1537);
+        let input = aoc_utils::input::workspace(2025, 4);
+        aoc_utils::ledger::assert_answer(2025, 4, 'a', super::aoc_2025_04_a(&input));
     }
 
     #[rstest]
@@ -72,8 +80,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_04_b() {
-        assert_eq!(super::aoc_2025_04_b(super::INPUT), 8707);
+        let input = aoc_utils::input::workspace(2025, 4);
+        aoc_utils::ledger::assert_answer(2025, 4, 'b', super::aoc_2025_04_b(&input));
     }
 
     const TEST_INPUT: &str = "
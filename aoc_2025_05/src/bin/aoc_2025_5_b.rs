@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2025_05::{aoc_2025_05_b, INPUT};
+use aoc_2025_05::aoc_2025_05_b;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2025, 5);
 
-    println!("Part B {}", aoc_2025_05_b(INPUT));
+    println!(
+        "Part B {:?}",
+        aoc_utils::heap::profile(2025, 5, 'b', || aoc_2025_05_b(&input))
+    );
 }
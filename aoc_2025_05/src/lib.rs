@@ -1,5 +1,7 @@
 use std::{ops::RangeInclusive, str::FromStr};
 
+use aoc_utils::error::AocError;
+
 use winnow::Result;
 use winnow::ascii::dec_uint;
 use winnow::ascii::line_ending;
@@ -25,10 +27,12 @@ struct Ingredients {
 }
 
 impl FromStr for Ingredients {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse_ingredients.parse(s).map_err(|e| e.to_string())
+        parse_ingredients
+            .parse(s)
+            .map_err(|e| AocError::parse(s, e.offset(), 1, "expected ranges like 3-5, an empty line and ids"))
     }
 
     // // iterator parsing version
@@ -87,7 +91,7 @@ fn parse_ingredients<'s>(i: &mut Stream<'s>) -> Result<Ingredients> {
 }
 
 #[tracing::instrument]
-pub fn aoc_2025_05_a(input: &str) -> Result<usize, String> {
+pub fn aoc_2025_05_a(input: &str) -> Result<usize, AocError> {
     let ingredients: Ingredients = input.parse()?;
 
     let mut count = 0;
@@ -109,7 +113,7 @@ pub fn aoc_2025_05_a(input: &str) -> Result<usize, String> {
 }
 
 #[tracing::instrument]
-pub fn aoc_2025_05_b(input: &str) -> Result<usize, String> {
+pub fn aoc_2025_05_b(input: &str) -> Result<usize, AocError> {
     let ingredients: Ingredients = input.parse()?;
 
     // sort ranges by start so we don'T have to check the cardesian product of all ranges
@@ -153,7 +157,13 @@ pub fn aoc_2025_05_b(input: &str) -> Result<usize, String> {
     Ok(total_count)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 5,
+    title: "Cafeteria",
+    a: aoc_2025_05_a,
+    b: aoc_2025_05_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -169,8 +179,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_05_a() {
-        assert_eq!(super::aoc_2025_05_a(super::INPUT), Ok(744));
+        let input = aoc_utils::input::workspace(2025, 5);
+        aoc_utils::ledger::assert_answer(2025, 5, 'a', super::aoc_2025_05_a(&input));
     }
 
     #[rstest]
@@ -180,8 +192,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_05_b() {
-        assert_eq!(super::aoc_2025_05_b(super::INPUT), Ok(347468726696961));
+        let input = aoc_utils::input::workspace(2025, 5);
+        aoc_utils::ledger::assert_answer(2025, 5, 'b', super::aoc_2025_05_b(&input));
     }
 
     #[rstest]
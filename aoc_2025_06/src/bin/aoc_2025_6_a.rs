@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2025_06::{aoc_2025_06_a, INPUT};
+use aoc_2025_06::aoc_2025_06_a;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2025, 6);
 
-    println!("Part A {}", aoc_2025_06_a(INPUT));
+    println!(
+        "Part A {}",
+        aoc_utils::heap::profile(2025, 6, 'a', || aoc_2025_06_a(&input))
+    );
 }
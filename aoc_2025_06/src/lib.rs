@@ -131,7 +131,13 @@ pub fn aoc_2025_06_b(input: &str) -> u64 {
     dbg!(result)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 6,
+    title: "Trash Compactor",
+    a: aoc_2025_06_a,
+    b: aoc_2025_06_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -144,8 +150,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_06_a() {
-        assert_eq!(super::aoc_2025_06_a(super::INPUT), 6100348226985);
+        let input = aoc_utils::input::workspace(2025, 6);
+        aoc_utils::ledger::assert_answer(2025, 6, 'a', super::aoc_2025_06_a(&input));
     }
 
     #[rstest]
@@ -155,8 +163,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_06_b() {
-        assert_eq!(super::aoc_2025_06_b(super::INPUT), 12377473011151);
+        let input = aoc_utils::input::workspace(2025, 6);
+        aoc_utils::ledger::assert_answer(2025, 6, 'b', super::aoc_2025_06_b(&input));
     }
 
     const TEST_INPUT: &str = "123 328  51 64 
@@ -1,9 +1,13 @@
 // binary for perf tests etc.
-use aoc_2025_07::{aoc_2025_07_b, INPUT};
+use aoc_2025_07::aoc_2025_07_b;
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace(2025, 7);
 
-    println!("Part B {}", aoc_2025_07_b(INPUT));
+    println!(
+        "Part B {:?}",
+        aoc_utils::heap::profile(2025, 7, 'b', || aoc_2025_07_b(&input))
+    );
 }
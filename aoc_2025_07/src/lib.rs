@@ -3,15 +3,26 @@
     Solution idea:
 
 */
-use aoc_utils::grid::Grid;
+use aoc_utils::{error::AocError, grid::Grid};
 use glam::IVec2;
 use std::collections::BTreeSet;
 
+/// the manifold and the start S, which becomes empty space
+fn parse(input: &str) -> Result<(Grid<char>, IVec2), AocError> {
+    let mut grid = input
+        .parse::<Grid<char>>()
+        .map_err(|_| AocError::parse(input, 0, input.len(), "expected a grid of rows of equal width"))?;
+    if grid.find('S').is_none() {
+        return Err(AocError::precondition("no start S in the manifold"));
+    }
+    let start = grid.find_cursor('S', 'S');
+    Ok((grid, start))
+}
+
 #[tracing::instrument]
-pub fn aoc_2025_07_a(input: &str) -> Result<usize, String> {
-    let mut grid = input.parse::<Grid<char>>().expect("valid grid");
+pub fn aoc_2025_07_a(input: &str) -> Result<usize, AocError> {
+    let (mut grid, start) = parse(input)?;
     let width = grid.width as i32;
-    let start = grid.find_cursor('S', 'S');
 
     // list all active x coordinates of beams in a sorted set
     let mut beams = BTreeSet::new();
@@ -63,10 +74,9 @@ impl Beam {
 }
 
 #[tracing::instrument]
-pub fn aoc_2025_07_b(input: &str) -> Result<usize, String> {
-    let mut grid = input.parse::<Grid<char>>().expect("valid grid");
+pub fn aoc_2025_07_b(input: &str) -> Result<usize, AocError> {
+    let (mut grid, start) = parse(input)?;
     let width = grid.width as i32;
-    let start = grid.find_cursor('S', 'S');
 
     // list all active x coordinates of beams in a sorted set
     let mut beams = BTreeSet::new();
@@ -150,7 +160,13 @@ pub fn aoc_2025_07_b(input: &str) -> Result<usize, String> {
     Ok(split_count)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 7,
+    title: "Laboratories",
+    a: aoc_2025_07_a,
+    b: aoc_2025_07_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -163,8 +179,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_07_a() {
-        assert_eq!(super::aoc_2025_07_a(super::INPUT), Ok(1628));
+        let input = aoc_utils::input::workspace(2025, 7);
+        aoc_utils::ledger::assert_answer(2025, 7, 'a', super::aoc_2025_07_a(&input));
     }
 
     #[rstest]
@@ -174,8 +192,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_07_b() {
-        assert_eq!(super::aoc_2025_07_b(super::INPUT), Ok(0));
+        let input = aoc_utils::input::workspace(2025, 7);
+        assert_eq!(super::aoc_2025_07_b(&input), Ok(0));
     }
 
     const TEST_INPUT: &str = ".......S.......
@@ -3,59 +3,135 @@
     Solution idea:
     k-D tree for spatial queries
     Time to put one implementation into our aoc_utils
+
+    ~1000 boxes are only ~500k pairs, just sort all pairs by distance.
+    Circuits are connected components, union-find keeps track of them (aoc_utils::dsu).
+    Part b: the merge log knows which connection joined everything.
 */
-use aoc_utils::k_d_tree::IKdTree3d;
+use aoc_utils::dsu::DisjointSet;
 use glam::IVec3;
 
-#[tracing::instrument]
-pub fn aoc_2025_08_a(input: &str) -> usize {
-    let points =input.trim().lines().map(|l| {
-        let mut parts = l.split(',').map(|n| n.parse::<i32>().unwrap());
-        let x = parts.next().unwrap();
-        let y = parts.next().unwrap();
-        let z = parts.next().unwrap();
-        IVec3::new(x,y,z)
-    }).collect::<Vec<_>>();
-    let tree = IKdTree3d::new(points, 16);
-
-    
-    0
+fn parse(input: &str) -> Vec<IVec3> {
+    input
+        .trim()
+        .lines()
+        .map(|l| {
+            let mut parts = l.trim().split(',').map(|n| n.parse::<i32>().unwrap());
+            let x = parts.next().unwrap();
+            let y = parts.next().unwrap();
+            let z = parts.next().unwrap();
+            IVec3::new(x, y, z)
+        })
+        .collect::<Vec<_>>()
+}
+
+/// all pairs of boxes sorted by distance, closest first
+fn pairs_by_distance(points: &[IVec3]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::with_capacity(points.len() * points.len() / 2);
+    for a in 0..points.len() {
+        for b in a + 1..points.len() {
+            // squared distance keeps the order, i64 as coordinates are up to 100000
+            let d = (points[a] - points[b]).as_i64vec3().length_squared();
+            pairs.push((d, a, b));
+        }
+    }
+    pairs.sort_unstable();
+    pairs.into_iter().map(|(_, a, b)| (a, b)).collect()
 }
 
-#[tracing::instrument]
-pub fn aoc_2025_08_b(_input: &str) -> usize {
-    0
+#[tracing::instrument(skip(input))]
+pub fn aoc_2025_08_a(input: &str, connections: usize) -> usize {
+    let points = parse(input);
+    let mut circuits = DisjointSet::new(points.len());
+
+    for (a, b) in pairs_by_distance(&points).into_iter().take(connections) {
+        circuits.union(a, b);
+    }
+
+    circuits.component_sizes().iter().take(3).product()
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+#[tracing::instrument(skip(input))]
+pub fn aoc_2025_08_b(input: &str) -> usize {
+    let points = parse(input);
+    let mut circuits = DisjointSet::new(points.len());
+
+    for (a, b) in pairs_by_distance(&points) {
+        circuits.union(a, b);
+        if circuits.components() == 1 {
+            break;
+        }
+    }
+
+    let last = circuits
+        .connecting_merge()
+        .expect("all boxes should end up in one circuit");
+    (points[last.a].x as i64 * points[last.b].x as i64) as usize
+}
+
+aoc_utils::solution! {
+    year: 2025,
+    day: 8,
+    title: "Playground",
+    a: |input, params| aoc_2025_08_a(input, params.get_or("connections", 1000)?),
+    b: aoc_2025_08_b,
+}
 
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case(TEST_INPUT, 0)]
-    fn aoc_2025_08_a_example(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(super::aoc_2025_08_a(input), expected);
+    #[case(TEST_INPUT, 10, 40)]
+    fn aoc_2025_08_a_example(
+        #[case] input: &str,
+        #[case] connections: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(super::aoc_2025_08_a(input, connections), expected);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_08_a() {
-        assert_eq!(super::aoc_2025_08_a(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2025, 8);
+        aoc_utils::ledger::assert_answer(2025, 8, 'a', super::aoc_2025_08_a(&input, 1000));
     }
 
     #[rstest]
-    #[case(TEST_INPUT, 0)]
+    #[case(TEST_INPUT, 25272)]
     fn aoc_2025_08_b_example(#[case] input: &str, #[case] expected: usize) {
         assert_eq!(super::aoc_2025_08_b(input), expected);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_08_b() {
-        assert_eq!(super::aoc_2025_08_b(super::INPUT), 0);
+        let input = aoc_utils::input::workspace(2025, 8);
+        aoc_utils::ledger::assert_answer(2025, 8, 'b', super::aoc_2025_08_b(&input));
     }
 
-    const TEST_INPUT: &str = "";
+    const TEST_INPUT: &str = "
+    162,817,812
+    57,618,57
+    906,360,560
+    592,479,940
+    352,342,300
+    466,668,158
+    542,29,236
+    431,825,988
+    739,650,466
+    52,470,668
+    216,146,977
+    819,987,18
+    117,168,530
+    805,96,715
+    346,949,466
+    970,615,88
+    941,993,340
+    862,61,35
+    984,92,344
+    425,690,689";
 
     #[allow(dead_code)]
     const TEST_INPUT_2: &str = "";
@@ -444,7 +444,13 @@ pub fn aoc_2025_09_b(input: &str) -> usize {
         .expect("No valid rectangle found")
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 9,
+    title: "Movie Theater",
+    a: aoc_2025_09_a,
+    b: aoc_2025_09_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -458,8 +464,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_09_a() {
-        assert_eq!(super::aoc_2025_09_a(super::INPUT), 4735222687);
+        let input = aoc_utils::input::workspace(2025, 9);
+        aoc_utils::ledger::assert_answer(2025, 9, 'a', super::aoc_2025_09_a(&input));
     }
 
     #[rstest]
@@ -469,11 +477,13 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_09_b() {
+        let input = aoc_utils::input::workspace(2025, 9);
         // 4594510710 too high
         // 4586258880 too high
         // 1569262188
-        assert_eq!(super::aoc_2025_09_b(super::INPUT), 1569262188);
+        aoc_utils::ledger::assert_answer(2025, 9, 'b', super::aoc_2025_09_b(&input));
     }
 
     #[rstest]
@@ -601,8 +611,6 @@ mod tests {
     #[rstest]
     #[ignore] 
     #[case(TEST_INPUT)]
-    #[ignore] 
-    #[case(crate::INPUT)]
     fn longest_edges(#[case] input: &str) {
         let red_tiles = super::parse(input);
         let edges = super::get_polygon_edges(&red_tiles);
@@ -621,6 +629,13 @@ mod tests {
         assert!(false, "informational test, longest edges");
     }
 
+    #[test]
+    #[ignore]
+    fn longest_edges_in_input() {
+        let input = aoc_utils::input::workspace(2025, 9);
+        longest_edges(&input);
+    }
+
     const TEST_INPUT: &str = "
 7,1
 11,1
@@ -33,6 +33,14 @@
     Z3 is not usable on this machine
 
     what options are left????
+
+    Gaussian elimination over rationals (aoc_utils::linalg), no MKL, no rounding.
+    Usually a few buttons are left as free variables. Their presses are bounded by the
+    smallest requirement of the counters they touch, so we can enumerate them and keep
+    the cheapest non negative integer solution.
+
+    That is integer linear programming in disguise: minimise sum(a) with B a = r, a >= 0, a integer.
+    aoc_utils::ilp does exactly what solve_with_z3 would have done, without native libraries.
     */
 
 use std::str::FromStr;
@@ -45,8 +53,10 @@ use std::str::FromStr;
 use bitvec::prelude::*;
 use regex::Regex;
 
-use ndarray::{Array1, Array2};
-use ndarray_linalg::LeastSquaresSvdInto; // for least squares solving, consumes input matrix
+use aoc_utils::{
+    error::AocError,
+    ilp::{Outcome, Problem, Relation},
+};
 // use Z3::{Config, Context, Solver, ast::Int};
 
 #[derive(Clone, PartialEq, Eq)]
@@ -119,71 +129,30 @@ impl Machine {
     //     state
     // }
 
-    #[allow(dead_code)]
-    fn solve_with_least_squares(&self) -> Result<Vec<usize>, String> {
-        let m = self.requirement.len();
-
-        // Build matrix B
-        #[allow(non_snake_case)]
-        let B = build_B(m, &self.button_wiring);
-
-        // Build requirement vector r
-        let r = Array1::from_vec(
-            self.requirement
-                .iter()
-                .map(|&x| x as f64)
-                .collect::<Vec<f64>>(),
-        );
-        println!("B: {:?}\nr: {:?}", B, r);
-
-        // Solve least squares problem B * a = r
-        let a = B.least_squares_into(r).map_err(|e| e.to_string())?.solution;
-        println!("Least squares solution a: {:?}", a);
-
-        // Round solution to nearest integers - fail! Try 2 first multiply to inteers and then divide by gcd - also fail
-        // We could try to multiply with factors 2, 3, 4, 5,... untill all solutions are in epsilon range of integers
-        // then round and divide by greatest common divisor. Would work for sample input, but is it guaranteed to work in general?
-        // this does not give good integer solutions at all... Time to learn Z3
-        let epsilon = 1e-2;
-        let mut multiplier = 1.0;
-        let mut a_int: Vec<usize> = a
-            .iter()
-            .map(|&x| (x * multiplier).round() as usize)
-            .collect();
-        let mut deviation: f64 = a
-            .iter()
-            .zip(a_int.iter())
-            .map(|(&x, &y)| (x - y as f64).abs())
-            .sum();
-
-        while deviation > epsilon && multiplier < 100.0 {
-            multiplier = multiplier + 1.0;
+    /// Matrix B with one row per counter and one column per button
+    fn button_matrix(&self) -> Vec<Vec<i64>> {
+        (0..self.requirement.len())
+            .map(|counter| {
+                self.button_wiring
+                    .iter()
+                    .map(|button| button[counter] as i64)
+                    .collect()
+            })
+            .collect()
+    }
 
-            a_int = a
-                .iter()
-                .map(|&x| (x * multiplier).round() as usize)
-                .collect();
-            deviation = a
-                .iter()
-                .zip(a_int.iter())
-                .map(|(&x, &y)| (x - y as f64).abs())
-                .sum();
+    /// Find the number of presses per button with the least total presses so that B a = r
+    fn solve_minimal_presses(&self) -> Result<Vec<usize>, AocError> {
+        let mut problem = Problem::minimize(&vec![1i64; self.button_wiring.len()]);
+        for (row, &r) in self.button_matrix().iter().zip(&self.requirement) {
+            problem.add_constraint(row, Relation::Eq, r as i64);
         }
 
-        // rounding makes 367 instead of 366, so there will be no gcd reduction which is needed
-        println!("Rounded integer solution a_int: {:?}", a_int);
-
-        let gcd = gcd_all(&a_int);
-        if gcd > 1 {
-            for x in &mut a_int {
-                *x /= gcd;
-            }
-            println!("Divided by GCD {}: {:?}", gcd, a_int);
-        } else {
-            println!("GCD is 1, no division performed");
+        match problem.solve_integer() {
+            Outcome::Optimal { x, .. } => Ok(x.into_iter().map(|a| a as usize).collect()),
+            Outcome::Infeasible => Err(AocError::no_answer(format!("no solution for machine {:?}", self))),
+            Outcome::Unbounded => Err(AocError::no_answer(format!("unbounded solution for machine {:?}", self))),
         }
-
-        Ok(a_int)
     }
 
     // #[allow(dead_code)]
@@ -297,12 +266,15 @@ impl FromStr for Machine {
     }
 }
 
-fn parse(input: &str) -> Result<Vec<Machine>, String> {
+fn parse(input: &str) -> Result<Vec<Machine>, AocError> {
     input
         .trim()
         .lines()
-        .map(|line| line.parse::<Machine>().map_err(|e| e.to_string()))
-        .collect::<Result<Vec<Machine>, String>>()
+        .map(|line| {
+            line.parse::<Machine>()
+                .map_err(|e| AocError::parse_at(input, line, e))
+        })
+        .collect::<Result<Vec<Machine>, AocError>>()
 }
 
 // how does winnow work??? What does error \n          ^\n mean? Not worth it
@@ -455,54 +427,8 @@ where
     None // No solution found up to max_len
 }
 
-// Solving use the hard weapon: MATH
-// Solve system of linear equations via ndarray-linalg
-// no integer solution :-(
-
-// Build a 0/1 column vector of length `m` from  BitVec
-    #[allow(dead_code)]
-fn button_to_vector(m: usize, ones: &BitVec) -> Array1<f64> {
-    assert!(
-        ones.len() == m,
-        "Button BitVec length {} does not match expected length {}",
-        ones.len(),
-        m
-    );
-    ones.iter()
-        .by_vals()
-        .map(|bit| if bit { 1.0 } else { 0.0 })
-        .collect::<Array1<f64>>()
-}
-
-/// Compute greatest common divisor via Euclidean algorithm
-#[allow(dead_code)]
-fn gcd(a: usize, b: usize) -> usize {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-fn gcd_all(numbers: &[usize]) -> usize {
-    numbers.iter().cloned().reduce(gcd).unwrap_or(1)
-}
-
-/// Build matrix B by concatenating button vectors as columns
-#[allow(non_snake_case)]
-    #[allow(dead_code)]
-fn build_B(m: usize, button_masks: &[BitVec]) -> Array2<f64> {
-    let n = button_masks.len();
-    let mut B = Array2::<f64>::zeros((m, n));
-    for (j, ones) in button_masks.iter().enumerate() {
-        let col = button_to_vector(m, ones);
-        B.column_mut(j).assign(&col);
-    }
-    B
-}
-
 #[tracing::instrument]
-pub fn aoc_2025_10_a(input: &str) -> Result<usize, String> {
+pub fn aoc_2025_10_a(input: &str) -> Result<usize, AocError> {
     let machines = parse(input)?;
 
     let mut button_presses = 0;
@@ -530,7 +456,7 @@ pub fn aoc_2025_10_a(input: &str) -> Result<usize, String> {
 }
 
 #[tracing::instrument]
-pub fn aoc_2025_10_b(input: &str) -> Result<usize, String> {
+pub fn aoc_2025_10_b(input: &str) -> Result<usize, AocError> {
     let machines = parse(input)?;
 
     let mut button_presses = 0;
@@ -554,8 +480,8 @@ pub fn aoc_2025_10_b(input: &str) -> Result<usize, String> {
         //     println!("No init sequence found for machine {:?}", machine);
         // }
 
-        // use least squares to solve system of linear equations. Integer solution pretty please
-        let button_sequence = machine.solve_with_least_squares()?;
+        // minimise total presses subject to the counter equations, integer solutions only
+        let button_sequence = machine.solve_minimal_presses()?;
         
         // cannot get Z3 to work on my system, missing library files during linking phase and needs clang even with binaries
         // let button_sequence = machine.solve_with_z3()?;
@@ -573,14 +499,20 @@ pub fn aoc_2025_10_b(input: &str) -> Result<usize, String> {
     Ok(button_presses)
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: 2025,
+    day: 10,
+    title: "Factory",
+    a: aoc_2025_10_a,
+    b: aoc_2025_10_b,
+}
 
 #[cfg(test)]
 mod tests {
     use super::find_minimal_variation;
     use super::Machine;
+    use aoc_utils::error::AocError;
     use bitvec::prelude::*;
-    use ndarray::prelude::*;
     use rstest::rstest;
 
     #[rstest]
@@ -588,13 +520,15 @@ mod tests {
     #[case(TEST_INPUT_LINE_2, Ok(3))]
     #[case(TEST_INPUT_LINE_3, Ok(2))]
     #[case(TEST_INPUT, Ok(7))]
-    fn aoc_2025_10_a_example(#[case] input: &str, #[case] expected: Result<usize, String>) {
+    fn aoc_2025_10_a_example(#[case] input: &str, #[case] expected: Result<usize, AocError>) {
         assert_eq!(super::aoc_2025_10_a(input), expected);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_10_a() {
-        assert_eq!(super::aoc_2025_10_a(super::INPUT), Ok(481));
+        let input = aoc_utils::input::workspace(2025, 10);
+        aoc_utils::ledger::assert_answer(2025, 10, 'a', super::aoc_2025_10_a(&input));
     }
 
     #[rstest]
@@ -602,13 +536,15 @@ mod tests {
     #[case(TEST_INPUT_LINE_2, Ok(12))]
     #[case(TEST_INPUT_LINE_3, Ok(11))]
     #[case(TEST_INPUT, Ok(33))]
-    fn aoc_2025_10_b_example(#[case] input: &str, #[case] expected: Result<usize, String>) {
+    fn aoc_2025_10_b_example(#[case] input: &str, #[case] expected: Result<usize, AocError>) {
         assert_eq!(super::aoc_2025_10_b(input), expected);
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn aoc_2025_10_b() {
-        assert_eq!(super::aoc_2025_10_b(super::INPUT), Ok(0));
+        let input = aoc_utils::input::workspace(2025, 10);
+        assert_eq!(super::aoc_2025_10_b(&input), Ok(0));
     }
 
     #[rstest]
@@ -680,16 +616,25 @@ mod tests {
     // }
 
     #[rstest]
-    #[case( TEST_INPUT_LINE_1, bitvec![0,0,0,1], Array1::from_vec(vec![0.0,0.0,0.0,1.0]) )]
-    fn button_to_vector_example(
-        #[case] input: &str,
-        #[case] button: BitVec,
-        #[case] expected: Array1<f64>,
-    ) {
+    #[case( TEST_INPUT_LINE_1, vec![
+        vec![0, 0, 0, 0, 1, 1],
+        vec![0, 1, 0, 0, 0, 1],
+        vec![0, 0, 1, 1, 1, 0],
+        vec![1, 1, 0, 1, 0, 0],
+    ])]
+    fn button_matrix_example(#[case] input: &str, #[case] expected: Vec<Vec<i64>>) {
         let machine: Machine = input.trim().parse().unwrap();
-        let m = machine.requirement.len();
-        let result = super::button_to_vector(m, &button);
-        assert_eq!(result, expected);
+        assert_eq!(machine.button_matrix(), expected);
+    }
+
+    #[rstest]
+    #[case(TEST_INPUT_LINE_1, 10)]
+    #[case(TEST_INPUT_LINE_2, 12)]
+    #[case(TEST_INPUT_LINE_3, 11)]
+    fn solve_minimal_presses_example(#[case] input: &str, #[case] expected: usize) {
+        let machine: Machine = input.trim().parse().unwrap();
+        let presses = machine.solve_minimal_presses().unwrap();
+        assert_eq!(presses.iter().sum::<usize>(), expected);
     }
 
     const TEST_INPUT: &str = "
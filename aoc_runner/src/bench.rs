@@ -0,0 +1,535 @@
+/*! Benchmark history, aoc bench
+ *
+ * aoc bench <year> [<day>] runs the divan benches of the days and appends their results to
+ * bench_history.jsonl, one run per line keyed by the git commit:
+ *
+ * {"commit":"3a280e7","time":1733558400,"results":[{"year":2024,"day":22,"bench":"part1",
+ *   "fastest_ns":15390,"median_ns":15490,"mean_ns":17530,"samples":5}]}
+ *
+ * aoc bench import <file>  adds a saved divan table, e.g. an old aoc_2024_22.bench.txt.
+ *                          PowerShell wrote them as UTF-16 and mangled the µ, both is accepted.
+ * aoc bench report         Markdown table of the latest timing of every bench, the change to the
+ *                          timing before and the slowest benches
+*/
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::LazyLock,
+    time::{Duration, SystemTime},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::table::{format_duration, markdown};
+
+pub const HISTORY: &str = "bench_history.jsonl";
+/// default for --tolerance of the report, divan medians are steadier than a single run
+pub const TOLERANCE: f64 = 1.1;
+const SLOWEST: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sample {
+    pub year: u16,
+    pub day: u8,
+    /// name of the divan bench, part1 or part2 for most days
+    pub bench: String,
+    pub fastest_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub samples: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchRun {
+    pub commit: String,
+    /// seconds since the unix epoch
+    pub time: u64,
+    pub results: Vec<Sample>,
+}
+
+impl BenchRun {
+    pub fn new(commit: &str, results: Vec<Sample>) -> Self {
+        let time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self {
+            commit: commit.to_string(),
+            time,
+            results,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, usize, serde_json::Error),
+    /// cargo bench failed, with the end of its output
+    Cargo(String, String),
+    /// the file name or the table does not tell which day it is
+    UnknownDay(PathBuf),
+}
+
+impl Display for BenchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BenchError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            BenchError::Json(path, line, e) => {
+                write!(f, "{}:{line}: invalid bench run {e}", path.display())
+            }
+            BenchError::Cargo(name, output) => write!(f, "cargo bench of {name} failed\n{output}"),
+            BenchError::UnknownDay(path) => {
+                write!(f, "{}: no aoc_<year>_<dd> in name or table", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+/// Runs in the order they were added
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    runs: Vec<BenchRun>,
+}
+
+impl History {
+    /// a missing file is an empty history
+    pub fn load(path: &Path) -> Result<Self, BenchError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(BenchError::Io(path.to_path_buf(), e)),
+        };
+        let runs = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| BenchError::Json(path.to_path_buf(), i + 1, e))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { runs })
+    }
+
+    /// only appends, earlier runs stay untouched
+    pub fn append(path: &Path, run: &BenchRun) -> Result<(), BenchError> {
+        let io_error = |e| BenchError::Io(path.to_path_buf(), e);
+        let mut line =
+            serde_json::to_string(run).map_err(|e| BenchError::Json(path.to_path_buf(), 0, e))?;
+        line.push('\n');
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(io_error)
+    }
+
+    pub fn runs(&self) -> &[BenchRun] {
+        &self.runs
+    }
+
+    pub fn push(&mut self, run: BenchRun) {
+        self.runs.push(run);
+    }
+
+    /// latest sample of every bench with the one before, ordered by year, day and bench
+    pub fn timings(&self) -> Vec<Timing<'_>> {
+        let mut timings = BTreeMap::new();
+        for run in &self.runs {
+            for sample in &run.results {
+                let key = (sample.year, sample.day, sample.bench.as_str());
+                let previous = timings.get(&key).map(|t: &Timing| t.sample);
+                timings.insert(
+                    key,
+                    Timing {
+                        commit: &run.commit,
+                        sample,
+                        previous,
+                    },
+                );
+            }
+        }
+        timings.into_values().collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing<'h> {
+    pub commit: &'h str,
+    pub sample: &'h Sample,
+    pub previous: Option<&'h Sample>,
+}
+
+impl Timing<'_> {
+    /// median compared to the median before, 1.2 is 20% slower
+    pub fn change(&self) -> Option<f64> {
+        self.previous
+            .filter(|p| p.median_ns > 0)
+            .map(|p| self.sample.median_ns as f64 / p.median_ns as f64)
+    }
+
+    pub fn is_regression(&self, tolerance: f64) -> bool {
+        self.change().is_some_and(|c| c > tolerance)
+    }
+}
+
+/// Text of a saved bench output, PowerShell redirects write UTF-16 with a byte order mark
+pub fn decode(bytes: &[u8]) -> String {
+    let utf16 = |bytes: &[u8], from: fn([u8; 2]) -> u16| {
+        let units = bytes
+            .chunks_exact(2)
+            .map(|c| from([c[0], c[1]]))
+            .collect::<Vec<_>>();
+        String::from_utf16_lossy(&units)
+    };
+    let text = match bytes {
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    };
+    // appended outputs bring their own byte order mark
+    text.replace('\u{FEFF}', "")
+}
+
+/// "16.99 µs" in nanoseconds. Anything else ending in s is taken as a mangled µs.
+fn nanos(cell: &str) -> Option<u64> {
+    let mut words = cell.split_whitespace();
+    let value = words.next()?.parse::<f64>().ok()?;
+    let factor = match words.next()? {
+        "ns" => 1.0,
+        "ms" => 1e6,
+        "s" => 1e9,
+        unit if unit.ends_with('s') => 1e3,
+        _ => return None,
+    };
+    Some((value * factor).round() as u64)
+}
+
+/// Samples of divan's table, benches in groups are named group/bench.
+/// A bench appearing twice (appended outputs) keeps its last sample.
+///
+/// aoc_2024_22_bench  fastest       │ slowest       │ median        │ mean          │ samples │ iters
+/// ├─ part1           16.99 µs      │ 33.19 µs      │ 17.49 µs      │ 17.67 µs      │ 100     │ 100
+pub fn parse_divan(output: &str, year: u16, day: u8) -> Vec<Sample> {
+    let mut groups: Vec<(usize, String)> = Vec::new();
+    let mut samples: Vec<Sample> = Vec::new();
+    for line in output.lines() {
+        let Some(branch) = line.find('─') else {
+            continue;
+        };
+        let depth = line[..branch].chars().count();
+        let cells = line[branch..]
+            .trim_start_matches(['─', ' '])
+            .split('│')
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        groups.retain(|(d, _)| *d < depth);
+
+        let Some((name, fastest)) = cells[0].split_once(' ') else {
+            groups.push((depth, cells[0].to_string()));
+            continue;
+        };
+        let (Some(fastest), Some(median), Some(mean), Some(count)) = (
+            nanos(fastest),
+            cells.get(2).and_then(|c| nanos(c)),
+            cells.get(3).and_then(|c| nanos(c)),
+            cells.get(4).and_then(|c| c.parse().ok()),
+        ) else {
+            continue;
+        };
+
+        let bench = groups
+            .iter()
+            .map(|(_, g)| g.as_str())
+            .chain([name])
+            .collect::<Vec<_>>()
+            .join("/");
+        samples.retain(|s| s.bench != bench);
+        samples.push(Sample {
+            year,
+            day,
+            bench,
+            fastest_ns: fastest,
+            median_ns: median,
+            mean_ns: mean,
+            samples: count,
+        });
+    }
+    samples
+}
+
+static DAY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"aoc_(\d{4})_(\d{2})").unwrap());
+
+/// year and day of the first aoc_<year>_<dd> in text
+pub fn day_of(text: &str) -> Option<(u16, u8)> {
+    let captures = DAY.captures(text)?;
+    Some((captures[1].parse().ok()?, captures[2].parse().ok()?))
+}
+
+fn git(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// short hash of HEAD, -dirty with uncommitted changes
+pub fn commit(root: &Path) -> String {
+    let Some(head) = git(root, &["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(root, &["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if changes.is_empty() => head,
+        _ => format!("{head}-dirty"),
+    }
+}
+
+/// commit which last changed file, a saved output belongs to it rather than to HEAD
+pub fn commit_of(root: &Path, file: &Path) -> String {
+    git(
+        root,
+        &["log", "-1", "--format=%h", "--", &file.to_string_lossy()],
+    )
+    .filter(|hash| !hash.is_empty())
+    .unwrap_or_else(|| commit(root))
+}
+
+/// Output of the divan benches of a day crate, without the PowerShell redirect
+pub fn run_benches(root: &Path, crate_name: &str) -> Result<String, BenchError> {
+    let output = Command::new("cargo")
+        .args(["bench", "-q", "-p", crate_name, "--benches", "--"])
+        .args(["--color", "never"])
+        .current_dir(root)
+        .output()
+        .map_err(|e| BenchError::Io(PathBuf::from("cargo"), e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let lines = stderr.lines().collect::<Vec<_>>();
+        let tail = lines[lines.len().saturating_sub(10)..].join("\n");
+        return Err(BenchError::Cargo(crate_name.to_string(), tail));
+    }
+    Ok(decode(&output.stdout))
+}
+
+fn duration(nanos: u64) -> String {
+    format_duration(Duration::from_nanos(nanos))
+}
+
+fn change_cell(timing: &Timing, tolerance: f64) -> String {
+    match timing.change() {
+        None => String::new(),
+        Some(change) if timing.is_regression(tolerance) => {
+            format!("**{:+.1}%**", (change - 1.0) * 100.0)
+        }
+        Some(change) => format!("{:+.1}%", (change - 1.0) * 100.0),
+    }
+}
+
+/// Markdown report of the latest timings, changes beyond tolerance are bold and listed
+pub fn report(history: &History, tolerance: f64) -> String {
+    let timings = history.timings();
+    let mut rows = vec![
+        [
+            "Year", "Day", "Bench", "Median", "Mean", "Fastest", "Samples", "Commit", "Change",
+        ]
+        .map(String::from),
+    ];
+    rows.extend(timings.iter().map(|t| {
+        let s = t.sample;
+        [
+            s.year.to_string(),
+            format!("{:02}", s.day),
+            s.bench.clone(),
+            duration(s.median_ns),
+            duration(s.mean_ns),
+            duration(s.fastest_ns),
+            s.samples.to_string(),
+            t.commit.to_string(),
+            change_cell(t, tolerance),
+        ]
+    }));
+    let mut text = format!("# Benchmarks\n\n{}\n", markdown(&rows));
+
+    let mut slowest = timings.iter().collect::<Vec<_>>();
+    slowest.sort_by_key(|t| std::cmp::Reverse(t.sample.median_ns));
+    let mut rows = vec![["Year", "Day", "Bench", "Median"].map(String::from)];
+    rows.extend(slowest.iter().take(SLOWEST).map(|t| {
+        [
+            t.sample.year.to_string(),
+            format!("{:02}", t.sample.day),
+            t.sample.bench.clone(),
+            duration(t.sample.median_ns),
+        ]
+    }));
+    text += &format!("\n## Slowest {SLOWEST}\n\n{}\n", markdown(&rows));
+
+    let regressions = timings
+        .iter()
+        .filter(|t| t.is_regression(tolerance))
+        .map(|t| format!("- {} {:02} {}", t.sample.year, t.sample.day, t.sample.bench))
+        .collect::<Vec<_>>();
+    if !regressions.is_empty() {
+        text += &format!(
+            "\n## Slower than {:+.0}%\n\n{}\n",
+            (tolerance - 1.0) * 100.0,
+            regressions.join("\n")
+        );
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const DIVAN: &str = "Timer precision: 100 ns
+aoc_2024_22_bench  fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ part1           16.99 µs      │ 33.19 µs      │ 17.49 µs      │ 17.67 µs      │ 100     │ 100
+├─ group                         │               │               │               │         │
+│  ╰─ inner        1.5 ms        │ 2 ms          │ 1.6 ms        │ 1.7 ms        │ 10      │ 10
+╰─ part2           2.001 s       │ 2.5 s         │ 2.1 s         │ 2.2 s         │ 5       │ 5
+";
+
+    fn sample(bench: &str, median_ns: u64) -> Sample {
+        Sample {
+            year: 2024,
+            day: 22,
+            bench: bench.to_string(),
+            fastest_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            samples: 100,
+        }
+    }
+
+    #[rstest]
+    #[case("16.99 µs", Some(16990))]
+    #[case("17 Âµs", Some(17000))]
+    #[case("17 ?s", Some(17000))]
+    #[case("2 ns", Some(2))]
+    #[case("1.5 ms", Some(1_500_000))]
+    #[case("2.001 s", Some(2_001_000_000))]
+    #[case("100", None)]
+    fn nanos_should_read_units(#[case] cell: &str, #[case] expected: Option<u64>) {
+        assert_eq!(nanos(cell), expected);
+    }
+
+    #[test]
+    fn parse_divan_should_read_table() {
+        let sut = parse_divan(DIVAN, 2024, 22);
+
+        let benches = sut.iter().map(|s| s.bench.as_str()).collect::<Vec<_>>();
+        assert_eq!(benches, vec!["part1", "group/inner", "part2"]);
+        assert_eq!(
+            sut[0],
+            Sample {
+                year: 2024,
+                day: 22,
+                bench: "part1".to_string(),
+                fastest_ns: 16990,
+                median_ns: 17490,
+                mean_ns: 17670,
+                samples: 100,
+            }
+        );
+        assert_eq!(sut[2].median_ns, 2_100_000_000);
+    }
+
+    #[test]
+    fn parse_divan_should_keep_last_of_appended_tables() {
+        let sut = parse_divan(
+            &format!("{DIVAN}{}", DIVAN.replace("17.49", "20")),
+            2024,
+            22,
+        );
+        assert_eq!(sut.len(), 3);
+        assert_eq!(
+            sut.iter().find(|s| s.bench == "part1").unwrap().median_ns,
+            20000
+        );
+    }
+
+    #[test]
+    fn decode_should_read_utf16() {
+        let text = "aoc_2024_22_bench\n├─ part1 16.99 µs";
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes.extend([0xFF, 0xFE]);
+        bytes.extend("\n".encode_utf16().flat_map(u16::to_le_bytes));
+
+        assert_eq!(decode(&bytes), format!("{text}\n"));
+        assert_eq!(decode(text.as_bytes()), text);
+    }
+
+    #[rstest]
+    #[case("aoc_2024_22.bench.txt", Some((2024, 22)))]
+    #[case("aoc_2024_22_bench  fastest", Some((2024, 22)))]
+    #[case("benchmarks.txt", None)]
+    fn day_of_should_find_crate(#[case] text: &str, #[case] expected: Option<(u16, u8)>) {
+        assert_eq!(day_of(text), expected);
+    }
+
+    #[test]
+    fn history_should_round_trip_lines() {
+        let path = std::env::temp_dir().join("aoc_runner_bench_history.jsonl");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+
+        let run = BenchRun::new("3a280e7", vec![sample("part1", 17490)]);
+        History::append(&path, &run).unwrap();
+        History::append(&path, &run).unwrap();
+
+        assert_eq!(History::load(&path).unwrap().runs(), [run.clone(), run]);
+    }
+
+    #[test]
+    fn timings_should_compare_with_previous() {
+        let mut sut = History::default();
+        sut.push(BenchRun::new(
+            "a",
+            vec![sample("part1", 100), sample("part2", 100)],
+        ));
+        sut.push(BenchRun::new("b", vec![sample("part1", 150)]));
+
+        let timings = sut.timings();
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings[0].commit, "b");
+        assert_eq!(timings[0].change(), Some(1.5));
+        assert!(timings[0].is_regression(TOLERANCE));
+        assert_eq!(timings[1].commit, "a");
+        assert_eq!(timings[1].change(), None);
+    }
+
+    #[test]
+    fn report_should_flag_regressions() {
+        let mut history = History::default();
+        history.push(BenchRun::new(
+            "a",
+            vec![sample("part1", 1000), sample("part2", 1000)],
+        ));
+        history.push(BenchRun::new(
+            "b",
+            vec![sample("part1", 2000), sample("part2", 1050)],
+        ));
+
+        let sut = report(&history, TOLERANCE);
+
+        assert!(sut.contains(
+            "| 2024 | 22  | part1 | 2.00µs | 2.00µs | 2.00µs  | 100     | b      | **+100.0%** |"
+        ));
+        assert!(sut.contains("| +5.0%       |"));
+        assert!(sut.contains("## Slowest 10"));
+        assert!(sut.contains("## Slower than +10%\n\n- 2024 22 part1\n"));
+    }
+}
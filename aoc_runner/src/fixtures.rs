@@ -0,0 +1,92 @@
+/*! Fake days for the tests of the runner
+ *
+ * Years 9998 and 9999 have no puzzles, the fixtures never meet a real day or its input.
+*/
+
+/// a counts the lines, b is not solved
+pub mod aoc_9999_01 {
+    pub fn aoc_9999_01_a(input: &str) -> usize {
+        input.lines().count()
+    }
+
+    aoc_utils::solution! {
+        year: 9999,
+        day: 1,
+        title: "Counting",
+        a: aoc_9999_01_a,
+    }
+}
+
+/// no part solved
+pub mod aoc_9998_02 {
+    aoc_utils::solution! {
+        year: 9998,
+        day: 2,
+        title: "Nothing",
+    }
+}
+
+/// a panics on anything but a number
+pub mod aoc_9999_03 {
+    aoc_utils::solution! {
+        year: 9999,
+        day: 3,
+        title: "Panic",
+        a: |input, _| input.parse::<u8>().expect("a number"),
+    }
+}
+
+/// length of the input, b times the param factor, generates the seed scale times
+pub mod aoc_9999_04 {
+    pub fn aoc_9999_04_a(input: &str) -> usize {
+        input.len()
+    }
+
+    pub fn generate(seed: u64, scale: usize) -> String {
+        seed.to_string().repeat(scale)
+    }
+
+    aoc_utils::solution! {
+        year: 9999,
+        day: 4,
+        title: "Length",
+        a: aoc_9999_04_a,
+        b: |input, params| input.len() * params.get_or("factor", 1)?,
+        generate: generate,
+    }
+}
+
+/// length of the input without a generator
+pub mod aoc_9999_05 {
+    aoc_utils::solution! {
+        year: 9999,
+        day: 5,
+        title: "No generator",
+        a: |input, _| input.len(),
+    }
+}
+
+/// a counts the lines, b gives the last one
+pub mod aoc_9999_06 {
+    aoc_utils::solution! {
+        year: 9999,
+        day: 6,
+        title: "Lines",
+        a: |input, _| input.lines().count(),
+        b: |input, _| input.lines().last().unwrap_or_default().to_string(),
+    }
+}
+
+/// a always fails
+pub mod aoc_9999_07 {
+    pub fn aoc_9999_07_a(_input: &str) -> Result<usize, String> {
+        Err("no way".to_string())
+    }
+
+    aoc_utils::solution! {
+        year: 9999,
+        day: 7,
+        title: "Broken",
+        a: aoc_9999_07_a,
+    }
+}
@@ -0,0 +1,69 @@
+/*! Where to find the puzzle input of a day
+ *
+ * With an input directory the input of 2024 day 7 is {dir}/2024/07.txt, see aoc_utils::input.
+ * The directory is given by --input-dir or the environment variable AOC_INPUT_DIR.
+ * Missing inputs are fetched into it if AOC_SESSION holds the session cookie.
+ * Without one, the input.txt next to the lib.rs of the day crate is used as before.
+*/
+use std::path::PathBuf;
+
+use aoc_utils::input::{Fetcher, INPUT_DIR_VAR, InputDir, InputError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inputs {
+    Dir(InputDir),
+    /// aoc_{year}_{day:02}/src/input.txt below the workspace root
+    Workspace(PathBuf),
+}
+
+impl Inputs {
+    /// explicit directory, else AOC_INPUT_DIR, else the day crates of this workspace
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        dir.or_else(|| std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+            .map(|dir| {
+                let dir = InputDir::new(dir);
+                Inputs::Dir(match Fetcher::from_env() {
+                    Some(fetcher) => dir.with_fetcher(fetcher),
+                    None => dir,
+                })
+            })
+            .unwrap_or_else(|| Inputs::Workspace(crate::workspace_root()))
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        match self {
+            Inputs::Dir(dir) => dir.path(year, day),
+            Inputs::Workspace(root) => aoc_utils::input::workspace_path(root, year, day),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self {
+            Inputs::Dir(dir) => dir.load(year, day),
+            Inputs::Workspace(_) => {
+                let path = self.path(year, day);
+                std::fs::read_to_string(&path).map_err(|e| InputError::Io(path, e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::path::Path;
+
+    #[rstest]
+    #[case(Inputs::Dir(InputDir::new("inputs")), "inputs/2024/07.txt")]
+    #[case(Inputs::Workspace("aoc".into()), "aoc/aoc_2024_07/src/input.txt")]
+    fn path_should_follow_layout(#[case] sut: Inputs, #[case] expected: &str) {
+        assert_eq!(sut.path(2024, 7), PathBuf::from(expected));
+    }
+
+    #[test]
+    fn new_should_prefer_explicit_dir() {
+        let sut = Inputs::new(Some("somewhere".into()));
+        assert!(matches!(sut, Inputs::Dir(dir) if dir.dir() == Path::new("somewhere")));
+    }
+}
@@ -0,0 +1,42 @@
+/*! Runner for all days of the workspace
+ *
+ * Every day crate registers its Puzzle with aoc_utils::solution!, days.rs collects them.
+ * Inputs are read at runtime, so the runner does not depend on include_str! of a single day.
+ *
+ * aoc run 2024 17 a    one part
+ * aoc run 2024 17      both parts of a day
+ * aoc run 2024         every day of a year
+ * aoc run --all        everything
+ *
+ * --jobs <n> runs n days at once, --isolate every day in its own process, see parallel.rs
+ *
+ * aoc verify [<year> [<day>]]  compares the answers and times with the ledger (aoc_data.json)
+ * aoc new <year> <day>         creates the crate of a new day from template/
+ * aoc scrape <year> <day>      fills title, examples and their answers in from the puzzle page
+ * aoc bench <year> [<day>]     runs the divan benches into bench_history.jsonl, see bench.rs
+ * aoc scale <year> <day>       times a day on growing synthetic inputs, see scale.rs
+ * aoc readme                   renders the progress tables of README.md
+*/
+pub mod bench;
+mod days;
+#[cfg(test)]
+mod fixtures;
+pub mod input;
+pub mod parallel;
+pub mod readme;
+pub mod run;
+pub mod scaffold;
+pub mod scale;
+pub mod scrape;
+pub mod table;
+pub mod verify;
+
+pub use days::registry;
+
+/// root of the workspace, the runner lives in it
+pub fn workspace_root() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner lives in the workspace")
+        .to_path_buf()
+}
@@ -0,0 +1,1034 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_runner::{
+    bench::{self, BenchError, BenchRun, History},
+    input::Inputs,
+    parallel::{self, DayRun, Mode},
+    readme, registry,
+    run::{DayResult, Part, PartResult, Selection, run_day},
+    scaffold::{self, NewDay},
+    scale, scrape,
+    table::{format_duration, summary},
+    verify::{self, Status, TOLERANCE},
+    workspace_root,
+};
+use aoc_utils::{
+    Answer, Params, Solution,
+    input::{Fetcher, InputError, SESSION_VAR},
+    ledger::Ledger,
+    solution::Error,
+    telemetry::{Output, Telemetry},
+};
+
+const USAGE: &str = "usage:
+    aoc run <year> [<day> [a|b]] [options] [--record]
+    aoc run --all [options] [--record]
+    aoc verify [<year> [<day> [a|b]]] [options] [--tolerance <f>] [--save-baseline]
+    aoc new <year> <day> [--input-dir <dir>] [--ledger <file>]
+    aoc scrape <year> <day> [--html <file> | --url <url>]
+    aoc bench <year> [<day>] [--history <file>]
+    aoc bench --all [--history <file>]
+    aoc bench import <file>... [--history <file>]
+    aoc bench report [--tolerance <f>] [--history <file>]
+    aoc scale <year> <day> [a|b] [--scales <n,...>] [--seed <n>] [--budget <s>] [--params <k=v,...>]
+    aoc readme [--check] [--ledger <file>] [--history <file>]
+
+options:
+    --input-dir <dir>   inputs are read from <dir>/<year>/<dd>.txt, default $AOC_INPUT_DIR
+                        or src/input.txt of the day crate. Missing inputs are fetched
+                        into <dir> if $AOC_SESSION holds the session cookie
+    --params <k=v,...>  extra parameters of a day, e.g. --params row=10
+    --ledger <file>     answers and baseline times, default aoc_data.json of the workspace
+    --trace <output>    fmt, json, chrome[:<file>], tracy or off, default $AOC_TRACE or fmt.
+                        $RUST_LOG selects the spans, a summary of their times follows the run
+    --jobs <n>          runs n days at once on a rayon pool, 0 for one per cpu, default 1.
+                        More than one job implies --isolate, the output stays apart
+    --isolate           runs every day in its own process for clean timings and captures
+                        its output, the output of failed days is shown
+
+run:
+    --record            add new answers to the ledger, known answers are never overwritten
+    --report-json       ends with the results as json line instead of the table, the
+                        protocol of --isolate
+
+verify:
+    --tolerance <f>     a part is slower if it takes more than f * baseline, default 1.5
+    --save-baseline     take the times of the correct answers as new baseline
+
+new:
+    creates aoc_<year>_<dd> from template/ with the title of the ledger, registers it
+    in the runner and fetches the input into src/input.txt
+
+scrape:
+    fills the title, the examples and their answers of the puzzle page into the
+    placeholders of src/lib.rs of aoc_<year>_<dd>, written tests stay as they are.
+    Fetches the page with $AOC_SESSION, part two needs part one solved
+    --html <file>       a saved puzzle page instead
+    --url <url>         the page from another url, e.g. a local test server
+
+bench:
+    runs the divan benches of the days and adds their results to the history
+    --history <file>    results of all runs, default bench_history.jsonl of the workspace
+    import              adds saved divan output like aoc_2024_22.bench.txt
+    report              Markdown table of the latest timings, their change and the slowest
+    --tolerance <f>     report a bench as regression if its median grew by more than f,
+                        default 1.1
+
+scale:
+    runs a part on the inputs of the day's generator for growing scales and fits
+    time ~ size^k, k >= 1.5 is reported as quadratic. --params override the generated ones
+    --scales <n,...>    scales to generate, default 10,20,40,80,160,320,640
+    --seed <n>          seed of the generator, default 0
+    --budget <s>        skip the larger scales after a run of more than s seconds, default 2
+
+readme:
+    renders the progress tables of README.md between its marker comments from the
+    ledger, the day crates and the bench history
+    --check             only compare, fails if README.md is not up to date";
+
+/// lines of the captured output shown for a failed day
+const OUTPUT_TAIL: usize = 20;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Command {
+    #[default]
+    Run,
+    Verify,
+    New,
+    Scrape,
+    Bench,
+    BenchImport,
+    BenchReport,
+    Scale,
+    Readme,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    command: Command,
+    selection: Selection,
+    input_dir: Option<PathBuf>,
+    params: Params,
+    ledger: Option<PathBuf>,
+    record: bool,
+    tolerance: Option<f64>,
+    save_baseline: bool,
+    html: Option<PathBuf>,
+    url: Option<String>,
+    history: Option<PathBuf>,
+    files: Vec<PathBuf>,
+    check: bool,
+    trace: Option<Output>,
+    scales: Vec<usize>,
+    seed: Option<u64>,
+    budget: Option<Duration>,
+    jobs: Option<usize>,
+    isolate: bool,
+    report_json: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let Some((command, args)) = args.split_first() else {
+        return Err("missing command".to_string());
+    };
+    let (kind, args) = match (command.as_str(), args) {
+        ("bench", [sub, rest @ ..]) if sub == "import" => (Command::BenchImport, rest),
+        ("bench", [sub, rest @ ..]) if sub == "report" => (Command::BenchReport, rest),
+        ("bench", _) => (Command::Bench, args),
+        ("run", _) => (Command::Run, args),
+        ("verify", _) => (Command::Verify, args),
+        ("new", _) => (Command::New, args),
+        ("scrape", _) => (Command::Scrape, args),
+        ("scale", _) => (Command::Scale, args),
+        ("readme", _) => (Command::Readme, args),
+        _ => return Err(format!("unknown command {command}")),
+    };
+    let mut run = Args {
+        command: kind,
+        ..Args::default()
+    };
+    let with_history = matches!(
+        kind,
+        Command::Bench | Command::BenchImport | Command::BenchReport | Command::Readme
+    );
+
+    let mut all = false;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input-dir" => {
+                let dir = args.next().ok_or("--input-dir needs a directory")?;
+                run.input_dir = Some(PathBuf::from(dir));
+            }
+            "--params" => {
+                let params = args.next().ok_or("--params needs key=value pairs")?;
+                run.params = params.parse().map_err(|e: Error| e.to_string())?;
+            }
+            "--trace" => {
+                let output = args.next().ok_or("--trace needs an output")?;
+                run.trace = Some(output.parse()?);
+            }
+            "--ledger" => {
+                let file = args.next().ok_or("--ledger needs a file")?;
+                run.ledger = Some(PathBuf::from(file));
+            }
+            "--record" if run.command == Command::Run => run.record = true,
+            "--report-json" if run.command == Command::Run => run.report_json = true,
+            "--jobs" if matches!(kind, Command::Run | Command::Verify) => {
+                let jobs = args.next().ok_or("--jobs needs a number")?;
+                run.jobs = Some(jobs.parse().map_err(|_| format!("invalid jobs {jobs}"))?);
+            }
+            "--isolate" if matches!(kind, Command::Run | Command::Verify) => run.isolate = true,
+            "--tolerance" if matches!(kind, Command::Verify | Command::BenchReport) => {
+                let tolerance = args.next().ok_or("--tolerance needs a factor")?;
+                run.tolerance = Some(
+                    tolerance
+                        .parse()
+                        .ok()
+                        .filter(|t: &f64| *t >= 1.0)
+                        .ok_or(format!("invalid tolerance {tolerance}, expected >= 1.0"))?,
+                );
+            }
+            "--save-baseline" if run.command == Command::Verify => run.save_baseline = true,
+            "--html" if run.command == Command::Scrape => {
+                let file = args.next().ok_or("--html needs a file")?;
+                run.html = Some(PathBuf::from(file));
+            }
+            "--url" if run.command == Command::Scrape => {
+                let url = args.next().ok_or("--url needs an url")?;
+                run.url = Some(url.to_string());
+            }
+            "--check" if kind == Command::Readme => run.check = true,
+            "--scales" if kind == Command::Scale => {
+                let scales = args.next().ok_or("--scales needs a list of scales")?;
+                run.scales = scales
+                    .split(',')
+                    .map(|s| s.trim().parse().ok().filter(|s| *s > 0))
+                    .collect::<Option<_>>()
+                    .ok_or(format!("invalid scales {scales}, expected n,n,..."))?;
+            }
+            "--seed" if kind == Command::Scale => {
+                let seed = args.next().ok_or("--seed needs a number")?;
+                run.seed = Some(seed.parse().map_err(|_| format!("invalid seed {seed}"))?);
+            }
+            "--budget" if kind == Command::Scale => {
+                let budget = args.next().ok_or("--budget needs seconds")?;
+                run.budget = Some(
+                    budget
+                        .parse()
+                        .ok()
+                        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+                        .ok_or(format!("invalid budget {budget}, expected seconds"))?,
+                );
+            }
+            "--history" if with_history => {
+                let file = args.next().ok_or("--history needs a file")?;
+                run.history = Some(PathBuf::from(file));
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option {arg} for {command}"));
+            }
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    match kind {
+        Command::BenchImport if all || positional.is_empty() => {
+            return Err("bench import needs the files to import".to_string());
+        }
+        Command::BenchImport => {
+            run.files = positional.iter().map(PathBuf::from).collect();
+            return Ok(run);
+        }
+        Command::BenchReport if all || !positional.is_empty() => {
+            return Err("bench report reports everything, no year or day allowed".to_string());
+        }
+        Command::BenchReport => return Ok(run),
+        Command::Readme if all || !positional.is_empty() => {
+            return Err("readme covers every year, no year or day allowed".to_string());
+        }
+        Command::Readme => return Ok(run),
+        _ => {}
+    }
+
+    if matches!(run.command, Command::New | Command::Scrape) {
+        let [year, day] = positional.as_slice() else {
+            return Err(format!("{command} needs <year> <day>"));
+        };
+        run.selection.year = Some(year.parse().map_err(|_| format!("invalid year {year}"))?);
+        run.selection.day = Some(
+            day.parse()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or(format!("invalid day {day}"))?,
+        );
+        return if all {
+            Err(format!(
+                "{command} works on a single day, --all is not allowed"
+            ))
+        } else if run.html.is_some() && run.url.is_some() {
+            Err("either --html or --url".to_string())
+        } else {
+            Ok(run)
+        };
+    }
+
+    match (all, positional.as_slice()) {
+        (true, []) => {}
+        (true, _) => return Err("--all runs everything, no year or day allowed".to_string()),
+        // verify checks everything by default, a run of everything takes a while
+        (false, []) if run.command == Command::Verify => {}
+        (false, []) => return Err("missing year or --all".to_string()),
+        (false, [year, rest @ ..]) if rest.len() <= 2 => {
+            run.selection.year = Some(year.parse().map_err(|_| format!("invalid year {year}"))?);
+            if let Some(day) = rest.first() {
+                run.selection.day = Some(day.parse().map_err(|_| format!("invalid day {day}"))?);
+            }
+            if let Some(part) = rest.get(1) {
+                run.selection.part = Some(part.parse()?);
+            }
+        }
+        (false, _) => return Err("too many arguments".to_string()),
+    }
+    if kind == Command::Bench && run.selection.part.is_some() {
+        return Err("bench runs both parts of a day".to_string());
+    }
+    if kind == Command::Scale && run.selection.day.is_none() {
+        return Err("scale needs <year> <day>".to_string());
+    }
+    if run.report_json && (run.jobs.is_some() || run.isolate || run.record) {
+        return Err("--report-json reports a single run".to_string());
+    }
+    // days printing at once in the same process would mix their output
+    if run.jobs.is_some_and(|jobs| jobs != 1) {
+        run.isolate = true;
+    }
+    Ok(run)
+}
+
+fn print_part(year: u16, day: u8, result: &PartResult) {
+    let time = format_duration(result.elapsed);
+    match &result.answer {
+        Ok(Answer::Ocr(pixels)) => println!("{year} {day:02} {}: ({time})\n{pixels}", result.part),
+        Ok(answer) => println!("{year} {day:02} {}: {answer} ({time})", result.part),
+        Err(Error::NotSolved) => {}
+        Err(e) => println!("{year} {day:02} {}: {}", result.part, e.render()),
+    }
+}
+
+/// every error but a part without solution
+fn failed(answer: &Result<Answer, Error>) -> bool {
+    matches!(answer, Err(e) if *e != Error::NotSolved)
+}
+
+/// Arguments of the child run of a single day with --isolate
+fn child_args(args: &Args) -> Vec<String> {
+    let mut child = vec!["--trace".to_string(), "off".to_string()];
+    if !args.params.is_empty() {
+        child.extend(["--params".to_string(), args.params.to_string()]);
+    }
+    if let Some(dir) = &args.input_dir {
+        child.extend(["--input-dir".to_string(), dir.display().to_string()]);
+    }
+    child
+}
+
+/// Run the days on a rayon pool, the parts of a day are printed together when it is done
+fn run_parallel(
+    args: &Args,
+    days: &[&'static dyn Solution],
+    parts: &[Part],
+    inputs: &Inputs,
+) -> Result<Vec<DayResult>, String> {
+    let mode = if args.isolate {
+        let program = std::env::current_exe().map_err(|e| e.to_string())?;
+        Mode::Isolated {
+            program,
+            args: child_args(args),
+        }
+    } else {
+        Mode::InProcess
+    };
+
+    let start = Instant::now();
+    let on_day = |run: &DayRun| {
+        // stdout locks again for every line, a day is not torn apart by another one
+        let _lock = std::io::stdout().lock();
+        let (year, day) = (run.result.year, run.result.day);
+        if args.command == Command::Run {
+            for part in &run.result.parts {
+                print_part(year, day, part);
+            }
+        }
+        if run.result.parts.iter().any(|p| failed(&p.answer)) && !run.output.is_empty() {
+            let lines = run.output.lines().collect::<Vec<_>>();
+            let tail = &lines[lines.len().saturating_sub(OUTPUT_TAIL)..];
+            println!("output of {year} {day:02}:\n{}", tail.join("\n"));
+        }
+    };
+    let (runs, pool) = parallel::run_days(
+        days,
+        parts,
+        inputs,
+        &args.params,
+        &mode,
+        args.jobs.unwrap_or(1),
+        on_day,
+    )?;
+
+    println!();
+    println!(
+        "{}",
+        parallel::report(&runs, pool, start.elapsed(), args.isolate)
+    );
+    Ok(runs.into_iter().map(|run| run.result).collect())
+}
+
+/// Add new answers, conflicts are only reported
+fn record(ledger: &mut Ledger, results: &[DayResult]) {
+    for conflict in verify::record(ledger, results) {
+        eprintln!("{conflict}");
+    }
+}
+
+/// Report every part which is not as the ledger expects, returns false on regressions or failures
+fn check(ledger: &mut Ledger, results: &[DayResult], args: &Args) -> bool {
+    let tolerance = args.tolerance.unwrap_or(TOLERANCE);
+    let statuses = verify::verify(results, ledger, tolerance);
+
+    let (mut ok, mut missing, mut slower, mut broken) = (0, 0, 0, 0);
+    for (day, part, status) in &statuses {
+        match status {
+            Status::Ok => ok += 1,
+            Status::NotSolved => {}
+            Status::Missing(_) => missing += 1,
+            Status::Slower { .. } => slower += 1,
+            Status::Regression { .. } | Status::Failed(_) => broken += 1,
+        }
+        if !matches!(status, Status::Ok | Status::NotSolved) {
+            let (year, day, title) = (day.year, day.day, day.title);
+            println!("{year} {day:02} {} {title}: {status}", part.part);
+        }
+    }
+    println!("{ok} ok, {missing} missing, {slower} slower, {broken} regressions or failures");
+    if missing > 0 {
+        println!("add missing answers with aoc run <year> <day> --record");
+    }
+
+    if args.save_baseline {
+        let saved = verify::save_baseline(ledger, results, tolerance);
+        println!("new baseline for {saved} part(s)");
+    }
+    broken == 0
+}
+
+/// Create the crate of the day, a failed input download does not stop it
+fn new_day(args: &Args, ledger_path: &Path) -> Result<(), String> {
+    let (Some(year), Some(day)) = (args.selection.year, args.selection.day) else {
+        unreachable!("parse_args checks year and day of new");
+    };
+    let title = Ledger::load(ledger_path)
+        .map_err(|e| e.to_string())?
+        .get(year, day)
+        .map(|e| e.title.clone())
+        .unwrap_or_default();
+    let new_day = NewDay { year, day, title };
+
+    let root = workspace_root();
+    let written =
+        scaffold::create(&root, &root.join("template"), &new_day).map_err(|e| e.to_string())?;
+    for path in &written {
+        println!("created {}", path.display());
+    }
+    for registry in ["aoc_runner", "www"] {
+        if scaffold::register(&root.join(registry), &new_day).map_err(|e| e.to_string())? {
+            println!("registered {} in {registry}", new_day.crate_name());
+        }
+    }
+    if new_day.title.is_empty() {
+        println!("no title for {year} {day:02} in {}", ledger_path.display());
+    }
+
+    // the lib tests, bins and benches of the day read src/input.txt, see aoc_utils::input
+    let input = match Inputs::new(args.input_dir.clone()) {
+        Inputs::Dir(dir) => dir.load(year, day),
+        Inputs::Workspace(_) => match Fetcher::from_env() {
+            Some(fetcher) => fetcher.fetch(year, day),
+            None => Err(InputError::Missing(PathBuf::from("src/input.txt"))),
+        },
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            return Ok(());
+        }
+    };
+    let path = root
+        .join(new_day.crate_name())
+        .join("src")
+        .join("input.txt");
+    std::fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Fill the test placeholders of an existing day crate from its puzzle page
+fn scrape_day(args: &Args) -> Result<(), String> {
+    let (Some(year), Some(day)) = (args.selection.year, args.selection.day) else {
+        unreachable!("parse_args checks year and day of scrape");
+    };
+    let crate_name = NewDay {
+        year,
+        day,
+        title: String::new(),
+    }
+    .crate_name();
+    let lib_path = workspace_root()
+        .join(&crate_name)
+        .join("src")
+        .join("lib.rs");
+    let lib = std::fs::read_to_string(&lib_path).map_err(|e| {
+        format!(
+            "{}: {e}, create it with aoc new {year} {day}",
+            lib_path.display()
+        )
+    })?;
+
+    let html = match (&args.html, &args.url) {
+        (Some(file), _) => {
+            std::fs::read_to_string(file).map_err(|e| format!("{}: {e}", file.display()))?
+        }
+        // a stand-in server does not care for the session
+        (None, Some(url)) => Fetcher::from_env()
+            .unwrap_or_else(|| Fetcher::new("-"))
+            .fetch_url(url)
+            .map_err(|e| e.to_string())?,
+        (None, None) => Fetcher::from_env()
+            .ok_or(format!("set {SESSION_VAR} or use --html"))?
+            .page(year, day)
+            .map_err(|e| e.to_string())?,
+    };
+
+    let page = scrape::parse(&html);
+    if page.parts.is_empty() {
+        return Err("no puzzle description on the page".to_string());
+    }
+    let (lib, filled) = scrape::fill_tests(&lib, &crate_name, &page);
+    if filled.is_empty() {
+        println!("nothing to fill in {}", lib_path.display());
+        return Ok(());
+    }
+    std::fs::write(&lib_path, lib).map_err(|e| format!("{}: {e}", lib_path.display()))?;
+    for what in filled {
+        println!("filled {what}");
+    }
+    Ok(())
+}
+
+/// Run, import or report benches, returns false on regressions
+fn bench(args: &Args) -> Result<bool, String> {
+    let root = workspace_root();
+    let history_path = args
+        .history
+        .clone()
+        .unwrap_or_else(|| root.join(bench::HISTORY));
+
+    let runs = match args.command {
+        Command::BenchReport => {
+            let history = History::load(&history_path).map_err(|e| e.to_string())?;
+            if history.runs().is_empty() {
+                return Err(format!("no benches in {}", history_path.display()));
+            }
+            let tolerance = args.tolerance.unwrap_or(bench::TOLERANCE);
+            println!("{}", bench::report(&history, tolerance));
+            return Ok(!history.timings().iter().any(|t| t.is_regression(tolerance)));
+        }
+        Command::BenchImport => args
+            .files
+            .iter()
+            .map(|file| {
+                let bytes = std::fs::read(file).map_err(|e| BenchError::Io(file.clone(), e))?;
+                let output = bench::decode(&bytes);
+                let (year, day) = bench::day_of(&file.to_string_lossy())
+                    .or_else(|| bench::day_of(&output))
+                    .ok_or_else(|| BenchError::UnknownDay(file.clone()))?;
+                let results = bench::parse_divan(&output, year, day);
+                Ok(BenchRun::new(&bench::commit_of(&root, file), results))
+            })
+            .collect::<Result<Vec<_>, BenchError>>()
+            .map_err(|e| e.to_string())?,
+        _ => {
+            let mut results = Vec::new();
+            for day in args.selection.days(&registry()) {
+                let name = format!("aoc_{}_{:02}", day.year(), day.day());
+                if !root.join(&name).join("benches").is_dir() {
+                    continue;
+                }
+                println!("benching {name}");
+                match bench::run_benches(&root, &name) {
+                    Ok(output) => {
+                        results.extend(bench::parse_divan(&output, day.year(), day.day()))
+                    }
+                    Err(e) => eprintln!("{e}"),
+                }
+            }
+            vec![BenchRun::new(&bench::commit(&root), results)]
+        }
+    };
+
+    for run in runs.iter().filter(|r| !r.results.is_empty()) {
+        History::append(&history_path, run).map_err(|e| e.to_string())?;
+        println!(
+            "{} bench(es) of {} added to {}",
+            run.results.len(),
+            run.commit,
+            history_path.display()
+        );
+    }
+    if runs.iter().all(|r| r.results.is_empty()) {
+        return Err("no bench results".to_string());
+    }
+    Ok(true)
+}
+
+/// Scaling curves of the selected parts, false if a part failed
+fn scale_day(args: &Args) -> Result<bool, String> {
+    let (Some(year), Some(day)) = (args.selection.year, args.selection.day) else {
+        unreachable!("parse_args checks year and day of scale");
+    };
+    let solution = registry()
+        .get(year, day)
+        .ok_or(format!("{year} {day:02} is not registered"))?;
+    let scales = if args.scales.is_empty() {
+        scale::SCALES.to_vec()
+    } else {
+        args.scales.clone()
+    };
+
+    let mut passed = true;
+    for part in args.selection.parts() {
+        let points = scale::curve(
+            solution,
+            part,
+            args.seed.unwrap_or_default(),
+            &scales,
+            &args.params,
+            args.budget.unwrap_or(scale::BUDGET),
+        )
+        .ok_or(format!("{year} {day:02} has no generator of inputs"))?;
+        if points.iter().all(|p| p.answer == Err(Error::NotSolved)) {
+            continue;
+        }
+
+        println!("{year} {day:02} {part}: {}\n", solution.title());
+        println!("{}\n", scale::report(&points));
+        for p in &points {
+            if let Err(e) = &p.answer
+                && *e != Error::NotSolved
+            {
+                println!("scale {}: {}", p.scale, e.render());
+                passed = false;
+            }
+        }
+    }
+    Ok(passed)
+}
+
+/// Render the tables of README.md, with --check false if it is out of date
+fn update_readme(args: &Args, ledger_path: &Path) -> Result<bool, String> {
+    let root = workspace_root();
+    let ledger = Ledger::load(ledger_path).map_err(|e| e.to_string())?;
+    let history_path = args
+        .history
+        .clone()
+        .unwrap_or_else(|| root.join(bench::HISTORY));
+    let history = History::load(&history_path).map_err(|e| e.to_string())?;
+    let crates = readme::day_crates(&root).map_err(|e| format!("{}: {e}", root.display()))?;
+
+    let path = root.join("README.md");
+    let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let updated =
+        readme::update(&text, &readme::tables(&ledger, &crates, &history)).ok_or(format!(
+            "{} needs the lines {} and {}",
+            path.display(),
+            readme::START,
+            readme::END
+        ))?;
+
+    if updated == text {
+        println!("{} is up to date", path.display());
+        Ok(true)
+    } else if args.check {
+        println!("{} is out of date, run aoc readme", path.display());
+        Ok(false)
+    } else {
+        std::fs::write(&path, updated).map_err(|e| format!("{}: {e}", path.display()))?;
+        println!("{} updated", path.display());
+        Ok(true)
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let _telemetry = args
+        .trace
+        .clone()
+        .map_or_else(Telemetry::from_env, Telemetry::new)
+        .init();
+
+    if matches!(
+        args.command,
+        Command::Bench | Command::BenchImport | Command::BenchReport
+    ) {
+        return match bench(&args) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.command == Command::Scale {
+        return match scale_day(&args) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let ledger_path = args.ledger.clone().unwrap_or_else(Ledger::workspace_path);
+    if args.command == Command::Readme {
+        return match update_readme(&args, &ledger_path) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+    if matches!(args.command, Command::New | Command::Scrape) {
+        let done = match args.command {
+            Command::New => new_day(&args, &ledger_path),
+            _ => scrape_day(&args),
+        };
+        return match done {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let registry = registry();
+    let days = args.selection.days(&registry);
+    if days.is_empty() {
+        eprintln!("no registered day matches");
+        return ExitCode::FAILURE;
+    }
+
+    let mut ledger = match args.command {
+        Command::Run if !args.record => None,
+        _ => match Ledger::load(&ledger_path) {
+            Ok(ledger) => Some(ledger),
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let inputs = Inputs::new(args.input_dir.clone());
+    if let Inputs::Dir(dir) = &inputs
+        && !args.report_json
+    {
+        let fetching = if dir.fetcher().is_some() {
+            ", fetching missing ones"
+        } else {
+            ""
+        };
+        println!("inputs from {}{fetching}", dir.dir().display());
+    }
+
+    let parts = args.selection.parts();
+    let results = if args.jobs.is_some() || args.isolate {
+        match run_parallel(&args, &days, &parts, &inputs) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        days.into_iter()
+            .map(|day| {
+                run_day(day, &parts, &inputs, &args.params, |p| {
+                    if args.command == Command::Run && !args.report_json {
+                        print_part(day.year(), day.day(), p)
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    if args.report_json {
+        for result in &results {
+            println!("{}", parallel::report_line(result));
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let passed = match (args.command, &mut ledger) {
+        (Command::Verify, Some(ledger)) => check(ledger, &results, &args),
+        _ => {
+            println!();
+            println!("{}", summary(&results));
+            if let Some(ledger) = &mut ledger {
+                record(ledger, &results);
+            }
+            let failed = results
+                .iter()
+                .flat_map(|r| &r.parts)
+                .filter(|p| failed(&p.answer))
+                .count();
+            if failed > 0 {
+                eprintln!("{failed} part(s) failed");
+            }
+            failed == 0
+        }
+    };
+
+    if let Some(ledger) = &ledger
+        && (args.record || args.save_baseline)
+    {
+        if let Err(e) = ledger.save(&ledger_path) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+        println!("ledger {} updated", ledger_path.display());
+    }
+
+    if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[rstest]
+    #[case("run 2024 17 a", Selection { year: Some(2024), day: Some(17), part: Some(Part::A) })]
+    #[case("run 2024 17", Selection { year: Some(2024), day: Some(17), part: None })]
+    #[case("run 2024", Selection { year: Some(2024), day: None, part: None })]
+    #[case("run --all", Selection::all())]
+    fn parse_args_should_select(#[case] line: &str, #[case] expected: Selection) {
+        assert_eq!(parse_args(&args(line)).unwrap().selection, expected);
+    }
+
+    #[test]
+    fn parse_args_should_read_options() {
+        let sut = parse_args(&args(
+            "run 2022 15 --params row=10 --input-dir inputs --trace chrome:t.json",
+        ))
+        .unwrap();
+        assert_eq!(sut.params, Params::new().with("row", 10));
+        assert_eq!(sut.input_dir, Some(PathBuf::from("inputs")));
+        assert_eq!(
+            sut.trace,
+            Some(Output::Chrome(Some(PathBuf::from("t.json"))))
+        );
+    }
+
+    #[test]
+    fn parse_args_should_read_verify_options() {
+        let sut = parse_args(&args(
+            "verify 2024 --tolerance 2 --save-baseline --ledger l.json",
+        ))
+        .unwrap();
+        assert_eq!(sut.command, Command::Verify);
+        assert_eq!(sut.selection.year, Some(2024));
+        assert_eq!(sut.tolerance, Some(2.0));
+        assert!(sut.save_baseline);
+        assert_eq!(sut.ledger, Some(PathBuf::from("l.json")));
+    }
+
+    #[test]
+    fn parse_args_should_read_new() {
+        let sut = parse_args(&args("new 2024 7")).unwrap();
+        assert_eq!(sut.command, Command::New);
+        assert_eq!(
+            (sut.selection.year, sut.selection.day),
+            (Some(2024), Some(7))
+        );
+    }
+
+    #[test]
+    fn parse_args_should_read_scrape() {
+        let sut = parse_args(&args("scrape 2025 8 --html day08.html")).unwrap();
+        assert_eq!(sut.command, Command::Scrape);
+        assert_eq!(
+            (sut.selection.year, sut.selection.day),
+            (Some(2025), Some(8))
+        );
+        assert_eq!(sut.html, Some(PathBuf::from("day08.html")));
+    }
+
+    #[test]
+    fn parse_args_should_read_bench() {
+        let sut = parse_args(&args("bench 2024 22 --history h.jsonl")).unwrap();
+        assert_eq!(sut.command, Command::Bench);
+        assert_eq!(
+            (sut.selection.year, sut.selection.day),
+            (Some(2024), Some(22))
+        );
+        assert_eq!(sut.history, Some(PathBuf::from("h.jsonl")));
+
+        let sut = parse_args(&args("bench import aoc_2024_22.bench.txt")).unwrap();
+        assert_eq!(sut.command, Command::BenchImport);
+        assert_eq!(sut.files, vec![PathBuf::from("aoc_2024_22.bench.txt")]);
+
+        let sut = parse_args(&args("bench report --tolerance 1.2")).unwrap();
+        assert_eq!(sut.command, Command::BenchReport);
+        assert_eq!(sut.tolerance, Some(1.2));
+    }
+
+    #[test]
+    fn parse_args_should_read_readme() {
+        let sut = parse_args(&args("readme --check --ledger l.json")).unwrap();
+        assert_eq!(sut.command, Command::Readme);
+        assert!(sut.check);
+        assert_eq!(sut.ledger, Some(PathBuf::from("l.json")));
+    }
+
+    #[test]
+    fn parse_args_should_read_scale() {
+        let sut = parse_args(&args(
+            "scale 2024 18 b --scales 4,8,16 --seed 3 --budget 0.5 --params fallen=0",
+        ))
+        .unwrap();
+        assert_eq!(sut.command, Command::Scale);
+        assert_eq!(
+            sut.selection,
+            Selection {
+                year: Some(2024),
+                day: Some(18),
+                part: Some(Part::B)
+            }
+        );
+        assert_eq!(sut.scales, [4, 8, 16]);
+        assert_eq!(sut.seed, Some(3));
+        assert_eq!(sut.budget, Some(Duration::from_millis(500)));
+        assert_eq!(sut.params, Params::new().with("fallen", 0));
+    }
+
+    #[test]
+    fn parse_args_should_read_parallel() {
+        let sut = parse_args(&args("run --all --jobs 4 --isolate")).unwrap();
+        assert_eq!(sut.jobs, Some(4));
+        assert!(sut.isolate);
+
+        let sut = parse_args(&args("verify 2024 --jobs 0")).unwrap();
+        assert_eq!((sut.jobs, sut.isolate), (Some(0), true));
+
+        let sut = parse_args(&args("verify 2024 --jobs 1")).unwrap();
+        assert_eq!((sut.jobs, sut.isolate), (Some(1), false));
+
+        let sut = parse_args(&args("run 2024 7 --report-json")).unwrap();
+        assert!(sut.report_json);
+    }
+
+    #[test]
+    fn child_args_should_pass_params_and_inputs() {
+        let sut = parse_args(&args(
+            "run 2022 --isolate --params row=10 --input-dir inputs --ledger l.json",
+        ))
+        .unwrap();
+
+        let child = child_args(&sut);
+        assert_eq!(
+            child,
+            args("--trace off --params row=10 --input-dir inputs")
+        );
+        let mut line = args("run 2022 15");
+        line.extend(child);
+        line.push("--report-json".to_string());
+        assert!(parse_args(&line).unwrap().report_json);
+    }
+
+    #[rstest]
+    #[case("verify", Selection::all())]
+    #[case("run 2024 --record", Selection { year: Some(2024), day: None, part: None })]
+    fn parse_args_should_accept(#[case] line: &str, #[case] expected: Selection) {
+        assert_eq!(parse_args(&args(line)).unwrap().selection, expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("walk 2024")]
+    #[case("run")]
+    #[case("run --all 2024")]
+    #[case("run 2024 17 c")]
+    #[case("run 2024 17 a b")]
+    #[case("run twenty")]
+    #[case("run 2024 --input-dir")]
+    #[case("run 2024 --trace flame")]
+    #[case("run 2024 --save-baseline")]
+    #[case("verify 2024 --record")]
+    #[case("verify --tolerance 0.5")]
+    #[case("new 2024")]
+    #[case("new 2024 26")]
+    #[case("new 2024 7 a")]
+    #[case("new --all")]
+    #[case("new 2024 7 --html day07.html")]
+    #[case("scrape 2024")]
+    #[case("bench")]
+    #[case("bench 2024 22 a")]
+    #[case("bench import")]
+    #[case("bench report 2024")]
+    #[case("bench 2024 --tolerance 2")]
+    #[case("run 2024 --history h.jsonl")]
+    #[case("readme 2024")]
+    #[case("run 2024 --check")]
+    #[case("scrape 2024 7 --html a.html --url http://localhost")]
+    #[case("scale 2024")]
+    #[case("scale --all")]
+    #[case("scale 2024 18 --scales 4,x")]
+    #[case("scale 2024 18 --scales 0")]
+    #[case("scale 2024 18 --budget -1")]
+    #[case("run 2024 --seed 3")]
+    #[case("run 2024 --jobs")]
+    #[case("run 2024 --jobs many")]
+    #[case("bench 2024 --jobs 2")]
+    #[case("scale 2024 18 --isolate")]
+    #[case("verify --report-json")]
+    #[case("run 2024 7 --report-json --isolate")]
+    fn parse_args_should_reject(#[case] line: &str) {
+        assert!(parse_args(&args(line)).is_err());
+    }
+}
@@ -0,0 +1,470 @@
+/*! Running days at once, aoc run --jobs <n> [--isolate]
+ *
+ * --jobs n runs n days at once on a rayon pool, 0 takes one thread per cpu. Days in the
+ * same process would print into each other, so more than one job implies --isolate.
+ *
+ * --isolate runs every day in its own process, `aoc run <year> <day> --report-json`, for
+ * timings without neighbours in the same heap. A panic that aborts, e.g. a stack overflow,
+ * only fails that day. Its stdout and stderr are captured, the last line starting with
+ * REPORT_MARKER holds the results:
+ *
+ * aoc-report: [{"part":"a","outcome":{"Int":"42"},"elapsed_ns":1200}]
+ *
+ * Errors cross the process boundary rendered as Error::Failed. Missing inputs are fetched
+ * before a child starts, here the fetcher spaces the requests of all days. The children run
+ * without AOC_SESSION, each of them would only throttle its own requests.
+*/
+use std::{
+    path::PathBuf,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use aoc_utils::{Answer, Params, Solution, input::SESSION_VAR, solution::Error};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::Inputs,
+    run::{DayResult, Part, PartResult, run_day},
+    table::{format_duration, markdown},
+};
+
+pub const REPORT_MARKER: &str = "aoc-report: ";
+
+/// A day run on the pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRun {
+    pub result: DayResult,
+    /// from start to end of the day, with the process start if isolated
+    pub wall: Duration,
+    /// stdout and stderr of an isolated day, empty in the process
+    pub output: String,
+}
+
+/// How the days are run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    InProcess,
+    /// program and arguments of a child run, year, day and part are added
+    Isolated {
+        program: PathBuf,
+        args: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum Outcome {
+    /// i128 does not fit into every json reader
+    Int(String),
+    Text(String),
+    Ocr(String),
+    NotSolved,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Reported {
+    part: char,
+    outcome: Outcome,
+    elapsed_ns: u64,
+}
+
+impl From<&PartResult> for Reported {
+    fn from(result: &PartResult) -> Self {
+        let outcome = match &result.answer {
+            Ok(Answer::Int(n)) => Outcome::Int(n.to_string()),
+            Ok(Answer::Text(s)) => Outcome::Text(s.clone()),
+            Ok(Answer::Ocr(pixels)) => Outcome::Ocr(pixels.clone()),
+            Err(Error::NotSolved) => Outcome::NotSolved,
+            Err(Error::Failed(reason)) => Outcome::Failed(reason.clone()),
+            Err(e) => Outcome::Failed(e.render()),
+        };
+        Self {
+            part: result.part.into(),
+            outcome,
+            elapsed_ns: result.elapsed.as_nanos() as u64,
+        }
+    }
+}
+
+impl TryFrom<Reported> for PartResult {
+    type Error = String;
+
+    fn try_from(reported: Reported) -> Result<Self, Self::Error> {
+        let answer = match reported.outcome {
+            Outcome::Int(n) => Ok(Answer::Int(
+                n.parse().map_err(|_| format!("invalid answer {n}"))?,
+            )),
+            Outcome::Text(s) => Ok(Answer::Text(s)),
+            Outcome::Ocr(pixels) => Ok(Answer::Ocr(pixels)),
+            Outcome::NotSolved => Err(Error::NotSolved),
+            Outcome::Failed(reason) => Err(Error::Failed(reason)),
+        };
+        Ok(PartResult {
+            part: reported.part.to_string().parse()?,
+            answer,
+            elapsed: Duration::from_nanos(reported.elapsed_ns),
+        })
+    }
+}
+
+/// The line a child run ends with
+pub fn report_line(result: &DayResult) -> String {
+    let reported = result.parts.iter().map(Reported::from).collect::<Vec<_>>();
+    let json = serde_json::to_string(&reported).expect("results serialize");
+    format!("{REPORT_MARKER}{json}")
+}
+
+/// Results of a child run and its remaining output, Err if there is no report
+pub fn parse_report(stdout: &str) -> Result<(Vec<PartResult>, String), String> {
+    let lines = stdout.lines().collect::<Vec<_>>();
+    let index = lines
+        .iter()
+        .rposition(|line| line.starts_with(REPORT_MARKER))
+        .ok_or("no report")?;
+    let reported: Vec<Reported> = serde_json::from_str(&lines[index][REPORT_MARKER.len()..])
+        .map_err(|e| format!("invalid report: {e}"))?;
+    let parts = reported
+        .into_iter()
+        .map(PartResult::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let output = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, line)| format!("{line}\n"))
+        .collect();
+    Ok((parts, output))
+}
+
+fn run_isolated(
+    solution: &dyn Solution,
+    parts: &[Part],
+    inputs: &Inputs,
+    program: &PathBuf,
+    args: &[String],
+) -> DayRun {
+    let failed = |reason: String| {
+        parts
+            .iter()
+            .map(|&part| PartResult {
+                part,
+                answer: Err(Error::Failed(reason.clone())),
+                elapsed: Duration::ZERO,
+            })
+            .collect()
+    };
+    let day_run = |parts, wall, output| DayRun {
+        result: DayResult {
+            year: solution.year(),
+            day: solution.day(),
+            title: solution.title(),
+            parts,
+        },
+        wall,
+        output,
+    };
+
+    if let Inputs::Dir(dir) = inputs
+        && dir.fetcher().is_some()
+        && let Err(e) = dir.load(solution.year(), solution.day())
+    {
+        return day_run(failed(e.to_string()), Duration::ZERO, String::new());
+    }
+
+    let mut command = Command::new(program);
+    command.args([
+        "run",
+        &solution.year().to_string(),
+        &solution.day().to_string(),
+    ]);
+    if let [part] = parts {
+        command.arg(part.to_string());
+    }
+    command
+        .args(args)
+        .arg("--report-json")
+        .env_remove(SESSION_VAR);
+
+    let start = Instant::now();
+    let child = command.output();
+    let wall = start.elapsed();
+
+    let (reported, output) = match child {
+        Ok(child) => {
+            let stdout = String::from_utf8_lossy(&child.stdout);
+            let stderr = String::from_utf8_lossy(&child.stderr);
+            match parse_report(&stdout) {
+                Ok((parts, output)) => (Ok(parts), output + &stderr),
+                Err(e) => (
+                    Err(format!("{e} of the child, {}", child.status)),
+                    format!("{stdout}{stderr}"),
+                ),
+            }
+        }
+        Err(e) => (Err(format!("{}: {e}", program.display())), String::new()),
+    };
+    day_run(reported.unwrap_or_else(failed), wall, output)
+}
+
+/// Run the days on a pool of jobs threads, 0 for one per cpu.
+/// on_day sees every day as soon as it is done, the results keep the order of days.
+/// Returns the runs and the size of the pool.
+pub fn run_days<F>(
+    days: &[&'static dyn Solution],
+    parts: &[Part],
+    inputs: &Inputs,
+    params: &Params,
+    mode: &Mode,
+    jobs: usize,
+    on_day: F,
+) -> Result<(Vec<DayRun>, usize), String>
+where
+    F: Fn(&DayRun) + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let runs = pool.install(|| {
+        days.par_iter()
+            .map(|&day| {
+                let run = match mode {
+                    Mode::InProcess => {
+                        let start = Instant::now();
+                        let result = run_day(day, parts, inputs, params, |_| {});
+                        DayRun {
+                            result,
+                            wall: start.elapsed(),
+                            output: String::new(),
+                        }
+                    }
+                    Mode::Isolated { program, args } => {
+                        run_isolated(day, parts, inputs, program, args)
+                    }
+                };
+                on_day(&run);
+                run
+            })
+            .collect::<Vec<_>>()
+    });
+    Ok((runs, pool.current_num_threads()))
+}
+
+/// Markdown table of the time per day and a line with the pool and the speedup
+pub fn report(runs: &[DayRun], pool: usize, wall: Duration, isolated: bool) -> String {
+    let header = ["Year", "Day", "Title", "Time", "Wall", "Output"].map(String::from);
+    let mut rows = vec![header];
+    for run in runs {
+        let lines = run.output.lines().count();
+        rows.push([
+            run.result.year.to_string(),
+            format!("{:02}", run.result.day),
+            run.result.title.to_string(),
+            format_duration(run.result.elapsed()),
+            format_duration(run.wall),
+            if lines > 0 {
+                format!("{lines} lines")
+            } else {
+                String::new()
+            },
+        ]);
+    }
+
+    let summed = runs.iter().map(|r| r.wall).sum::<Duration>();
+    let speedup = summed.as_secs_f64() / wall.as_secs_f64().max(f64::EPSILON);
+    format!(
+        "{}\n{} day(s) on {pool} thread(s){}: {}, {} summed, {speedup:.1}x",
+        markdown(&rows),
+        runs.len(),
+        if isolated { ", one process each" } else { "" },
+        format_duration(wall),
+        format_duration(summed),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{aoc_9999_06, aoc_9999_07};
+    use aoc_utils::input::{Fetcher, InputDir};
+    use rstest::rstest;
+    use std::sync::Mutex;
+
+    fn part(part: Part, answer: Result<Answer, Error>) -> PartResult {
+        PartResult {
+            part,
+            answer,
+            elapsed: Duration::from_micros(15),
+        }
+    }
+
+    #[rstest]
+    #[case(part(Part::A, Ok(Answer::Int(i128::MIN))))]
+    #[case(part(Part::B, Ok(Answer::Text("abc".into()))))]
+    #[case(part(Part::A, Ok(Answer::Ocr("#.#\n.#.".into()))))]
+    #[case(part(Part::B, Err(Error::NotSolved)))]
+    #[case(part(Part::A, Err(Error::Failed("no way".into()))))]
+    fn report_line_should_round_trip(#[case] sut: PartResult) {
+        let result = DayResult {
+            year: 9999,
+            day: 6,
+            title: "Lines",
+            parts: vec![sut.clone()],
+        };
+        let stdout = format!("debug\n{}\nmore debug", report_line(&result));
+
+        assert_eq!(
+            parse_report(&stdout),
+            Ok((vec![sut], "debug\nmore debug\n".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_report_should_render_param_errors() {
+        let result = DayResult {
+            year: 9999,
+            day: 6,
+            title: "Lines",
+            parts: vec![part(
+                Part::A,
+                Err(Error::Param {
+                    key: "row".into(),
+                    value: "x".into(),
+                }),
+            )],
+        };
+
+        let (sut, _) = parse_report(&report_line(&result)).unwrap();
+
+        assert_eq!(
+            sut[0].answer,
+            Err(Error::Failed("invalid parameter row=x".into()))
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("thread 'main' has overflowed its stack")]
+    #[case("aoc-report: [{\"part\":\"c\"}]")]
+    fn parse_report_should_reject(#[case] stdout: &str) {
+        assert!(parse_report(stdout).is_err());
+    }
+
+    #[test]
+    fn run_days_should_keep_order() {
+        let dir = std::env::temp_dir().join("aoc_runner_run_days");
+        std::fs::create_dir_all(dir.join("9999")).unwrap();
+        std::fs::write(dir.join("9999").join("06.txt"), "1\n2\n3\n").unwrap();
+        std::fs::write(dir.join("9999").join("07.txt"), "").unwrap();
+        let days: [&'static dyn Solution; 3] = [
+            &aoc_9999_06::Puzzle,
+            &aoc_9999_07::Puzzle,
+            &aoc_9999_06::Puzzle,
+        ];
+
+        let seen = Mutex::new(0);
+        let (sut, pool) = run_days(
+            &days,
+            &Part::BOTH,
+            &Inputs::Dir(InputDir::new(dir)),
+            &Params::new(),
+            &Mode::InProcess,
+            2,
+            |_| *seen.lock().unwrap() += 1,
+        )
+        .unwrap();
+
+        assert_eq!(pool, 2);
+        assert_eq!(*seen.lock().unwrap(), 3);
+        let days = sut.iter().map(|r| r.result.day).collect::<Vec<_>>();
+        assert_eq!(days, [6, 7, 6]);
+        assert_eq!(
+            sut[0].result.part(Part::B).unwrap().answer,
+            Ok(Answer::Text("3".into()))
+        );
+        assert!(matches!(
+            sut[1].result.part(Part::A).unwrap().answer,
+            Err(Error::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn run_days_should_fail_day_without_child() {
+        let mode = Mode::Isolated {
+            program: PathBuf::from("does/not/exist"),
+            args: Vec::new(),
+        };
+        let (sut, _) = run_days(
+            &[&aoc_9999_06::Puzzle],
+            &[Part::A],
+            &Inputs::Dir(InputDir::new("does/not/exist")),
+            &Params::new(),
+            &mode,
+            1,
+            |_| {},
+        )
+        .unwrap();
+
+        assert!(matches!(
+            &sut[0].result.parts[..],
+            [PartResult { part: Part::A, answer: Err(Error::Failed(reason)), .. }]
+                if reason.contains("does/not/exist")
+        ));
+    }
+
+    #[test]
+    fn run_days_should_fetch_before_child() {
+        let fetcher = Fetcher::new("session")
+            .with_base_url("http://127.0.0.1:9")
+            .with_interval(Duration::ZERO);
+        let dir = InputDir::new(std::env::temp_dir().join("aoc_runner_no_inputs"));
+        let mode = Mode::Isolated {
+            program: PathBuf::from("does/not/exist"),
+            args: Vec::new(),
+        };
+        let (sut, _) = run_days(
+            &[&aoc_9999_06::Puzzle],
+            &[Part::A],
+            &Inputs::Dir(dir.with_fetcher(fetcher)),
+            &Params::new(),
+            &mode,
+            1,
+            |_| {},
+        )
+        .unwrap();
+
+        assert!(matches!(
+            &sut[0].result.parts[..],
+            [PartResult { answer: Err(Error::Failed(reason)), .. }]
+                if reason.starts_with("fetching input failed")
+        ));
+    }
+
+    #[test]
+    fn report_should_give_pool_and_speedup() {
+        let run = |day, millis, output: &str| DayRun {
+            result: DayResult {
+                year: 9999,
+                day,
+                title: "Lines",
+                parts: vec![part(Part::A, Ok(Answer::Int(1)))],
+            },
+            wall: Duration::from_millis(millis),
+            output: output.to_string(),
+        };
+        let runs = [run(6, 30, "a\nb\n"), run(7, 10, "")];
+
+        let sut = report(&runs, 4, Duration::from_millis(20), true);
+
+        assert!(sut.contains("| 2 lines |"), "{sut}");
+        assert!(
+            sut.ends_with(
+                "2 day(s) on 4 thread(s), one process each: 20.00ms, 40.00ms summed, 2.0x"
+            )
+        );
+    }
+}
@@ -0,0 +1,186 @@
+/*! aoc readme, progress tables of README.md
+ *
+ * Everything between the markers is generated, a table per year with a crate or an answer:
+ *
+ * <!-- aoc readme start -->
+ * ## 2024
+ * | Day | Title         | Stars | Time   | Crate                        |
+ * | --- | ------------- | ----- | ------ | ---------------------------- |
+ * | 07  | Bridge Repair | ⭐⭐  | 2.00ms | [aoc_2024_07](aoc_2024_07)   |
+ * <!-- aoc readme end -->
+ *
+ * A star is a verified answer in the ledger (aoc_data.json), not the star on the website.
+ * The time is the median of the latest benches (bench_history.jsonl), the baseline of the
+ * ledger for days without benches.
+*/
+use std::{collections::BTreeSet, path::Path, time::Duration};
+
+use aoc_utils::ledger::Ledger;
+
+use crate::{
+    bench::History,
+    table::{format_duration, markdown},
+};
+
+pub const START: &str = "<!-- aoc readme start -->";
+pub const END: &str = "<!-- aoc readme end -->";
+
+/// year and day of every aoc_<year>_<dd> crate below root
+pub fn day_crates(root: &Path) -> std::io::Result<BTreeSet<(u16, u8)>> {
+    let mut days = BTreeSet::new();
+    for entry in std::fs::read_dir(root)? {
+        let entry = entry?;
+        if !entry.path().join("Cargo.toml").is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if let Some((year, day)) = name
+            .strip_prefix("aoc_")
+            .and_then(|rest| rest.split_once('_'))
+            .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)))
+        {
+            days.insert((year, day));
+        }
+    }
+    Ok(days)
+}
+
+/// median of the latest benches of a day, summed over its benches
+fn bench_time(history: &History, year: u16, day: u8) -> Option<Duration> {
+    let nanos = history
+        .timings()
+        .iter()
+        .filter(|t| t.sample.year == year && t.sample.day == day)
+        .map(|t| t.sample.median_ns)
+        .reduce(|a, b| a + b)?;
+    Some(Duration::from_nanos(nanos))
+}
+
+/// Tables of all years with a crate or a verified answer, newest year first
+pub fn tables(ledger: &Ledger, crates: &BTreeSet<(u16, u8)>, history: &History) -> String {
+    let mut days = crates.clone();
+    days.extend(
+        ledger
+            .entries()
+            .iter()
+            .filter(|e| e.answer_a.is_some() || e.answer_b.is_some())
+            .map(|e| (e.year, e.day)),
+    );
+    let years = days.iter().map(|&(year, _)| year).collect::<BTreeSet<_>>();
+    // the titles of the whole year, a day without crate is still to do
+    days.extend(
+        ledger
+            .entries()
+            .iter()
+            .filter(|e| years.contains(&e.year))
+            .map(|e| (e.year, e.day)),
+    );
+
+    let mut sections = Vec::new();
+    for &year in years.iter().rev() {
+        let mut rows = vec![["Day", "Title", "Stars", "Time", "Crate"].map(String::from)];
+        for &(_, day) in days.iter().filter(|(y, _)| *y == year) {
+            let entry = ledger.get(year, day);
+            let stars = ['a', 'b']
+                .iter()
+                .filter(|&&part| entry.and_then(|e| e.answer(part)).is_some())
+                .map(|_| "⭐")
+                .collect::<String>();
+            let baseline = entry.and_then(|e| {
+                ['a', 'b']
+                    .iter()
+                    .filter_map(|&part| e.baseline(part))
+                    .reduce(|a, b| a + b)
+            });
+            let time = bench_time(history, year, day).or(baseline);
+            let name = format!("aoc_{year}_{day:02}");
+            rows.push([
+                format!("{day:02}"),
+                entry.map(|e| e.title.clone()).unwrap_or_default(),
+                stars,
+                time.map(format_duration).unwrap_or_default(),
+                if crates.contains(&(year, day)) {
+                    format!("[{name}]({name})")
+                } else {
+                    String::new()
+                },
+            ]);
+        }
+        sections.push(format!("## {year}\n{}", markdown(&rows)));
+    }
+    sections.join("\n\n")
+}
+
+/// readme with the part between the markers replaced, None without markers
+pub fn update(readme: &str, tables: &str) -> Option<String> {
+    let start = readme.find(START)? + START.len();
+    let end = start + readme[start..].find(END)?;
+    Some(format!(
+        "{}\n{tables}\n{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::{BenchRun, Sample};
+
+    const LEDGER: &str = r#"[
+  { "year": 2023, "day": 1, "title": "Trebuchet?!", "a": "*", "b": "*" },
+  { "year": 2024, "day": 1, "title": "Historian Hysteria", "a": "*", "b": "*",
+    "answer_a": "11", "answer_b": "31", "time_a_ns": 1000, "time_b_ns": 2000 },
+  { "year": 2024, "day": 2, "title": "Red-Nosed Reports", "a": "*", "b": "",
+    "answer_a": "2" },
+  { "year": 2024, "day": 3, "title": "Mull It Over", "a": "", "b": "" }
+]"#;
+
+    fn history() -> History {
+        let mut history = History::default();
+        history.push(BenchRun::new(
+            "a",
+            vec![Sample {
+                year: 2024,
+                day: 2,
+                bench: "part1".to_string(),
+                fastest_ns: 4000,
+                median_ns: 5000,
+                mean_ns: 6000,
+                samples: 100,
+            }],
+        ));
+        history
+    }
+
+    #[test]
+    fn tables_should_list_years_with_work() {
+        let ledger = LEDGER.parse::<Ledger>().unwrap();
+        let crates = BTreeSet::from([(2024, 1), (2024, 2)]);
+
+        let sut = tables(&ledger, &crates, &history());
+
+        let lines = sut.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "## 2024");
+        assert_eq!(lines.len(), 6);
+        assert!(lines[3].starts_with(
+            "| 01  | Historian Hysteria | ⭐⭐    | 3.00µs | [aoc_2024_01](aoc_2024_01) |"
+        ));
+        assert!(lines[4].starts_with(
+            "| 02  | Red-Nosed Reports  | ⭐     | 5.00µs | [aoc_2024_02](aoc_2024_02) |"
+        ));
+        assert!(lines[5].starts_with("| 03  | Mull It Over       |"));
+        assert!(!sut.contains("2023"));
+    }
+
+    #[test]
+    fn update_should_replace_between_markers() {
+        let readme = format!("# aoc\n{START}\nold\n{END}\nrest\n");
+
+        let sut = update(&readme, "new").unwrap();
+
+        assert_eq!(sut, format!("# aoc\n{START}\nnew\n{END}\nrest\n"));
+        assert_eq!(update(&sut, "new").unwrap(), sut);
+        assert_eq!(update("# aoc\n", "new"), None);
+    }
+}
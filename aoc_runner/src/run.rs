@@ -0,0 +1,245 @@
+use std::{
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_utils::{
+    Answer, Params, Solution, heap,
+    solution::{Error, Registry},
+};
+
+use crate::input::Inputs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::A, Part::B];
+
+    pub fn solve(
+        self,
+        solution: &dyn Solution,
+        input: &str,
+        params: &Params,
+    ) -> Result<Answer, Error> {
+        match self {
+            Part::A => solution.part_a(input, params),
+            Part::B => solution.part_b(input, params),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(format!("invalid part {s}, expected a or b")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+/// the ledger names the parts 'a' and 'b'
+impl From<Part> for char {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::A => 'a',
+            Part::B => 'b',
+        }
+    }
+}
+
+/// Which days and parts to run, None matches everything
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn days(&self, registry: &Registry) -> Vec<&'static dyn Solution> {
+        registry
+            .iter()
+            .filter(|s| self.year.is_none_or(|y| y == s.year()))
+            .filter(|s| self.day.is_none_or(|d| d == s.day()))
+            .collect()
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        self.part.map_or(Part::BOTH.to_vec(), |p| vec![p])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Result<Answer, Error>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    pub fn elapsed(&self) -> Duration {
+        self.parts.iter().map(|p| p.elapsed).sum()
+    }
+
+    pub fn part(&self, part: Part) -> Option<&PartResult> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+}
+
+/// A panic of the solution (most of them unwrap their parsing) fails only this part.
+/// With the feature dhat-heap every part gets its own heap profile.
+pub fn run_part(solution: &dyn Solution, part: Part, input: &str, params: &Params) -> PartResult {
+    let start = Instant::now();
+    let (year, day) = (solution.year(), solution.day());
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        heap::profile(year, day, part.into(), || {
+            part.solve(solution, input, params)
+        })
+    }))
+    .unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(Error::Failed(format!("panicked {reason}")))
+    });
+    PartResult {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Run the parts of a day, a missing input fails every part.
+/// on_part sees every result as soon as it is done.
+pub fn run_day<F>(
+    solution: &dyn Solution,
+    parts: &[Part],
+    inputs: &Inputs,
+    params: &Params,
+    mut on_part: F,
+) -> DayResult
+where
+    F: FnMut(&PartResult),
+{
+    let input = inputs
+        .read(solution.year(), solution.day())
+        .map_err(|e| Error::Failed(e.to_string()));
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let result = match &input {
+                Ok(input) => run_part(solution, part, input, params),
+                Err(e) => PartResult {
+                    part,
+                    answer: Err(e.clone()),
+                    elapsed: Duration::ZERO,
+                },
+            };
+            on_part(&result);
+            result
+        })
+        .collect();
+
+    DayResult {
+        year: solution.year(),
+        day: solution.day(),
+        title: solution.title(),
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{aoc_9998_02, aoc_9999_01, aoc_9999_03};
+    use aoc_utils::input::InputDir;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Selection::all(), 2)]
+    #[case(Selection { year: Some(9999), ..Selection::all() }, 1)]
+    #[case(Selection { year: Some(9999), day: Some(2), part: None }, 0)]
+    fn selection_should_filter_days(#[case] sut: Selection, #[case] expected: usize) {
+        let registry = aoc_utils::registry![aoc_9999_01, aoc_9998_02];
+        assert_eq!(sut.days(&registry).len(), expected);
+    }
+
+    #[rstest]
+    #[case("a", Ok(Part::A))]
+    #[case("B", Ok(Part::B))]
+    #[case("c", Err("invalid part c, expected a or b".to_string()))]
+    fn part_should_parse(#[case] input: &str, #[case] expected: Result<Part, String>) {
+        assert_eq!(input.parse::<Part>(), expected);
+    }
+
+    #[test]
+    fn run_day_should_report_every_part() {
+        let dir = std::env::temp_dir().join("aoc_runner_run_day");
+        std::fs::create_dir_all(dir.join("9999")).unwrap();
+        std::fs::write(dir.join("9999").join("01.txt"), "1\n2\n3\n").unwrap();
+
+        let mut seen = Vec::new();
+        let sut = run_day(
+            &aoc_9999_01::Puzzle,
+            &Part::BOTH,
+            &Inputs::Dir(InputDir::new(dir)),
+            &Params::new(),
+            |p| seen.push(p.part),
+        );
+
+        assert_eq!(seen, Part::BOTH);
+        assert_eq!(sut.part(Part::A).unwrap().answer, Ok(Answer::Int(3)));
+        assert_eq!(sut.part(Part::B).unwrap().answer, Err(Error::NotSolved));
+    }
+
+    #[test]
+    fn run_part_should_catch_panics() {
+        let sut = run_part(&aoc_9999_03::Puzzle, Part::A, "x", &Params::new());
+        assert!(matches!(sut.answer, Err(Error::Failed(reason)) if reason.contains("a number")));
+    }
+
+    #[test]
+    fn run_day_should_fail_without_input() {
+        let sut = run_day(
+            &aoc_9998_02::Puzzle,
+            &[Part::A],
+            &Inputs::Dir(InputDir::new("does/not/exist")),
+            &Params::new(),
+            |_| {},
+        );
+        assert!(matches!(sut.parts[0].answer, Err(Error::Failed(_))));
+    }
+}
@@ -0,0 +1,242 @@
+/*! aoc new <year> <day>, create the crate of a day from template/
+ *
+ * Every file of the template is copied, placeholders are replaced in contents and paths:
+ *
+ * {{year}}   2024
+ * {{day}}    7
+ * {{dd}}     07
+ * {{crate}}  aoc_2024_07
+ * {{title}}  Bridge Repair, from aoc_data.json
+ *
+ * The manifest is always written as Cargo.toml, cargo on linux ignores a cargo.toml.
+ * The new crate is registered in Cargo.toml and days.rs of the runner and of the wasm
+ * playground www.
+*/
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+}
+
+impl NewDay {
+    pub fn crate_name(&self) -> String {
+        format!("aoc_{}_{:02}", self.year, self.day)
+    }
+
+    pub fn render(&self, template: &str) -> String {
+        // the title ends up in a string literal of lib.rs
+        let title = self.title.replace('\\', "\\\\").replace('"', "\\\"");
+        template
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{dd}}", &format!("{:02}", self.day))
+            .replace("{{crate}}", &self.crate_name())
+            .replace("{{title}}", &title)
+    }
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} exists already", path.display()),
+            ScaffoldError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> ScaffoldError + '_ {
+    |e| ScaffoldError::Io(path.to_path_buf(), e)
+}
+
+/// relative paths of all files below dir
+fn files(dir: &Path) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let mut files = Vec::new();
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(relative) = dirs.pop() {
+        let current = dir.join(&relative);
+        for entry in std::fs::read_dir(&current).map_err(io_error(&current))? {
+            let entry = entry.map_err(io_error(&current))?;
+            let path = relative.join(entry.file_name());
+            if entry.path().is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Render template into root/aoc_{year}_{dd}, returns the written files
+pub fn create(root: &Path, template: &Path, day: &NewDay) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let target = root.join(day.crate_name());
+    if target.exists() {
+        return Err(ScaffoldError::Exists(target));
+    }
+
+    let mut written = Vec::new();
+    for relative in files(template)? {
+        let source = template.join(&relative);
+        let mut path = target.join(day.render(&relative.to_string_lossy()));
+        if path
+            .file_name()
+            .is_some_and(|n| n.eq_ignore_ascii_case("cargo.toml"))
+        {
+            path.set_file_name("Cargo.toml");
+        }
+
+        let content = std::fs::read_to_string(&source).map_err(io_error(&source))?;
+        let dir = path.parent().expect("file in crate");
+        std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+        std::fs::write(&path, day.render(&content)).map_err(io_error(&path))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Insert line among the lines of the same kind, sorted. Lines of the kind are recognized by
+/// is_kind and must be one block. None if the line is there already or there is no block.
+fn insert_sorted(text: &str, line: &str, is_kind: impl Fn(&str) -> bool) -> Option<String> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let first = lines.iter().position(|l| is_kind(l))?;
+    let block = lines[first..].iter().take_while(|l| is_kind(l)).count();
+    if lines[first..first + block].contains(&line) {
+        return None;
+    }
+    let index = first + lines[first..first + block].partition_point(|l| *l < line);
+
+    let mut lines = lines;
+    lines.insert(index, line);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Some(text)
+}
+
+fn is_day_crate(line: &str) -> bool {
+    line.trim_start()
+        .strip_prefix("aoc_")
+        .and_then(|rest| rest.get(..7))
+        .is_some_and(|year_day| {
+            year_day
+                .char_indices()
+                .all(|(i, c)| if i == 4 { c == '_' } else { c.is_ascii_digit() })
+        })
+}
+
+/// Add the day as dependency and to the registry of the runner or www, false if it is
+/// registered already
+pub fn register(runner: &Path, day: &NewDay) -> Result<bool, ScaffoldError> {
+    let name = day.crate_name();
+    let manifest = runner.join("Cargo.toml");
+    let days = runner.join("src").join("days.rs");
+
+    let mut registered = false;
+    for (path, line) in [
+        (&manifest, format!("{name} = {{ path = \"../{name}\" }}")),
+        (&days, format!("        {name},")),
+    ] {
+        let text = std::fs::read_to_string(path).map_err(io_error(path))?;
+        if let Some(text) = insert_sorted(&text, &line, is_day_crate) {
+            std::fs::write(path, text).map_err(io_error(path))?;
+            registered = true;
+        }
+    }
+    Ok(registered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn day() -> NewDay {
+        NewDay {
+            year: 2024,
+            day: 7,
+            title: "Bridge \"Repair\"".to_string(),
+        }
+    }
+
+    #[rstest]
+    #[case("src/bin/{{crate}}_a.rs", "src/bin/aoc_2024_07_a.rs")]
+    #[case(
+        "use {{crate}}::{INPUT, {{crate}}_a};",
+        "use aoc_2024_07::{INPUT, aoc_2024_07_a};"
+    )]
+    #[case("year: {{year}},\n    day: {{day}},", "year: 2024,\n    day: 7,")]
+    #[case("{{year}}/{{dd}}.txt", "2024/07.txt")]
+    #[case("title: \"{{title}}\",", "title: \"Bridge \\\"Repair\\\"\",")]
+    fn render_should_replace_placeholders(#[case] template: &str, #[case] expected: &str) {
+        assert_eq!(day().render(template), expected);
+    }
+
+    #[rstest]
+    #[case("aoc_2024_07 = { path = \"../aoc_2024_07\" }", true)]
+    #[case("        aoc_2016_01,", true)]
+    #[case("aoc_utils = { path = \"../aoc_utils\" }", false)]
+    #[case("aoc_runner", false)]
+    fn is_day_crate_should_match_days(#[case] line: &str, #[case] expected: bool) {
+        assert_eq!(is_day_crate(line), expected);
+    }
+
+    #[test]
+    fn insert_sorted_should_keep_order() {
+        let text = "    aoc_utils::registry![\n        aoc_2016_01,\n        aoc_2025_11,\n    ]\n";
+        let sut = insert_sorted(text, "        aoc_2024_07,", is_day_crate);
+        assert_eq!(
+            sut.as_deref(),
+            Some(
+                "    aoc_utils::registry![\n        aoc_2016_01,\n        aoc_2024_07,\n        aoc_2025_11,\n    ]\n"
+            )
+        );
+        assert_eq!(
+            insert_sorted(text, "        aoc_2016_01,", is_day_crate),
+            None
+        );
+    }
+
+    #[test]
+    fn create_should_render_template() {
+        let root = std::env::temp_dir().join("aoc_runner_scaffold");
+        let _ = std::fs::remove_dir_all(&root);
+        let template = root.join("template");
+        std::fs::create_dir_all(template.join("src").join("bin")).unwrap();
+        std::fs::write(template.join("cargo.toml"), "name = \"{{crate}}\"").unwrap();
+        std::fs::write(template.join("src").join("bin").join("{{crate}}_a.rs"), "").unwrap();
+
+        let sut = create(&root, &template, &day()).unwrap();
+
+        let target = root.join("aoc_2024_07");
+        assert_eq!(
+            sut,
+            vec![
+                target.join("Cargo.toml"),
+                target.join("src").join("bin").join("aoc_2024_07_a.rs")
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(target.join("Cargo.toml")).unwrap(),
+            "name = \"aoc_2024_07\""
+        );
+        assert!(matches!(
+            create(&root, &template, &day()),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+/*! Scaling curves on synthetic inputs, aoc scale
+ *
+ * aoc scale <year> <day> [a|b] runs a part on the inputs of the day's generator
+ * (Solution::generate) for growing scales and reports the time against the input size.
+ * The growth exponent k of time ~ size^k shows accidental quadratic behaviour long before
+ * the real input takes minutes:
+ *
+ * | Scale | Bytes | Answer | Time    | k    |
+ * |-------|-------|--------|---------|------|
+ * | 100   | 1530  | 42     | 1.20ms  |      |
+ * | 200   | 3071  | 97     | 4.81ms  | 2.00 |
+ *
+ * Every point is the fastest of a few runs, a point slower than the budget ends the curve.
+ * Runs below MIN_TIME are too noisy for the fit and only show their time.
+*/
+use std::time::Duration;
+
+use aoc_utils::{Answer, Params, Solution, solution::Error};
+
+use crate::{
+    run::{Part, run_part},
+    table::{cell, format_duration, markdown},
+};
+
+pub const SCALES: [usize; 7] = [10, 20, 40, 80, 160, 320, 640];
+/// default time of a single run after which larger scales are skipped
+pub const BUDGET: Duration = Duration::from_secs(2);
+/// a fitted exponent from here on is reported as quadratic
+pub const QUADRATIC: f64 = 1.5;
+const MIN_TIME: Duration = Duration::from_millis(1);
+const REPEATS: usize = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub scale: usize,
+    /// length of the generated input
+    pub bytes: usize,
+    pub answer: Result<Answer, Error>,
+    /// fastest of the runs
+    pub elapsed: Duration,
+}
+
+/// Run part on the generated input of every scale until a run exceeds the budget.
+/// params override the ones of the generator. None if the day has no generator.
+pub fn curve(
+    solution: &dyn Solution,
+    part: Part,
+    seed: u64,
+    scales: &[usize],
+    params: &Params,
+    budget: Duration,
+) -> Option<Vec<Point>> {
+    let mut points = Vec::new();
+    for &scale in scales {
+        let mut generated = solution.generate(seed, scale)?;
+        generated.params.extend(params);
+
+        let mut fastest = run_part(solution, part, &generated.input, &generated.params);
+        for _ in 1..REPEATS {
+            if fastest.answer.is_err() || fastest.elapsed > budget / REPEATS as u32 {
+                break;
+            }
+            let again = run_part(solution, part, &generated.input, &generated.params);
+            fastest.elapsed = fastest.elapsed.min(again.elapsed);
+        }
+
+        let over_budget = fastest.elapsed > budget;
+        points.push(Point {
+            scale,
+            bytes: generated.input.len(),
+            answer: fastest.answer,
+            elapsed: fastest.elapsed,
+        });
+        if over_budget {
+            break;
+        }
+    }
+    Some(points)
+}
+
+/// growth exponent from a to b, None if one is too fast to tell
+pub fn exponent(a: &Point, b: &Point) -> Option<f64> {
+    (a.elapsed >= MIN_TIME && b.elapsed >= MIN_TIME && b.bytes > a.bytes).then(|| {
+        (b.elapsed.as_secs_f64() / a.elapsed.as_secs_f64()).ln()
+            / (b.bytes as f64 / a.bytes as f64).ln()
+    })
+}
+
+/// least squares slope of log time over log size of the points slow enough to measure
+pub fn fit(points: &[Point]) -> Option<f64> {
+    let logs = points
+        .iter()
+        .filter(|p| p.elapsed >= MIN_TIME && p.bytes > 0)
+        .map(|p| ((p.bytes as f64).ln(), p.elapsed.as_secs_f64().ln()))
+        .collect::<Vec<_>>();
+    if logs.len() < 2 {
+        return None;
+    }
+
+    let n = logs.len() as f64;
+    let (mean_x, mean_y) = logs
+        .iter()
+        .fold((0.0, 0.0), |(x, y), (lx, ly)| (x + lx / n, y + ly / n));
+    let (cov, var) = logs.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+        (
+            cov + (x - mean_x) * (y - mean_y),
+            var + (x - mean_x) * (x - mean_x),
+        )
+    });
+    (var > 0.0).then(|| cov / var)
+}
+
+/// Markdown table of the curve and a line with the fitted exponent
+pub fn report(points: &[Point]) -> String {
+    let header = ["Scale", "Bytes", "Answer", "Time", "k"].map(String::from);
+    let mut rows = vec![header];
+    let mut previous: Option<&Point> = None;
+    for p in points {
+        let k = previous
+            .and_then(|prev| exponent(prev, p))
+            .map_or(String::new(), |k| format!("{k:.2}"));
+        rows.push([
+            p.scale.to_string(),
+            p.bytes.to_string(),
+            cell(&p.answer),
+            format_duration(p.elapsed),
+            k,
+        ]);
+        previous = Some(p);
+    }
+
+    let verdict = match fit(points) {
+        Some(k) if k >= QUADRATIC => format!("time ~ size^{k:.2}, looks quadratic or worse"),
+        Some(k) => format!("time ~ size^{k:.2}"),
+        None => format!(
+            "too fast to fit, the runs need at least {}",
+            format_duration(MIN_TIME)
+        ),
+    };
+    format!("{}\n{verdict}", markdown(&rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{aoc_9999_04, aoc_9999_05};
+    use rstest::rstest;
+
+    fn point(bytes: usize, millis: u64) -> Point {
+        Point {
+            scale: bytes,
+            bytes,
+            answer: Ok(Answer::Int(0)),
+            elapsed: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn curve_should_run_every_scale() {
+        let sut = curve(
+            &aoc_9999_04::Puzzle,
+            Part::B,
+            7,
+            &[1, 10, 100],
+            &Params::new().with("factor", 2),
+            BUDGET,
+        )
+        .unwrap();
+
+        let answers = sut.iter().map(|p| p.answer.clone()).collect::<Vec<_>>();
+        assert_eq!(answers, [2, 20, 200].map(|a| Ok(Answer::Int(a))).to_vec());
+        assert_eq!(sut[2].bytes, 100);
+    }
+
+    #[test]
+    fn curve_should_stop_after_budget() {
+        let sut = curve(
+            &aoc_9999_04::Puzzle,
+            Part::A,
+            7,
+            &[1, 10, 100],
+            &Params::new(),
+            Duration::ZERO,
+        );
+        assert_eq!(sut.map(|points| points.len()), Some(1));
+    }
+
+    #[test]
+    fn curve_should_need_generator() {
+        let sut = curve(
+            &aoc_9999_05::Puzzle,
+            Part::A,
+            0,
+            &SCALES,
+            &Params::new(),
+            BUDGET,
+        );
+        assert_eq!(sut, None);
+    }
+
+    #[rstest]
+    #[case(point(100, 2), point(200, 4), Some(1.0))]
+    #[case(point(100, 2), point(200, 8), Some(2.0))]
+    #[case(point(100, 0), point(200, 8), None)]
+    fn exponent_should_compare_growth(
+        #[case] a: Point,
+        #[case] b: Point,
+        #[case] expected: Option<f64>,
+    ) {
+        let sut = exponent(&a, &b);
+        assert_eq!(sut.map(|k| (k * 100.0).round() / 100.0), expected);
+    }
+
+    #[test]
+    fn report_should_warn_of_quadratic() {
+        let points = [point(10, 0), point(100, 1), point(200, 4), point(400, 16)];
+
+        let sut = report(&points);
+
+        assert!(sut.contains("| 2.00"), "{sut}");
+        assert!(sut.ends_with("time ~ size^2.00, looks quadratic or worse"));
+        assert!(report(&points[..2]).ends_with("too fast to fit, the runs need at least 1.00ms"));
+    }
+}
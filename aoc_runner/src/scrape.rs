@@ -0,0 +1,298 @@
+/*! aoc scrape <year> <day>, examples and their answers from the puzzle page
+ *
+ * The page is an article per part:
+ *
+ * <article class="day-desc"><h2>--- Day 7: Bridge Repair ---</h2>
+ *   ... <pre><code>190: 10 19
+ * 3267: 81 40 27
+ * </code></pre> ... total calibration result is <code><em>3749</em></code>.</article>
+ *
+ * The first <pre><code> of a part is taken as its example and the last emphasised code as
+ * its answer, part two mostly reuses the example of part one.
+ * Only the placeholders of the template in lib.rs are filled, written tests stay as they are.
+*/
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::run::Part;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PagePart {
+    pub examples: Vec<String>,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Page {
+    pub title: Option<String>,
+    pub parts: Vec<PagePart>,
+}
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap());
+static TITLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>").unwrap());
+static EXAMPLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+static ANSWER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap()
+});
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// text of html without tags and entities
+fn text(html: &str) -> String {
+    TAG.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn parse(html: &str) -> Page {
+    let title = TITLE.captures(html).map(|c| text(&c[1]));
+    let parts = ARTICLE
+        .captures_iter(html)
+        .map(|article| {
+            let article = &article[1];
+            PagePart {
+                examples: EXAMPLE
+                    .captures_iter(article)
+                    .map(|c| text(&c[1]))
+                    .collect(),
+                answer: ANSWER
+                    .captures_iter(article)
+                    .last()
+                    .and_then(|c| c.get(1).or(c.get(2)))
+                    .map(|m| text(m.as_str())),
+            }
+        })
+        .collect();
+    Page { title, parts }
+}
+
+impl Page {
+    fn part(&self, part: Part) -> Option<&PagePart> {
+        self.parts.get(if part == Part::A { 0 } else { 1 })
+    }
+
+    pub fn example(&self, part: Part) -> Option<&str> {
+        self.part(part)
+            .and_then(|p| p.examples.first())
+            .or_else(|| self.part(Part::A).and_then(|p| p.examples.first()))
+            .map(String::as_str)
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.part(part).and_then(|p| p.answer.as_deref())
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// "\n" first like the handwritten examples, the solutions trim their input
+fn example_literal(example: &str) -> String {
+    format!("\"\n{}\"", escape(example.trim_end_matches('\n')))
+}
+
+fn answer_literal(answer: &str) -> String {
+    match answer.parse::<i128>() {
+        Ok(_) => answer.to_string(),
+        Err(_) => format!("\"{}\"", escape(answer)),
+    }
+}
+
+/// Fill the placeholders of the template in lib.rs, returns the new lib.rs and what was filled
+pub fn fill_tests(lib: &str, crate_name: &str, page: &Page) -> (String, Vec<String>) {
+    let mut lines = lib.lines().map(String::from).collect::<Vec<_>>();
+    let mut filled = Vec::new();
+    let replace = |lines: &mut Vec<String>, placeholder: &str, with: String| {
+        let line = lines.iter_mut().find(|l| l.trim() == placeholder)?;
+        let indent = line.len() - line.trim_start().len();
+        *line = format!("{}{with}", &line[..indent]);
+        Some(())
+    };
+
+    if let Some(title) = &page.title
+        && replace(
+            &mut lines,
+            "title: \"\",",
+            format!("title: \"{}\",", escape(title)),
+        )
+        .is_some()
+    {
+        filled.push(format!("title {title}"));
+    }
+
+    let example_a = page.example(Part::A);
+    if let Some(example) = example_a
+        && replace(
+            &mut lines,
+            "const TEST_INPUT: &str = \"\";",
+            format!("const TEST_INPUT: &str = {};", example_literal(example)),
+        )
+        .is_some()
+    {
+        filled.push("example of part a".to_string());
+    }
+    // an own example of part two goes to TEST_INPUT_2
+    let mut input_b = "TEST_INPUT";
+    if let Some(example) = page.example(Part::B).filter(|&e| Some(e) != example_a)
+        && replace(
+            &mut lines,
+            "const TEST_INPUT_2: &str = \"\";",
+            format!("const TEST_INPUT_2: &str = {};", example_literal(example)),
+        )
+        .is_some()
+    {
+        input_b = "TEST_INPUT_2";
+        filled.push("example of part b".to_string());
+    }
+
+    for (part, input) in [(Part::A, "TEST_INPUT"), (Part::B, input_b)] {
+        let Some(answer) = page.answer(part) else {
+            continue;
+        };
+        let signature = format!("fn {crate_name}_{part}_example(");
+        let Some(at) = lines.iter().position(|l| l.contains(&signature)) else {
+            continue;
+        };
+        // the attributes right above the example test
+        let first = lines[..at]
+            .iter()
+            .rposition(|l| !l.trim_start().starts_with("#["))
+            .map_or(0, |i| i + 1);
+        let Some(case) = (first..at).find(|&i| lines[i].trim() == "#[case(TEST_INPUT, 0)]") else {
+            continue;
+        };
+        let indent = lines[case].len() - lines[case].trim_start().len();
+        lines[case] = format!(
+            "{}#[case({input}, {})]",
+            &lines[case][..indent],
+            answer_literal(answer)
+        );
+        if answer.parse::<i128>().is_err() {
+            lines[at] = lines[at].replace("expected: usize", "expected: &str");
+        }
+        filled.push(format!("answer {answer} of part {part}"));
+    }
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    (lib, filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 99: Counting &amp; Sorting ---</h2><p>Count the lines:</p>
+<pre><code>a
+&lt;b&gt;
+<em>c</em>
+</code></pre>
+<p>There are <code><em>3</em></code> lines, not <code>4</code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Sort them:</p>
+<p>Sorted they read <code><em>a,&lt;b&gt;,c</em></code>.</p>
+</article>
+</main>"#;
+
+    const LIB: &str = r#"aoc_utils::solution! {
+    year: 9999,
+    day: 99,
+    title: "",
+    a: aoc_9999_99_a,
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TEST_INPUT, 0)]
+    fn aoc_9999_99_a_example(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(super::aoc_9999_99_a(input), expected);
+    }
+
+    #[rstest]
+    #[case(TEST_INPUT, 0)]
+    fn aoc_9999_99_b_example(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(super::aoc_9999_99_b(input), expected);
+    }
+
+    const TEST_INPUT: &str = "";
+
+    #[allow(dead_code)]
+    const TEST_INPUT_2: &str = "";
+}
+"#;
+
+    #[test]
+    fn parse_should_find_title_examples_and_answers() {
+        let sut = parse(PAGE);
+
+        assert_eq!(sut.title.as_deref(), Some("Counting & Sorting"));
+        assert_eq!(sut.parts.len(), 2);
+        assert_eq!(sut.example(Part::A), Some("a\n<b>\nc\n"));
+        assert_eq!(sut.example(Part::B), Some("a\n<b>\nc\n"));
+        assert_eq!(sut.answer(Part::A), Some("3"));
+        assert_eq!(sut.answer(Part::B), Some("a,<b>,c"));
+    }
+
+    #[test]
+    fn parse_should_survive_other_pages() {
+        assert_eq!(parse("<html>Not Found</html>"), Page::default());
+    }
+
+    #[test]
+    fn fill_tests_should_fill_placeholders() {
+        let (sut, filled) = fill_tests(LIB, "aoc_9999_99", &parse(PAGE));
+
+        assert_eq!(filled.len(), 4);
+        assert!(sut.contains("    title: \"Counting & Sorting\",\n"));
+        assert!(sut.contains("    const TEST_INPUT: &str = \"\na\n<b>\nc\";\n"));
+        assert!(sut.contains("    #[case(TEST_INPUT, 3)]\n"));
+        assert!(sut.contains("    #[case(TEST_INPUT, \"a,<b>,c\")]\n"));
+        assert!(
+            sut.contains("fn aoc_9999_99_b_example(#[case] input: &str, #[case] expected: &str)")
+        );
+        assert!(sut.contains("    const TEST_INPUT_2: &str = \"\";\n"));
+    }
+
+    #[test]
+    fn fill_tests_should_keep_written_tests() {
+        let (once, _) = fill_tests(LIB, "aoc_9999_99", &parse(PAGE));
+        let (sut, filled) = fill_tests(&once, "aoc_9999_99", &parse(PAGE));
+
+        assert_eq!(sut, once);
+        assert!(filled.is_empty());
+    }
+
+    #[test]
+    fn fill_tests_should_use_own_example_of_part_two() {
+        let page = Page {
+            title: None,
+            parts: vec![
+                PagePart {
+                    examples: vec!["1\n".into()],
+                    answer: Some("1".into()),
+                },
+                PagePart {
+                    examples: vec!["2\n".into()],
+                    answer: Some("2".into()),
+                },
+            ],
+        };
+        let (sut, _) = fill_tests(LIB, "aoc_9999_99", &page);
+
+        assert!(sut.contains("    const TEST_INPUT_2: &str = \"\n2\";\n"));
+        assert!(sut.contains("    #[case(TEST_INPUT_2, 2)]\n"));
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use aoc_utils::{Answer, solution::Error};
+
+use crate::run::{DayResult, Part, PartResult};
+
+/// longer answers are cut in the table, the full answer is printed when the part is done
+const MAX_ANSWER: usize = 20;
+
+pub fn format_duration(d: Duration) -> String {
+    format!("{d:.2?}")
+}
+
+/// short form of an answer for a table cell
+pub fn cell(answer: &Result<Answer, Error>) -> String {
+    match answer {
+        Ok(Answer::Ocr(_)) => "(screen)".to_string(),
+        Ok(answer) => {
+            let answer = answer.to_string();
+            if answer.chars().count() > MAX_ANSWER {
+                format!(
+                    "{}…",
+                    answer.chars().take(MAX_ANSWER - 1).collect::<String>()
+                )
+            } else {
+                answer
+            }
+        }
+        Err(Error::NotSolved) => "-".to_string(),
+        Err(_) => "error".to_string(),
+    }
+}
+
+fn part_cells(part: Option<&PartResult>) -> [String; 2] {
+    part.map_or([String::new(), String::new()], |p| {
+        [cell(&p.answer), format_duration(p.elapsed)]
+    })
+}
+
+/// Markdown table of all results with the total time in the last row
+pub fn summary(results: &[DayResult]) -> String {
+    let header = ["Year", "Day", "Title", "A", "Time A", "B", "Time B"].map(String::from);
+    let mut rows = vec![header];
+    for r in results {
+        let [a, time_a] = part_cells(r.part(Part::A));
+        let [b, time_b] = part_cells(r.part(Part::B));
+        rows.push([
+            r.year.to_string(),
+            format!("{:02}", r.day),
+            r.title.to_string(),
+            a,
+            time_a,
+            b,
+            time_b,
+        ]);
+    }
+    let total = results.iter().map(DayResult::elapsed).sum();
+    rows.push([
+        String::new(),
+        String::new(),
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total),
+    ]);
+
+    markdown(&rows)
+}
+
+/// Markdown table with the first row as header, columns are padded to the same width
+pub fn markdown<const N: usize>(rows: &[[String; N]]) -> String {
+    let widths = (0..N)
+        .map(|c| rows.iter().map(|r| r[c].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    let line = |row: &[String; N]| {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &w)| format!(" {cell:<w$} "))
+            .collect::<Vec<_>>();
+        format!("|{}|", cells.join("|"))
+    };
+    let separator = format!(
+        "|{}|",
+        widths
+            .iter()
+            .map(|&w| format!(" {} ", "-".repeat(w)))
+            .collect::<Vec<_>>()
+            .join("|")
+    );
+
+    let mut table = vec![line(&rows[0]), separator];
+    table.extend(rows[1..].iter().map(line));
+    table.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Ok(Answer::Int(42)), "42")]
+    #[case(Ok(Answer::Text("a".repeat(30))), "aaaaaaaaaaaaaaaaaaa…")]
+    #[case(Ok(Answer::Ocr("#..#\n####".into())), "(screen)")]
+    #[case(Err(Error::NotSolved), "-")]
+    #[case(Err(Error::Failed("no input".into())), "error")]
+    fn cell_should_shorten(#[case] answer: Result<Answer, Error>, #[case] expected: &str) {
+        assert_eq!(cell(&answer), expected);
+    }
+
+    #[test]
+    fn summary_should_align_columns() {
+        let results = vec![DayResult {
+            year: 2024,
+            day: 7,
+            title: "Bridge Repair",
+            parts: vec![PartResult {
+                part: Part::A,
+                answer: Ok(Answer::Int(3749)),
+                elapsed: Duration::from_millis(2),
+            }],
+        }];
+
+        let sut = summary(&results);
+        let lines = sut.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(
+            lines
+                .iter()
+                .all(|l| l.chars().count() == lines[0].chars().count())
+        );
+        assert!(lines[2].contains("| 2024 | 07  | Bridge Repair | 3749 | 2.00ms |"));
+        assert!(lines[3].contains("Total"));
+    }
+}
@@ -0,0 +1,240 @@
+/*! Compare results with the answer ledger (aoc_data.json)
+ *
+ * aoc run --record    adds answers the ledger does not know yet, with their time as baseline
+ * aoc verify          runs everything again and reports regressions, missing answers and
+ *                     parts which got slower than tolerance * baseline
+ * aoc verify --save-baseline   takes the times of correct answers as new baseline
+*/
+use std::{fmt::Display, time::Duration};
+
+use aoc_utils::{
+    Answer,
+    ledger::{Entry, Ledger},
+    solution::Error,
+};
+
+use crate::{
+    run::{DayResult, PartResult},
+    table::format_duration,
+};
+
+/// default for --tolerance, timings of a single run are noisy
+pub const TOLERANCE: f64 = 1.5;
+/// faster parts are never reported as slower, the noise is bigger than the part
+const MIN_SLOWDOWN: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// the ledger has no answer yet
+    Missing(String),
+    Regression {
+        actual: String,
+        expected: String,
+    },
+    Slower {
+        elapsed: Duration,
+        baseline: Duration,
+    },
+    Failed(String),
+    NotSolved,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Missing(answer) => write!(f, "missing answer {answer}"),
+            Status::Regression { actual, expected } => {
+                write!(f, "regression {actual}, expected {expected}")
+            }
+            Status::Slower { elapsed, baseline } => write!(
+                f,
+                "slower {}, baseline {}",
+                format_duration(*elapsed),
+                format_duration(*baseline)
+            ),
+            Status::Failed(reason) => write!(f, "{reason}"),
+            Status::NotSolved => write!(f, "not solved"),
+        }
+    }
+}
+
+fn ledger_form(answer: &Answer) -> String {
+    answer.to_string()
+}
+
+pub fn check(result: &PartResult, entry: Option<&Entry>, tolerance: f64) -> Status {
+    let part = result.part.into();
+    let answer = match &result.answer {
+        Ok(answer) => ledger_form(answer),
+        Err(Error::NotSolved) => return Status::NotSolved,
+        Err(e) => return Status::Failed(e.to_string()),
+    };
+
+    let Some(expected) = entry.and_then(|e| e.answer(part)) else {
+        return Status::Missing(answer);
+    };
+    if expected != answer {
+        return Status::Regression {
+            actual: answer,
+            expected: expected.to_string(),
+        };
+    }
+
+    match entry.and_then(|e| e.baseline(part)) {
+        Some(baseline)
+            if result.elapsed > baseline.mul_f64(tolerance)
+                && result.elapsed - baseline > MIN_SLOWDOWN =>
+        {
+            Status::Slower {
+                elapsed: result.elapsed,
+                baseline,
+            }
+        }
+        _ => Status::Ok,
+    }
+}
+
+/// Status of every part of the results
+pub fn verify<'r>(
+    results: &'r [DayResult],
+    ledger: &Ledger,
+    tolerance: f64,
+) -> Vec<(&'r DayResult, &'r PartResult, Status)> {
+    results
+        .iter()
+        .flat_map(|day| {
+            let entry = ledger.get(day.year, day.day);
+            day.parts
+                .iter()
+                .map(move |p| (day, p, check(p, entry, tolerance)))
+        })
+        .collect()
+}
+
+/// Add answers the ledger does not know, returns the parts which disagree with the ledger.
+/// Known answers are never overwritten, a wrong answer in the ledger has to be fixed by hand.
+pub fn record(ledger: &mut Ledger, results: &[DayResult]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for day in results {
+        for p in &day.parts {
+            let Ok(answer) = &p.answer else {
+                continue;
+            };
+            let answer = ledger_form(answer);
+            let part = p.part.into();
+            let entry = ledger.entry(day.year, day.day, day.title);
+            match entry.answer(part) {
+                None => {
+                    entry.set_answer(part, &answer);
+                    entry.set_baseline(part, p.elapsed);
+                }
+                Some(known) if known != answer => conflicts.push(format!(
+                    "{} {:02} {}: {answer} differs from ledger {known}",
+                    day.year, day.day, p.part
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+    conflicts
+}
+
+/// New baseline for every part with the expected answer
+pub fn save_baseline(ledger: &mut Ledger, results: &[DayResult], tolerance: f64) -> usize {
+    let mut saved = 0;
+    for (day, p, status) in verify(results, ledger, tolerance) {
+        if matches!(status, Status::Ok | Status::Slower { .. }) {
+            ledger
+                .entry(day.year, day.day, day.title)
+                .set_baseline(p.part.into(), p.elapsed);
+            saved += 1;
+        }
+    }
+    saved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run::Part;
+    use rstest::rstest;
+
+    fn result(answer: Result<Answer, Error>, millis: u64) -> PartResult {
+        PartResult {
+            part: Part::A,
+            answer,
+            elapsed: Duration::from_millis(millis),
+        }
+    }
+
+    fn entry() -> Entry {
+        let mut entry = Entry::new(2024, 7, "Bridge Repair");
+        entry.set_answer('a', "3749");
+        entry.set_baseline('a', Duration::from_millis(10));
+        entry
+    }
+
+    #[rstest]
+    #[case(result(Ok(Answer::Int(3749)), 12), Status::Ok)]
+    #[case(result(Ok(Answer::Int(3749)), 20), Status::Slower {
+        elapsed: Duration::from_millis(20),
+        baseline: Duration::from_millis(10)
+    })]
+    #[case(result(Ok(Answer::Int(42)), 1), Status::Regression {
+        actual: "42".into(),
+        expected: "3749".into()
+    })]
+    #[case(result(Err(Error::NotSolved), 0), Status::NotSolved)]
+    #[case(result(Err(Error::Failed("no input".into())), 0), Status::Failed("failed: no input".into()))]
+    fn check_should_compare_with_ledger(#[case] sut: PartResult, #[case] expected: Status) {
+        assert_eq!(check(&sut, Some(&entry()), TOLERANCE), expected);
+    }
+
+    #[test]
+    fn check_should_report_missing() {
+        let sut = result(Ok(Answer::Text("abc".into())), 1);
+        assert_eq!(check(&sut, None, TOLERANCE), Status::Missing("abc".into()));
+        assert_eq!(
+            check(&sut, Some(&Entry::new(2024, 7, "")), TOLERANCE),
+            Status::Missing("abc".into())
+        );
+    }
+
+    #[test]
+    fn check_should_ignore_noise_of_fast_parts() {
+        let mut entry = entry();
+        entry.set_baseline('a', Duration::from_micros(10));
+        let sut = result(Ok(Answer::Int(3749)), 0);
+        assert_eq!(check(&sut, Some(&entry), TOLERANCE), Status::Ok);
+    }
+
+    #[test]
+    fn record_should_keep_known_answers() {
+        let mut ledger = Ledger::default();
+        *ledger.entry(2024, 7, "Bridge Repair") = entry();
+        let results = vec![DayResult {
+            year: 2024,
+            day: 7,
+            title: "Bridge Repair",
+            parts: vec![
+                result(Ok(Answer::Int(1)), 5),
+                PartResult {
+                    part: Part::B,
+                    ..result(Ok(Answer::Int(11387)), 5)
+                },
+            ],
+        }];
+
+        let conflicts = record(&mut ledger, &results);
+
+        assert_eq!(conflicts, vec!["2024 07 a: 1 differs from ledger 3749"]);
+        assert_eq!(ledger.answer(2024, 7, 'a'), Some("3749"));
+        assert_eq!(ledger.answer(2024, 7, 'b'), Some("11387"));
+        assert_eq!(
+            ledger.get(2024, 7).unwrap().baseline('b'),
+            Some(Duration::from_millis(5))
+        );
+    }
+}
@@ -0,0 +1,533 @@
+/*! Property based and differential tests of solutions
+ *
+ * Some days have more than one implementation (brute force and the clever one, plain and
+ * petgraph). The examples only show that they agree on a handful of inputs. Check generates
+ * random inputs of growing size, runs the implementations against each other and shrinks
+ * a disagreement to a small counterexample.
+ *
+ * A Generator makes a value from an Rng and a size, and proposes smaller variants of a
+ * value for shrinking. from_fn turns a closure into one, shrink_lines removes lines of
+ * puzzle inputs. A panic counts as outcome, implementations which both panic agree.
+ *
+ * The seed is DEFAULT_SEED unless AOC_SEED is set, so a test sees the same values on every
+ * run. A failure reports the seed to replay it, AOC_SEED explores other values.
+ *
+ * ``` rust
+ * use aoc_utils::check::{from_fn, shrink_vec, Check};
+ *
+ * let numbers = || {
+ *     from_fn(|rng, size| (0..size).map(|_| rng.below(100)).collect::<Vec<_>>())
+ *         .shrink_with(shrink_vec)
+ * };
+ * let sum_loop = |v: &Vec<usize>| {
+ *     let mut sum = 0;
+ *     for x in v {
+ *         sum += x;
+ *     }
+ *     sum
+ * };
+ * let sum_iter = |v: &Vec<usize>| v.iter().sum::<usize>();
+ * Check::new(numbers()).assert_agree(&[("loop", &sum_loop), ("iter", &sum_iter)]);
+ *
+ * // shrinks to the smallest vector with a 99 in it
+ * let failure = Check::new(numbers()).seed(1).property(|v| !v.contains(&99)).unwrap_err();
+ * assert_eq!(failure.input, vec![99]);
+ * ```
+*/
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::Range,
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+/// environment variable with the seed to replay a failure
+pub const SEED_VAR: &str = "AOC_SEED";
+
+/// seed of Check::new without SEED_VAR
+pub const DEFAULT_SEED: u64 = 0x2015_1201;
+
+/// SplitMix64, small, fast and good enough for test inputs. Same seed, same values on
+/// every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// uniform in 0..n, 0 for n == 0
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+        // multiply shift instead of modulo, no bias worth mentioning for test inputs
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// uniform in range, range.start for an empty range
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        let len = range.end.saturating_sub(range.start).max(0) as usize;
+        range.start + self.below(len) as i64
+    }
+
+    /// true with probability p
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// random element, panics on an empty slice
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Random values for a Check
+pub trait Generator {
+    type Value: Clone + Debug;
+
+    /// a value, size grows from 1 to Check::max_size over the cases
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value;
+
+    /// smaller variants of value, the first one still failing wins
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        Vec::new()
+    }
+}
+
+/// Generator from closures, see from_fn
+pub struct FromFn<T, F, S> {
+    generate: F,
+    shrink: S,
+    value: PhantomData<fn() -> T>,
+}
+
+/// shrink of from_fn, no smaller values
+pub type NoShrink<T> = fn(&T) -> Vec<T>;
+
+/// Generator calling generate, without shrinking
+pub fn from_fn<T, F>(generate: F) -> FromFn<T, F, NoShrink<T>>
+where
+    F: Fn(&mut Rng, usize) -> T,
+{
+    FromFn {
+        generate,
+        shrink: |_| Vec::new(),
+        value: PhantomData,
+    }
+}
+
+impl<T, F, S> FromFn<T, F, S> {
+    /// shrink the values with shrink, e.g. shrink_lines or shrink_vec
+    pub fn shrink_with<S2>(self, shrink: S2) -> FromFn<T, F, S2>
+    where
+        S2: Fn(&T) -> Vec<T>,
+    {
+        FromFn {
+            generate: self.generate,
+            shrink,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T, F, S> Generator for FromFn<T, F, S>
+where
+    T: Clone + Debug,
+    F: Fn(&mut Rng, usize) -> T,
+    S: Fn(&T) -> Vec<T>,
+{
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> T {
+        (self.generate)(rng, size)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        (self.shrink)(value)
+    }
+}
+
+// &Vec and &String to fit Generator::shrink of Vec and String values
+/// vec without chunks of halving length: first half, second half, ... down to single elements
+#[allow(clippy::ptr_arg)]
+pub fn shrink_vec<T: Clone>(items: &Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    let mut chunk = items.len().div_ceil(2);
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let mut candidate = items[..start].to_vec();
+            candidate.extend_from_slice(&items[(start + chunk).min(items.len())..]);
+            candidates.push(candidate);
+        }
+        if chunk == 1 {
+            break;
+        }
+        chunk = chunk.div_ceil(2);
+    }
+    candidates
+}
+
+/// puzzle input without chunks of lines, like shrink_vec. Keeps at least one line,
+/// solutions may assume a puzzle input is not empty.
+#[allow(clippy::ptr_arg)]
+pub fn shrink_lines(input: &String) -> Vec<String> {
+    shrink_vec(&input.lines().collect())
+        .into_iter()
+        .filter(|lines| !lines.is_empty())
+        .map(|lines| lines.join("\n"))
+        .collect()
+}
+
+/// A value for which the implementations disagree or the property does not hold
+#[derive(Debug, Clone)]
+pub struct Failure<T> {
+    pub seed: u64,
+    /// 1-based case which failed first
+    pub case: usize,
+    pub shrinks: usize,
+    /// the shrunk value
+    pub input: T,
+    /// name and outcome of every implementation (or the property) for input
+    pub outcomes: Vec<(String, String)>,
+}
+
+impl<T: Debug> Display for Failure<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "failed at case {} (shrunk {} times), replay with {SEED_VAR}={}",
+            self.case, self.shrinks, self.seed
+        )?;
+        writeln!(f, "input: {:?}", self.input)?;
+        for (name, outcome) in &self.outcomes {
+            writeln!(f, "  {name}: {outcome}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> std::error::Error for Failure<T> {}
+
+/// Runs of a Generator against a property or several implementations
+#[derive(Debug, Clone)]
+pub struct Check<G> {
+    generator: G,
+    cases: usize,
+    max_size: usize,
+    max_shrinks: usize,
+    seed: u64,
+}
+
+/// Implementation under test with its name
+pub type Implementation<'a, T, R> = (&'a str, &'a dyn Fn(&T) -> R);
+
+impl<G: Generator> Check<G> {
+    /// 100 cases up to size 20, seed from AOC_SEED or DEFAULT_SEED
+    pub fn new(generator: G) -> Self {
+        let seed = seed_from(std::env::var(SEED_VAR).ok().as_deref());
+        Self {
+            generator,
+            cases: 100,
+            max_size: 20,
+            max_shrinks: 1000,
+            seed,
+        }
+    }
+
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn max_shrinks(mut self, max_shrinks: usize) -> Self {
+        self.max_shrinks = max_shrinks;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// property holds (and does not panic) for every generated value
+    pub fn property(&self, property: impl Fn(&G::Value) -> bool) -> Result<(), Failure<G::Value>> {
+        self.run(
+            |value| !matches!(outcome(|| property(value)), Ok(true)),
+            |value| {
+                let outcome = match outcome(|| property(value)) {
+                    Ok(holds) => holds.to_string(),
+                    Err(panic) => panic,
+                };
+                vec![("property".to_string(), outcome)]
+            },
+        )
+    }
+
+    /// all implementations return the same for every generated value
+    pub fn agree<R: PartialEq + Debug>(
+        &self,
+        implementations: &[Implementation<G::Value, R>],
+    ) -> Result<(), Failure<G::Value>> {
+        let outcomes = |value: &G::Value| {
+            implementations
+                .iter()
+                .map(|(_, f)| outcome(|| f(value)))
+                .collect::<Vec<_>>()
+        };
+        self.run(
+            |value| {
+                let outcomes = outcomes(value);
+                outcomes.windows(2).any(|pair| match pair {
+                    [Ok(a), Ok(b)] => a != b,
+                    [Err(_), Err(_)] => false,
+                    _ => true,
+                })
+            },
+            |value| {
+                implementations
+                    .iter()
+                    .zip(outcomes(value))
+                    .map(|((name, _), outcome)| {
+                        let outcome = match outcome {
+                            Ok(result) => format!("{result:?}"),
+                            Err(panic) => panic,
+                        };
+                        (name.to_string(), outcome)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    /// property, panics with the failure
+    pub fn assert_property(&self, property: impl Fn(&G::Value) -> bool) {
+        if let Err(failure) = self.property(property) {
+            panic!("{failure}");
+        }
+    }
+
+    /// agree, panics with the failure
+    pub fn assert_agree<R: PartialEq + Debug>(
+        &self,
+        implementations: &[Implementation<G::Value, R>],
+    ) {
+        if let Err(failure) = self.agree(implementations) {
+            panic!("{failure}");
+        }
+    }
+
+    fn run(
+        &self,
+        fails: impl Fn(&G::Value) -> bool,
+        report: impl Fn(&G::Value) -> Vec<(String, String)>,
+    ) -> Result<(), Failure<G::Value>> {
+        let mut rng = Rng::new(self.seed);
+        for case in 0..self.cases {
+            let size = 1 + case * self.max_size.saturating_sub(1) / self.cases.max(1);
+            let value = self.generator.generate(&mut rng, size);
+            if !fails(&value) {
+                continue;
+            }
+
+            let (input, shrinks) = self.shrink(value, &fails);
+            return Err(Failure {
+                seed: self.seed,
+                case: case + 1,
+                shrinks,
+                outcomes: report(&input),
+                input,
+            });
+        }
+        Ok(())
+    }
+
+    /// greedy, take the first smaller value which still fails until none does
+    fn shrink(&self, mut value: G::Value, fails: impl Fn(&G::Value) -> bool) -> (G::Value, usize) {
+        let mut shrinks = 0;
+        'shrink: while shrinks < self.max_shrinks {
+            for candidate in self.generator.shrink(&value) {
+                if fails(&candidate) {
+                    value = candidate;
+                    shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        (value, shrinks)
+    }
+}
+
+/// result of f or the panic message
+fn outcome<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        format!("panicked: {message}")
+    })
+}
+
+/// value of SEED_VAR or DEFAULT_SEED, a value which is no u64 is a mistake and panics
+fn seed_from(value: Option<&str>) -> u64 {
+    match value.map(str::trim) {
+        None | Some("") => DEFAULT_SEED,
+        Some(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{SEED_VAR}={value} is no u64 seed")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn numbers() -> impl Generator<Value = Vec<usize>> {
+        from_fn(|rng, size| (0..size).map(|_| rng.below(100)).collect::<Vec<_>>())
+            .shrink_with(shrink_vec)
+    }
+
+    #[test]
+    fn rng_should_repeat_for_same_seed() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let sut = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(sut, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(sut[0], sut[1]);
+    }
+
+    #[rstest]
+    #[case(0..10)]
+    #[case(-5..5)]
+    #[case(7..8)]
+    fn rng_range_should_stay_in_range(#[case] range: Range<i64>) {
+        let mut sut = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(range.contains(&sut.range(range.clone())));
+        }
+    }
+
+    #[rstest]
+    #[case(vec![1, 2, 3, 4], vec![vec![3, 4], vec![1, 2], vec![2, 3, 4], vec![1, 3, 4], vec![1, 2, 4], vec![1, 2, 3]])]
+    #[case(vec![1], vec![vec![]])]
+    #[case(vec![], vec![])]
+    fn shrink_vec_should_remove_chunks(#[case] items: Vec<u8>, #[case] expected: Vec<Vec<u8>>) {
+        assert_eq!(shrink_vec(&items), expected);
+    }
+
+    #[test]
+    fn agree_should_pass_for_same_results() {
+        let sum_iter = |v: &Vec<usize>| v.iter().sum::<usize>();
+        let sum_reduce = |v: &Vec<usize>| v.iter().copied().reduce(|a, b| a + b).unwrap_or(0);
+        let sut = Check::new(numbers()).seed(3);
+        assert!(
+            sut.agree(&[("iter", &sum_iter), ("reduce", &sum_reduce)])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn agree_should_shrink_to_minimal_counterexample() {
+        let sum = |v: &Vec<usize>| v.iter().sum::<usize>();
+        // wrong as soon as a number is at least 50
+        let capped = |v: &Vec<usize>| v.iter().map(|x| x.min(&49)).sum::<usize>();
+        let sut = Check::new(numbers()).seed(3);
+
+        let failure = sut
+            .agree(&[("sum", &sum), ("capped", &capped)])
+            .unwrap_err();
+
+        assert_eq!(failure.input.len(), 1);
+        assert!(failure.input[0] >= 50);
+        assert_eq!(failure.seed, 3);
+        assert_eq!(failure.outcomes[0].0, "sum");
+    }
+
+    #[test]
+    fn agree_should_count_panics_as_outcome() {
+        let sum = |v: &Vec<usize>| v.iter().sum::<usize>();
+        let panics = |_: &Vec<usize>| -> usize { panic!("always") };
+        let sut = Check::new(numbers()).seed(5);
+
+        let failure = sut
+            .agree(&[("sum", &sum), ("panics", &panics)])
+            .unwrap_err();
+        assert!(failure.input.is_empty());
+        assert_eq!(failure.outcomes[1].1, "panicked: always");
+        assert!(sut.agree(&[("panics", &panics), ("also", &panics)]).is_ok());
+    }
+
+    #[test]
+    fn property_should_report_seed_and_input() {
+        let sut = Check::new(numbers()).seed(11);
+
+        let failure = sut.property(|v| v.len() < 5).unwrap_err();
+
+        assert_eq!(failure.input.len(), 5);
+        assert!(failure.to_string().contains("AOC_SEED=11"));
+        assert_eq!(
+            failure.outcomes,
+            vec![("property".to_string(), "false".to_string())]
+        );
+    }
+
+    #[rstest]
+    #[case(None, DEFAULT_SEED)]
+    #[case(Some(""), DEFAULT_SEED)]
+    #[case(Some("42"), 42)]
+    #[case(Some(" 7\n"), 7)]
+    fn seed_from_should_default_without_value(#[case] value: Option<&str>, #[case] expected: u64) {
+        assert_eq!(seed_from(value), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "AOC_SEED=abc is no u64 seed")]
+    fn seed_from_should_panic_for_no_number() {
+        seed_from(Some("abc"));
+    }
+
+    #[test]
+    fn assert_property_should_panic_with_seed() {
+        let sut = Check::new(numbers()).seed(11);
+
+        let panic =
+            catch_unwind(AssertUnwindSafe(|| sut.assert_property(|v| v.len() < 5))).unwrap_err();
+
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.contains("replay with AOC_SEED=11"));
+    }
+
+    #[test]
+    fn shrink_lines_should_keep_line_structure() {
+        let sut = shrink_lines(&"a\nb\nc".to_string());
+        assert_eq!(sut, vec!["c", "a\nb", "b\nc", "a\nc", "a\nb"]);
+        assert!(shrink_lines(&"a".to_string()).is_empty());
+    }
+}
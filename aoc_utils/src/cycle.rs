@@ -0,0 +1,246 @@
+/*! Cycle detection for state sequences x0, f(x0), f(f(x0)), ...
+ *
+ * "Simulate a billion steps" usually means: the states repeat after some time,
+ * find the cycle and skip whole cycles.
+ *
+ * mu: index of the first state on the cycle, lambda: cycle length.
+ * So state(n) == state(n + lambda) for all n >= mu.
+ *
+ * brent: O(1) memory, needs a cheap pure f and comparable states.
+ * find_cycle / CycleDetector: HashMap of fingerprints. Use it if the state contains ever growing
+ * parts (tower height, step counter) which are not part of the repetition. The recorded history
+ * extrapolates such accumulated values with nth_value.
+ *
+ * https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::cycle::{brent, find_cycle};
+ * // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+ * let f = |x: &u32| if *x < 4 { x + 1 } else { 2 };
+ * assert_eq!(brent(0, f), (2, 3));
+ *
+ * let cycle = find_cycle(0, f, |x| *x);
+ * assert_eq!((cycle.mu, cycle.lambda), (2, 3));
+ * assert_eq!(*cycle.nth_state(1_000_000), 4);
+ * ```
+*/
+use std::{collections::HashMap, hash::Hash};
+
+/// Brent's algorithm. Returns (mu, lambda).
+/// Loops forever if the sequence never repeats.
+pub fn brent<S, F>(x0: S, f: F) -> (usize, usize)
+where
+    S: PartialEq + Clone,
+    F: Fn(&S) -> S,
+{
+    // search successive powers of two for the cycle length
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = f(&hare);
+        lambda += 1;
+    }
+
+    // hare lambda steps ahead of tortoise, move both until they meet at the start of the cycle
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..lambda {
+        hare = f(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        mu += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// A detected cycle together with every state up to and including its first repetition
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub mu: usize,
+    pub lambda: usize,
+    /// state after i steps for i in 0..=mu+lambda
+    pub history: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// index in history which is equivalent to step n
+    #[inline]
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.history.len() {
+            n
+        } else {
+            self.mu + (n - self.mu) % self.lambda
+        }
+    }
+
+    /// state after n steps, only meaningful for the repeating part of the state
+    pub fn nth_state(&self, n: usize) -> &S {
+        &self.history[self.equivalent_step(n)]
+    }
+
+    /// Extrapolate an accumulated value (height, score, ...) to step n.
+    /// Every full cycle adds value(mu + lambda) - value(mu).
+    pub fn nth_value<V>(&self, n: usize, value: V) -> i64
+    where
+        V: Fn(&S) -> i64,
+    {
+        if n < self.history.len() {
+            return value(&self.history[n]);
+        }
+
+        let cycles = ((n - self.mu) / self.lambda) as i64;
+        let remainder = (n - self.mu) % self.lambda;
+        let per_cycle = value(&self.history[self.mu + self.lambda]) - value(&self.history[self.mu]);
+
+        value(&self.history[self.mu + remainder]) + cycles * per_cycle
+    }
+}
+
+/// Incremental detector for simulations which are easier to step by hand than to write as f(&S) -> S.
+/// Push the fingerprint and state of step 0, 1, 2, ... until a cycle is returned.
+#[derive(Clone, Debug)]
+pub struct CycleDetector<K, S> {
+    seen: HashMap<K, usize>,
+    history: Vec<S>,
+}
+
+impl<K: Hash + Eq, S> Default for CycleDetector<K, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq, S> CycleDetector<K, S> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// number of steps pushed so far
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Record the next step. Returns the cycle as soon as the fingerprint was seen before.
+    pub fn push(&mut self, fingerprint: K, state: S) -> Option<Cycle<S>> {
+        let step = self.history.len();
+        self.history.push(state);
+
+        match self.seen.insert(fingerprint, step) {
+            Some(mu) => Some(Cycle {
+                mu,
+                lambda: step - mu,
+                history: std::mem::take(&mut self.history),
+            }),
+            None => None,
+        }
+    }
+}
+
+/// Iterate f from x0 until a fingerprint repeats. Loops forever if it never does.
+pub fn find_cycle<S, K, F, FP>(x0: S, f: F, fingerprint: FP) -> Cycle<S>
+where
+    K: Hash + Eq,
+    F: Fn(&S) -> S,
+    FP: Fn(&S) -> K,
+{
+    let mut detector = CycleDetector::new();
+    let mut state = x0;
+    loop {
+        let next = f(&state);
+        if let Some(cycle) = detector.push(fingerprint(&state), state) {
+            return cycle;
+        }
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // x -> x^2 + 1 mod m, the classic pollard rho sequence
+    #[rstest]
+    #[case(2, 255, (0, 6))]
+    #[case(3, 7, (0, 1))]
+    #[case(1, 1000, (3, 6))]
+    #[case(7, 1019, (7, 8))]
+    fn brent_should_find_cycle(#[case] x0: u64, #[case] m: u64, #[case] expected: (usize, usize)) {
+        let f = |x: &u64| (x * x + 1) % m;
+        assert_eq!(brent(x0, f), expected);
+
+        let cycle = find_cycle(x0, f, |x| *x);
+        assert_eq!((cycle.mu, cycle.lambda), expected);
+    }
+
+    #[test]
+    fn brent_should_handle_fixpoint() {
+        assert_eq!(brent(5, |x: &i32| *x), (0, 1));
+    }
+
+    #[test]
+    fn nth_state_should_skip_cycles() {
+        let f = |x: &u32| if *x < 4 { x + 1 } else { 2 };
+        let cycle = find_cycle(0, f, |x| *x);
+
+        // compare with plain simulation
+        let mut x = 0;
+        for n in 0..50 {
+            assert_eq!(*cycle.nth_state(n), x, "step {n}");
+            x = f(&x);
+        }
+    }
+
+    #[test]
+    fn nth_value_should_extrapolate_accumulated_value() {
+        // (phase, height): phase repeats 0, 1, 2 after a warm up, height grows by phase + 1
+        let f = |&(phase, height): &(u32, i64)| {
+            let next = if phase < 2 { phase + 1 } else { 0 };
+            (next, height + phase as i64 + 1)
+        };
+        let cycle = find_cycle((5, 0), f, |&(phase, _)| phase);
+        assert_eq!((cycle.mu, cycle.lambda), (1, 3));
+
+        let mut state = (5, 0);
+        for n in 0..100 {
+            assert_eq!(cycle.nth_value(n, |s| s.1), state.1, "step {n}");
+            state = f(&state);
+        }
+        assert_eq!(
+            cycle.nth_value(1_000_000_000_000, |s| s.1),
+            2_000_000_000_004
+        );
+    }
+
+    #[test]
+    fn detector_should_report_first_repetition() {
+        let mut sut = CycleDetector::new();
+        for (step, key) in ['a', 'b', 'c', 'd'].into_iter().enumerate() {
+            assert_eq!(sut.push(key, step), None);
+        }
+        let cycle = sut.push('b', 4).expect("cycle");
+        assert_eq!((cycle.mu, cycle.lambda), (1, 3));
+        assert_eq!(cycle.history, vec![0, 1, 2, 3, 4]);
+    }
+}
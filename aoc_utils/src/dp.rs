@@ -0,0 +1,341 @@
+/*! Top down dynamic programming and path counting
+ *
+ * Memo is an explicit cache for recursive functions. No macro magic, so keys may borrow
+ * from the input (&str slices of the puzzle) which the memoize crate can not handle.
+ * It also remembers which keys are currently evaluated. Hitting one of them again means
+ * the recursion would never end, we return the cycle as error instead.
+ *
+ * count_paths is adapted from the pathfinding crate
+ * https://github.com/evenfurther/pathfinding/blob/main/src/directed/count_paths.rs
+ * Memoized DFS instead of BFS, no uncertainty about when to propagate path counts.
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::dp::Memo;
+ * // ways to build a word from patterns, keys borrow from the word
+ * let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+ * let ways = Memo::new().solve("rrbgbr", &|word: &&str, ways| {
+ *     let word = *word;
+ *     if word.is_empty() {
+ *         return Ok(1);
+ *     }
+ *     patterns
+ *         .iter()
+ *         .filter(|p| word.starts_with(*p))
+ *         .map(|p| ways(&word[p.len()..]))
+ *         .sum::<Result<usize, _>>()
+ * });
+ * assert_eq!(ways, Ok(6));
+ * ```
+*/
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+/// The recursion reached a key which is still being evaluated (or a graph could not be sorted).
+/// `cycle` lists the keys from the first occurrence of that key up to the call which repeated it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<K> {
+    pub cycle: Vec<K>,
+}
+
+impl<K: Debug> Display for CycleError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cycle: {:?}", self.cycle)
+    }
+}
+
+impl<K: Debug> std::error::Error for CycleError<K> {}
+
+/// Cache for a recursive function K -> V with cycle detection
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    /// keys currently evaluated, in call order
+    stack: Vec<K>,
+    on_stack: HashSet<K>,
+}
+
+impl<K, V> Default for Memo<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+        }
+    }
+
+    /// number of cached results
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// prime the cache with known results (base cases)
+    pub fn insert(&mut self, key: K, value: V) {
+        self.cache.insert(key, value);
+    }
+
+    /// Evaluate f(key) with memoization. f gets the key and a function to evaluate sub problems,
+    /// which must be propagated with ? so a detected cycle ends the whole recursion.
+    pub fn solve<F>(&mut self, key: K, f: &F) -> Result<V, CycleError<K>>
+    where
+        F: Fn(&K, &mut dyn FnMut(K) -> Result<V, CycleError<K>>) -> Result<V, CycleError<K>>,
+    {
+        if let Some(v) = self.cache.get(&key) {
+            return Ok(v.clone());
+        }
+
+        if self.on_stack.contains(&key) {
+            let start = self
+                .stack
+                .iter()
+                .position(|k| *k == key)
+                .expect("key on stack");
+            let mut cycle = self.stack[start..].to_vec();
+            cycle.push(key);
+            return Err(CycleError { cycle });
+        }
+
+        self.stack.push(key.clone());
+        self.on_stack.insert(key.clone());
+
+        let result = f(&key, &mut |sub| self.solve(sub, f));
+
+        self.stack.pop();
+        self.on_stack.remove(&key);
+
+        if let Ok(v) = &result {
+            self.cache.insert(key, v.clone());
+        }
+        result
+    }
+}
+
+/// Count all paths from start to a node for which success returns true.
+/// Success nodes are not expanded any further. Errors if a cycle is reachable (infinitely many paths).
+pub fn count_paths<T, FN, IN, FS>(
+    start: T,
+    successors: FN,
+    success: FS,
+) -> Result<usize, CycleError<T>>
+where
+    T: Eq + Hash + Clone,
+    FN: Fn(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: Fn(&T) -> bool,
+{
+    Memo::new().solve(start, &|node, count| {
+        if success(node) {
+            return Ok(1);
+        }
+        // https://doc.rust-lang.org/std/iter/fn.successors.html
+        successors(node).into_iter().map(&mut *count).sum()
+    })
+}
+
+/// Count all paths from start to a success node which pass through every waypoint (in any order).
+/// The state is extended by a bitmask of the visited waypoints, so at most 64 waypoints.
+pub fn count_paths_via<T, FN, IN, FS>(
+    start: T,
+    successors: FN,
+    success: FS,
+    waypoints: &[T],
+) -> Result<usize, CycleError<T>>
+where
+    T: Eq + Hash + Clone,
+    FN: Fn(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: Fn(&T) -> bool,
+{
+    assert!(waypoints.len() <= 64, "at most 64 waypoints");
+    let all: u64 = if waypoints.len() == 64 {
+        u64::MAX
+    } else {
+        (1 << waypoints.len()) - 1
+    };
+    let mark = |node: &T, visited: u64| {
+        waypoints
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| *w == node)
+            .fold(visited, |visited, (i, _)| visited | 1 << i)
+    };
+
+    let start_visited = mark(&start, 0);
+    count_paths(
+        (start, start_visited),
+        |(node, visited)| {
+            let visited = *visited;
+            successors(node)
+                .into_iter()
+                .map(move |next| {
+                    let next_visited = mark(&next, visited);
+                    (next, next_visited)
+                })
+                .collect::<Vec<_>>()
+        },
+        |(node, visited)| *visited == all && success(node),
+    )
+    .map_err(|e| CycleError {
+        cycle: e.cycle.into_iter().map(|(node, _)| node).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn graph(edges: &[(&'static str, &'static str)]) -> HashMap<&'static str, Vec<&'static str>> {
+        let mut graph: HashMap<_, Vec<_>> = HashMap::new();
+        for &(from, to) in edges {
+            graph.entry(from).or_default().push(to);
+        }
+        graph
+    }
+
+    #[test]
+    fn solve_should_memoize() {
+        // fibonacci would take forever without cache
+        let fib = Memo::new().solve(90u64, &|&n, fib| {
+            if n < 2 {
+                Ok(n)
+            } else {
+                Ok(fib(n - 1)? + fib(n - 2)?)
+            }
+        });
+        assert_eq!(fib, Ok(2880067194370816120));
+    }
+
+    #[test]
+    fn solve_should_use_primed_cache() {
+        let mut sut = Memo::new();
+        sut.insert(0, 42);
+        assert_eq!(sut.solve(3, &|&n, f| Ok(f(n - 1)? + 1)), Ok(45));
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.get(&2), Some(&44));
+    }
+
+    #[test]
+    fn solve_should_report_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 1
+        let next = |n: u32| if n < 3 { n + 1 } else { 1 };
+        let result: Result<u32, _> = Memo::new().solve(0, &|&n, f| f(next(n)));
+        assert_eq!(
+            result,
+            Err(CycleError {
+                cycle: vec![1, 2, 3, 1]
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("you", 5)]
+    #[case("hhh", 5)]
+    #[case("out", 1)]
+    fn count_paths_should(#[case] start: &str, #[case] expected: usize) {
+        // aoc 2025 day 11 example
+        let g = graph(&[
+            ("aaa", "you"),
+            ("aaa", "hhh"),
+            ("you", "bbb"),
+            ("you", "ccc"),
+            ("bbb", "ddd"),
+            ("bbb", "eee"),
+            ("ccc", "ddd"),
+            ("ccc", "eee"),
+            ("ccc", "fff"),
+            ("ddd", "ggg"),
+            ("eee", "out"),
+            ("fff", "out"),
+            ("ggg", "out"),
+            ("hhh", "ccc"),
+            ("hhh", "fff"),
+            ("hhh", "iii"),
+            ("iii", "out"),
+        ]);
+        let paths = count_paths(
+            start,
+            |n| g.get(n).cloned().unwrap_or_default(),
+            |n| *n == "out",
+        );
+        assert_eq!(paths, Ok(expected));
+    }
+
+    #[test]
+    fn count_paths_should_detect_cycle() {
+        let g = graph(&[("a", "b"), ("b", "c"), ("c", "b"), ("c", "out")]);
+        let paths = count_paths(
+            "a",
+            |n| g.get(n).cloned().unwrap_or_default(),
+            |n| *n == "out",
+        );
+        assert_eq!(
+            paths,
+            Err(CycleError {
+                cycle: vec!["b", "c", "b"]
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(&[], 8)]
+    #[case(&["fft"], 4)]
+    #[case(&["dac", "fft"], 2)]
+    #[case(&["svr", "dac", "fft", "out"], 2)]
+    #[case(&["aaa", "bbb"], 0)]
+    fn count_paths_via_should(#[case] waypoints: &[&str], #[case] expected: usize) {
+        // aoc 2025 day 11 part b example
+        let g = graph(&[
+            ("svr", "aaa"),
+            ("svr", "bbb"),
+            ("aaa", "fft"),
+            ("fft", "ccc"),
+            ("bbb", "tty"),
+            ("tty", "ccc"),
+            ("ccc", "ddd"),
+            ("ccc", "eee"),
+            ("ddd", "hub"),
+            ("hub", "fff"),
+            ("eee", "dac"),
+            ("dac", "fff"),
+            ("fff", "ggg"),
+            ("fff", "hhh"),
+            ("ggg", "out"),
+            ("hhh", "out"),
+        ]);
+        let paths = count_paths_via(
+            "svr",
+            |n| g.get(n).cloned().unwrap_or_default(),
+            |n| *n == "out",
+            waypoints,
+        );
+        assert_eq!(paths, Ok(expected));
+    }
+}
@@ -0,0 +1,221 @@
+/*! Disjoint set union (union-find)
+ *
+ * Connected components without BFS. Path compression (halving) and union by size,
+ * so find and union are practically O(1).
+ * Keeps a merge log, AoC likes to ask for the edge which finally joined everything.
+ *
+ * https://en.wikipedia.org/wiki/Disjoint-set_data_structure
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::dsu::DisjointSet;
+ * let mut dsu = DisjointSet::new(4);
+ * dsu.union(0, 1);
+ * dsu.union(2, 3);
+ * assert_eq!(dsu.component_sizes(), vec![2, 2]);
+ * dsu.union(1, 0); // no op, already connected
+ * dsu.union(1, 3);
+ * assert_eq!(dsu.connecting_merge().map(|m| (m.a, m.b, m.step)), Some((1, 3, 3)));
+ * ```
+*/
+
+/// A successful union
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Merge {
+    /// elements as given to union
+    pub a: usize,
+    pub b: usize,
+    /// index of the union call (counting no op calls too), so it can be mapped back to an edge list
+    pub step: usize,
+    /// size of the merged component
+    pub size: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    /// size of the component, only valid for roots
+    size: Vec<usize>,
+    components: usize,
+    steps: usize,
+    merges: Vec<Merge>,
+}
+
+impl DisjointSet {
+    /// n singleton sets 0..n
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+            steps: 0,
+            merges: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// representative of the set containing x
+    pub fn find(&mut self, mut x: usize) -> usize {
+        // path halving: point every other node on the path to its grandparent
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// join the sets of a and b. Returns false if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let step = self.steps;
+        self.steps += 1;
+
+        let (mut root_a, mut root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        // attach smaller tree below the bigger one
+        if self.size[root_a] < self.size[root_b] {
+            std::mem::swap(&mut root_a, &mut root_b);
+        }
+        self.parent[root_b] = root_a;
+        self.size[root_a] += self.size[root_b];
+        self.components -= 1;
+
+        self.merges.push(Merge {
+            a,
+            b,
+            step,
+            size: self.size[root_a],
+        });
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// size of the component containing x
+    pub fn component_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// number of disjoint sets
+    #[inline]
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// sizes of all components, biggest first
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// all elements grouped by component
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: std::collections::HashMap<usize, Vec<usize>> =
+            std::collections::HashMap::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            groups.entry(root).or_default().push(x);
+        }
+        groups.into_values().collect()
+    }
+
+    /// all successful unions in order
+    pub fn merges(&self) -> &[Merge] {
+        &self.merges
+    }
+
+    /// the union which joined everything into a single component, if that happened yet
+    pub fn connecting_merge(&self) -> Option<&Merge> {
+        if self.components == 1 {
+            self.merges.last()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_should_create_singletons() {
+        let mut sut = DisjointSet::new(5);
+        assert_eq!(sut.components(), 5);
+        assert_eq!(sut.component_sizes(), vec![1; 5]);
+        assert!(!sut.connected(0, 1));
+        assert_eq!(sut.connecting_merge(), None);
+    }
+
+    #[test]
+    fn union_should_join_components() {
+        let mut sut = DisjointSet::new(6);
+        assert!(sut.union(0, 1));
+        assert!(sut.union(1, 2));
+        assert!(sut.union(3, 4));
+        assert!(!sut.union(2, 0));
+
+        assert_eq!(sut.components(), 3);
+        assert_eq!(sut.component_sizes(), vec![3, 2, 1]);
+        assert_eq!(sut.component_size(2), 3);
+        assert_eq!(sut.component_size(5), 1);
+        assert!(sut.connected(0, 2));
+        assert!(!sut.connected(0, 3));
+
+        let mut groups = sut.groups();
+        groups.iter_mut().for_each(|g| g.sort());
+        groups.sort();
+        assert_eq!(groups, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn merge_log_should_record_connecting_union() {
+        let mut sut = DisjointSet::new(4);
+        let edges = [(0, 1), (2, 3), (1, 0), (3, 2), (0, 3), (1, 2)];
+        for (a, b) in edges {
+            sut.union(a, b);
+        }
+
+        assert_eq!(sut.merges().len(), 3);
+        assert_eq!(
+            sut.connecting_merge(),
+            Some(&Merge {
+                a: 0,
+                b: 3,
+                step: 4,
+                size: 4
+            })
+        );
+        assert_eq!(edges[sut.connecting_merge().unwrap().step], (0, 3));
+    }
+
+    #[test]
+    fn find_should_survive_long_chains() {
+        let n = 100_000;
+        let mut sut = DisjointSet::new(n);
+        for i in 1..n {
+            sut.union(i - 1, i);
+        }
+        assert_eq!(sut.components(), 1);
+        assert_eq!(sut.component_size(0), n);
+        assert_eq!(sut.connecting_merge().map(|m| m.step), Some(n - 2));
+    }
+}
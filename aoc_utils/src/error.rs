@@ -0,0 +1,147 @@
+/*! One error type for the solutions
+ *
+ * AocError covers what goes wrong in a puzzle: input which does not parse, a search without
+ * an answer and input which breaks an assumption of the solution. It is a miette Diagnostic,
+ * a parse error keeps the input and the span of the offending part, so the runner prints
+ * the line highlighted instead of a bare "invalid input".
+ *
+ * Parts return `aoc_utils::error::Result<T>` and use ? like every other Result, the
+ * solution! macro turns the error into Error::Input.
+ *
+ * ``` rust
+ * use aoc_utils::error::{AocError, Result};
+ *
+ * fn parse(input: &str) -> Result<Vec<u32>> {
+ *     input
+ *         .lines()
+ *         .map(|line| line.parse().map_err(|_| AocError::parse_at(input, line, "not a number")))
+ *         .collect()
+ * }
+ *
+ * let e = parse("1\n2\nx\n").unwrap_err();
+ * assert_eq!(e.to_string(), "invalid input at line 3: not a number");
+ * ```
+*/
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, SourceSpan};
+use thiserror::Error;
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
+pub enum AocError {
+    /// part of the input does not parse
+    #[error("invalid input at line {line}: {message}")]
+    #[diagnostic(code(aoc::parse))]
+    Parse {
+        message: String,
+        /// 1-based line of the start of span
+        line: usize,
+        #[source_code]
+        input: String,
+        #[label("{message}")]
+        span: SourceSpan,
+    },
+    /// the input parses, but there is no answer, e.g. a search which never reaches the goal
+    #[error("no answer: {0}")]
+    #[diagnostic(code(aoc::no_answer))]
+    NoAnswer(String),
+    /// the input breaks an assumption of the solution
+    #[error("precondition violated: {0}")]
+    #[diagnostic(code(aoc::precondition))]
+    Precondition(String),
+}
+
+impl AocError {
+    /// parse error of the bytes at offset..offset + len of input
+    pub fn parse(input: &str, offset: usize, len: usize, message: impl ToString) -> Self {
+        let offset = offset.min(input.len());
+        let len = len.min(input.len() - offset);
+        AocError::Parse {
+            message: message.to_string(),
+            line: input[..offset].matches('\n').count() + 1,
+            input: input.to_string(),
+            span: (offset, len).into(),
+        }
+    }
+
+    /// parse error of part, a slice of input like a line of input.lines().
+    /// A part outside of input marks the whole input.
+    pub fn parse_at(input: &str, part: &str, message: impl ToString) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset > input.len() || offset + part.len() > input.len() {
+            return Self::parse(input, 0, input.len(), message);
+        }
+        Self::parse(input, offset, part.len(), message)
+    }
+
+    /// parse error of the line with 0-based index
+    pub fn parse_line(input: &str, index: usize, message: impl ToString) -> Self {
+        match input.lines().nth(index) {
+            Some(line) => Self::parse_at(input, line, message),
+            None => Self::parse(input, input.len(), 0, message),
+        }
+    }
+
+    pub fn no_answer(reason: impl ToString) -> Self {
+        AocError::NoAnswer(reason.to_string())
+    }
+
+    pub fn precondition(reason: impl ToString) -> Self {
+        AocError::Precondition(reason.to_string())
+    }
+
+    /// the diagnostic as miette prints it, the offending input highlighted.
+    /// Without colors, the output often ends up in a file.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor())
+            .render_report(&mut out, self)
+            .map(|_| out)
+            .unwrap_or_else(|_| self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "47|53\n97-13\n75|29\n";
+
+    #[rstest]
+    #[case(AocError::parse_at(INPUT, &INPUT[6..11], "expected |"), 2, 6, 5)]
+    #[case(AocError::parse_line(INPUT, 1, "expected |"), 2, 6, 5)]
+    #[case(AocError::parse(INPUT, 9, 100, "expected |"), 2, 9, 9)]
+    #[case(AocError::parse_at(INPUT, "elsewhere", "expected |"), 1, 0, 18)]
+    #[case(AocError::parse_line(INPUT, 7, "expected |"), 4, 18, 0)]
+    fn parse_should_locate_span(
+        #[case] sut: AocError,
+        #[case] expected_line: usize,
+        #[case] expected_offset: usize,
+        #[case] expected_len: usize,
+    ) {
+        let AocError::Parse { line, span, .. } = sut else {
+            panic!("not a parse error");
+        };
+        assert_eq!(line, expected_line);
+        assert_eq!((span.offset(), span.len()), (expected_offset, expected_len));
+    }
+
+    #[test]
+    fn render_should_highlight_line() {
+        let sut = AocError::parse_line(INPUT, 1, "expected |");
+
+        let rendered = sut.render();
+
+        assert!(rendered.contains("invalid input at line 2: expected |"));
+        assert!(rendered.contains("97-13"));
+        assert!(rendered.contains("aoc::parse"));
+    }
+
+    #[test]
+    fn render_should_work_without_input() {
+        let sut = AocError::no_answer("no path to the exit");
+        assert!(sut.render().contains("no answer: no path to the exit"));
+    }
+}
@@ -0,0 +1,418 @@
+/*! Cliques in undirected graphs
+ *
+ * BitGraph stores the adjacency matrix as one bitset per node, so the neighbour sets of
+ * Bron–Kerbosch are intersected 64 nodes at a time. n² bits, fine for a few thousand nodes.
+ *
+ * maximal_cliques: Bron–Kerbosch with pivot (Tomita), every clique which can not be extended
+ * maximum_clique: same search, branches which can not beat the best clique are cut
+ * k_cliques: all cliques of exactly k nodes, e.g. triangles with a node starting with t
+ *
+ * https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::graph::{BitGraph, Graph};
+ * let mut g = Graph::undirected();
+ * for line in ["ka-co", "ta-co", "de-co", "ta-ka", "de-ta", "ka-de", "tc-co", "kh-tc"] {
+ *     let (a, b) = line.split_once('-').unwrap();
+ *     g.add_edge(a, b);
+ * }
+ * let cliques = BitGraph::from(&g);
+ * let mut lan = g.labels(&cliques.maximum_clique());
+ * lan.sort();
+ * assert_eq!(lan.join(","), "co,de,ka,ta");
+ * assert_eq!(cliques.k_cliques(3, |v| g.label(v).starts_with('t')).len(), 3);
+ * ```
+*/
+use super::Graph;
+
+/// Fixed size set of 0..n
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            words: vec![0; n.div_ceil(64)],
+        }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    #[inline]
+    pub fn remove(&mut self, i: usize) {
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+
+    #[inline]
+    pub fn contains(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        BitSet {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & b)
+                .collect(),
+        }
+    }
+
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        BitSet {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & !b)
+                .collect(),
+        }
+    }
+
+    /// size of the intersection without building it
+    pub fn intersection_len(&self, other: &BitSet) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// members in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+
+/// Undirected graph of nodes 0..n with bitset adjacency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGraph {
+    adjacency: Vec<BitSet>,
+}
+
+impl From<&Graph<'_>> for BitGraph {
+    /// same ids as the graph, edge directions are ignored
+    fn from(graph: &Graph<'_>) -> Self {
+        let mut bits = BitGraph::new(graph.len());
+        for from in 0..graph.len() {
+            for to in graph.neighbours(from) {
+                bits.add_edge(from, to);
+            }
+        }
+        bits
+    }
+}
+
+impl BitGraph {
+    pub fn new(n: usize) -> Self {
+        Self {
+            adjacency: vec![BitSet::new(n); n],
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// self loops are ignored, a node is no neighbour of itself
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        if a != b {
+            self.adjacency[a].insert(b);
+            self.adjacency[b].insert(a);
+        }
+    }
+
+    #[inline]
+    pub fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        self.adjacency[a].contains(b)
+    }
+
+    #[inline]
+    pub fn neighbours(&self, v: usize) -> &BitSet {
+        &self.adjacency[v]
+    }
+
+    fn all(&self) -> BitSet {
+        let mut all = BitSet::new(self.len());
+        (0..self.len()).for_each(|v| all.insert(v));
+        all
+    }
+
+    /// node of candidates ∪ excluded with the most neighbours in candidates
+    fn pivot(&self, candidates: &BitSet, excluded: &BitSet) -> Option<usize> {
+        candidates
+            .iter()
+            .chain(excluded.iter())
+            .max_by_key(|&u| self.adjacency[u].intersection_len(candidates))
+    }
+
+    /// all cliques which can not be extended by another node, members ascending
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            self.all(),
+            BitSet::new(self.len()),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: BitSet,
+        mut excluded: BitSet,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        let Some(pivot) = self.pivot(&candidates, &excluded) else {
+            let mut found = clique.clone();
+            found.sort_unstable();
+            cliques.push(found);
+            return;
+        };
+
+        // neighbours of the pivot are found by branches of the other nodes
+        for v in candidates
+            .difference(&self.adjacency[pivot])
+            .iter()
+            .collect::<Vec<_>>()
+        {
+            clique.push(v);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(&self.adjacency[v]),
+                excluded.intersection(&self.adjacency[v]),
+                cliques,
+            );
+            clique.pop();
+            candidates.remove(v);
+            excluded.insert(v);
+        }
+    }
+
+    /// a biggest clique, members ascending
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+        self.grow_maximum(
+            &mut Vec::new(),
+            self.all(),
+            BitSet::new(self.len()),
+            &mut best,
+        );
+        best.sort_unstable();
+        best
+    }
+
+    fn grow_maximum(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: BitSet,
+        mut excluded: BitSet,
+        best: &mut Vec<usize>,
+    ) {
+        if clique.len() + candidates.len() <= best.len() {
+            return; // even all candidates would not be enough
+        }
+        let Some(pivot) = self.pivot(&candidates, &excluded) else {
+            *best = clique.clone();
+            return;
+        };
+
+        for v in candidates
+            .difference(&self.adjacency[pivot])
+            .iter()
+            .collect::<Vec<_>>()
+        {
+            clique.push(v);
+            self.grow_maximum(
+                clique,
+                candidates.intersection(&self.adjacency[v]),
+                excluded.intersection(&self.adjacency[v]),
+                best,
+            );
+            clique.pop();
+            candidates.remove(v);
+            excluded.insert(v);
+        }
+    }
+
+    /// All cliques of exactly k nodes containing at least one node for which contains is true.
+    /// Every clique once, members ascending.
+    pub fn k_cliques<F>(&self, k: usize, contains: F) -> Vec<Vec<usize>>
+    where
+        F: Fn(usize) -> bool,
+    {
+        let mut cliques = Vec::new();
+        if k > 0 {
+            self.extend_k(&mut Vec::new(), self.all(), k, &contains, &mut cliques);
+        }
+        cliques
+    }
+
+    fn extend_k<F>(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: BitSet,
+        k: usize,
+        contains: &F,
+        cliques: &mut Vec<Vec<usize>>,
+    ) where
+        F: Fn(usize) -> bool,
+    {
+        if clique.len() == k {
+            if clique.iter().any(|&v| contains(v)) {
+                cliques.push(clique.clone());
+            }
+            return;
+        }
+
+        // candidates only contains nodes after the last member, so every clique is built once
+        for v in candidates.iter().collect::<Vec<_>>() {
+            if clique.len() + candidates.len() < k {
+                break;
+            }
+            clique.push(v);
+            self.extend_k(
+                clique,
+                candidates.intersection(&self.adjacency[v]),
+                k,
+                contains,
+                cliques,
+            );
+            clique.pop();
+            candidates.remove(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn example() -> Graph<'static> {
+        // aoc 2024 day 23 example
+        let mut g = Graph::undirected();
+        for line in [
+            "kh-tc", "qp-kh", "de-cg", "ka-co", "yn-aq", "qp-ub", "cg-tb", "vc-aq", "tb-ka",
+            "wh-tc", "yn-cg", "kh-ub", "ta-co", "de-co", "tc-td", "tb-wq", "wh-td", "ta-ka",
+            "td-qp", "aq-cg", "wq-ub", "ub-vc", "de-ta", "wq-aq", "wq-vc", "wh-yn", "ka-de",
+            "kh-ta", "co-tc", "wh-qp", "tb-vc", "td-yn",
+        ] {
+            let (a, b) = line.split_once('-').unwrap();
+            g.add_edge(a, b);
+        }
+        g
+    }
+
+    fn sorted_labels(g: &Graph, cliques: &[Vec<usize>]) -> Vec<String> {
+        let mut labels = cliques
+            .iter()
+            .map(|c| {
+                let mut labels = g.labels(c);
+                labels.sort();
+                labels.join(",")
+            })
+            .collect::<Vec<_>>();
+        labels.sort();
+        labels
+    }
+
+    #[test]
+    fn bitset_should_iterate_ascending() {
+        let mut sut = BitSet::new(200);
+        for i in [130, 3, 64, 63, 199, 0] {
+            sut.insert(i);
+        }
+        sut.remove(63);
+        assert_eq!(sut.iter().collect::<Vec<_>>(), vec![0, 3, 64, 130, 199]);
+        assert_eq!(sut.len(), 5);
+        assert!(sut.contains(130));
+        assert!(!sut.contains(131));
+        assert!(BitSet::new(10).is_empty());
+    }
+
+    #[test]
+    fn k_cliques_should_find_triangles_with_t() {
+        let g = example();
+        let sut = BitGraph::from(&g);
+
+        let triangles = sut.k_cliques(3, |v| g.label(v).starts_with('t'));
+        assert_eq!(
+            sorted_labels(&g, &triangles),
+            vec![
+                "co,de,ta", "co,ka,ta", "de,ka,ta", "qp,td,wh", "tb,vc,wq", "tc,td,wh", "td,wh,yn"
+            ]
+        );
+        assert_eq!(sut.k_cliques(3, |_| true).len(), 12);
+    }
+
+    #[rstest]
+    #[case(1, 16)]
+    #[case(2, 32)]
+    #[case(4, 1)]
+    #[case(5, 0)]
+    fn k_cliques_should_count(#[case] k: usize, #[case] expected: usize) {
+        let sut = BitGraph::from(&example());
+        assert_eq!(sut.k_cliques(k, |_| true).len(), expected);
+    }
+
+    #[test]
+    fn maximum_clique_should_find_lan_party() {
+        let g = example();
+        let sut = BitGraph::from(&g);
+        assert_eq!(
+            sorted_labels(&g, &[sut.maximum_clique()]),
+            vec!["co,de,ka,ta"]
+        );
+    }
+
+    #[test]
+    fn maximal_cliques_should_not_be_extendable() {
+        // two triangles sharing an edge, plus a pendant and an isolated node
+        let mut sut = BitGraph::new(6);
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (1, 3), (2, 3), (3, 4)] {
+            sut.add_edge(a, b);
+        }
+
+        let mut cliques = sut.maximal_cliques();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4], vec![5]]
+        );
+        assert_eq!(sut.maximum_clique().len(), 3);
+    }
+}
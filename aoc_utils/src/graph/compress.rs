@@ -0,0 +1,415 @@
+/*! All pairs shortest paths and graph compression
+ *
+ * Mazes and valve networks are mostly corridors. Only a few nodes matter (junctions, valves,
+ * start, end), everything in between is just distance.
+ * CompressedGraph walks from every interesting node until it hits the next interesting ones
+ * (Dijkstra, BFS for weight 1) and keeps only these corridors with their length.
+ * Floyd–Warshall on the corridors gives all pairs distances and the interesting nodes passed on the way.
+ *
+ * https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::{graph::compress_grid, grid::Grid};
+ * use glam::IVec2;
+ * let maze: Grid<char> = ["#########",
+ *                          "#S..#...#",
+ *                          "##.##.#.#",
+ *                          "#.....#E#",
+ *                          "#########"].join("\n").parse().unwrap();
+ * let (start, end) = (IVec2::new(1, 1), IVec2::new(7, 3));
+ * let compressed = compress_grid(&maze, |c| *c != '#', |_, c| *c == 'S' || *c == 'E');
+ *
+ * assert_eq!(compressed.distance(&start, &end), Some(12));
+ * // S, the junctions at (2, 1) and (2, 3), E
+ * assert_eq!(compressed.path(&start, &end).unwrap().len(), 4);
+ * ```
+*/
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use glam::IVec2;
+
+use super::Graph;
+use crate::grid::Grid;
+
+const UNREACHABLE: u64 = u64::MAX;
+const NO_NODE: usize = usize::MAX;
+
+/// Distances between all pairs of nodes 0..n (Floyd–Warshall)
+#[derive(Clone, PartialEq, Eq)]
+pub struct DistanceMatrix {
+    len: usize,
+    distance: Vec<u64>,
+    /// predecessor of to on a shortest path from from
+    prev: Vec<usize>,
+}
+
+impl std::fmt::Debug for DistanceMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Distances")?;
+        for from in 0..self.len {
+            for to in 0..self.len {
+                match self.distance(from, to) {
+                    Some(d) => write!(f, "{d:5}|")?,
+                    None => write!(f, "  ∞  |")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Graph<'_>> for DistanceMatrix {
+    /// same ids as the graph
+    fn from(graph: &Graph<'_>) -> Self {
+        DistanceMatrix::floyd_warshall(
+            graph.len(),
+            (0..graph.len())
+                .flat_map(|from| graph.edges(from).iter().map(move |&(to, w)| (from, to, w))),
+        )
+    }
+}
+
+impl DistanceMatrix {
+    /// all pairs shortest paths for weighted edges (from, to, weight). O(n³)
+    pub fn floyd_warshall<E>(len: usize, edges: E) -> Self
+    where
+        E: IntoIterator<Item = (usize, usize, u64)>,
+    {
+        let mut distance = vec![UNREACHABLE; len * len];
+        let mut prev = vec![NO_NODE; len * len];
+        let index = |from: usize, to: usize| from * len + to;
+
+        for i in 0..len {
+            distance[index(i, i)] = 0;
+            prev[index(i, i)] = i;
+        }
+        for (from, to, weight) in edges {
+            // keep the cheapest of parallel edges
+            let idx = index(from, to);
+            if from != to && weight < distance[idx] {
+                distance[idx] = weight;
+                prev[idx] = from;
+            }
+        }
+
+        for k in 0..len {
+            for i in 0..len {
+                let via_k = distance[index(i, k)];
+                if via_k == UNREACHABLE {
+                    continue;
+                }
+                for j in 0..len {
+                    let rest = distance[index(k, j)];
+                    // do not add to infinity
+                    if rest != UNREACHABLE && via_k + rest < distance[index(i, j)] {
+                        distance[index(i, j)] = via_k + rest;
+                        prev[index(i, j)] = prev[index(k, j)];
+                    }
+                }
+            }
+        }
+
+        Self {
+            len,
+            distance,
+            prev,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    fn index(&self, from: usize, to: usize) -> usize {
+        from * self.len + to
+    }
+
+    /// None if to is unreachable from from
+    pub fn distance(&self, from: usize, to: usize) -> Option<u64> {
+        let d = self.distance[self.index(from, to)];
+        (d != UNREACHABLE).then_some(d)
+    }
+
+    /// last node before to on a shortest path from from
+    pub fn predecessor(&self, from: usize, to: usize) -> Option<usize> {
+        let p = self.prev[self.index(from, to)];
+        (p != NO_NODE).then_some(p)
+    }
+
+    /// nodes of a shortest path including both ends
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.predecessor(from, to)?;
+
+        let mut path = vec![to];
+        let mut v = to;
+        while v != from {
+            v = self.prev[self.index(from, v)];
+            path.push(v);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Interesting nodes of a bigger graph with the distances between them
+#[derive(Debug, Clone)]
+pub struct CompressedGraph<N> {
+    /// interesting nodes, index is the id in edges and distances
+    pub nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+    /// corridors (to, length) which do not pass another interesting node
+    pub edges: Vec<Vec<(usize, u64)>>,
+    pub distances: DistanceMatrix,
+}
+
+impl<N> CompressedGraph<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// successors returns (next, weight) of a node in the full graph
+    pub fn new<I, FN, IN>(interesting: I, successors: FN) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        FN: Fn(&N) -> IN,
+        IN: IntoIterator<Item = (N, u64)>,
+    {
+        let mut nodes = Vec::new();
+        let mut ids = HashMap::new();
+        for node in interesting {
+            ids.entry(node.clone()).or_insert_with(|| {
+                nodes.push(node);
+                nodes.len() - 1
+            });
+        }
+
+        let edges = nodes
+            .iter()
+            .map(|source| corridors(source, &ids, &successors))
+            .collect::<Vec<_>>();
+
+        let distances = DistanceMatrix::floyd_warshall(
+            nodes.len(),
+            edges
+                .iter()
+                .enumerate()
+                .flat_map(|(from, e)| e.iter().map(move |&(to, w)| (from, to, w))),
+        );
+
+        Self {
+            nodes,
+            ids,
+            edges,
+            distances,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn id(&self, node: &N) -> Option<usize> {
+        self.ids.get(node).copied()
+    }
+
+    /// shortest distance in the full graph, None for unreachable or uninteresting nodes
+    pub fn distance(&self, from: &N, to: &N) -> Option<u64> {
+        self.distances.distance(self.id(from)?, self.id(to)?)
+    }
+
+    /// interesting nodes passed on a shortest path, including both ends
+    pub fn path(&self, from: &N, to: &N) -> Option<Vec<N>> {
+        let path = self.distances.path(self.id(from)?, self.id(to)?)?;
+        Some(path.into_iter().map(|id| self.nodes[id].clone()).collect())
+    }
+}
+
+/// Dijkstra from source, stopping at every other interesting node
+fn corridors<N, FN, IN>(source: &N, ids: &HashMap<N, usize>, successors: &FN) -> Vec<(usize, u64)>
+where
+    N: Eq + Hash + Clone,
+    FN: Fn(&N) -> IN,
+    IN: IntoIterator<Item = (N, u64)>,
+{
+    // nodes of the full graph are numbered on the fly, N needs no Ord for the heap
+    let mut seen = vec![source.clone()];
+    let mut distance = HashMap::from([(source.clone(), 0)]);
+    let mut frontier = BinaryHeap::from([(Reverse(0), 0)]);
+    let mut found = Vec::new();
+
+    while let Some((Reverse(d), i)) = frontier.pop() {
+        let node = seen[i].clone();
+        if distance[&node] < d {
+            continue; // outdated entry
+        }
+        if i != 0
+            && let Some(&id) = ids.get(&node)
+        {
+            found.push((id, d));
+            continue; // the corridor ends here
+        }
+        for (next, weight) in successors(&node) {
+            let next_distance = d + weight;
+            if distance
+                .get(&next)
+                .is_none_or(|&known| next_distance < known)
+            {
+                distance.insert(next.clone(), next_distance);
+                seen.push(next);
+                frontier.push((Reverse(next_distance), seen.len() - 1));
+            }
+        }
+    }
+    found
+}
+
+/// Compress the passable cells of a grid (4 neighbourhood).
+/// Junctions and dead ends are always interesting, keep adds more (start, end, keys, ...).
+pub fn compress_grid<T, P, K>(grid: &Grid<T>, passable: P, keep: K) -> CompressedGraph<IVec2>
+where
+    P: Fn(&T) -> bool,
+    K: Fn(IVec2, &T) -> bool,
+{
+    let open_neighbours = |pos: IVec2| {
+        grid.iter_axis_neighbours_with_positions(pos)
+            .filter(|(_, t)| passable(t))
+            .map(|(p, _)| (p, 1))
+            .collect::<Vec<_>>()
+    };
+
+    let interesting = grid
+        .iter_with_positions()
+        .filter(|(pos, t)| passable(t) && (open_neighbours(*pos).len() != 2 || keep(*pos, t)))
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+
+    CompressedGraph::new(interesting, |pos| open_neighbours(*pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn floyd_warshall_should_find_all_pairs() {
+        // 0 -> 1 -> 2 -> 3 is cheaper than 0 -> 3, 4 is unreachable
+        let edges = [
+            (0, 1, 1),
+            (1, 2, 2),
+            (2, 3, 3),
+            (0, 3, 10),
+            (3, 0, 1),
+            (0, 1, 5),
+        ];
+        let sut = DistanceMatrix::floyd_warshall(5, edges);
+
+        assert_eq!(sut.distance(0, 3), Some(6));
+        assert_eq!(sut.distance(3, 2), Some(4));
+        assert_eq!(sut.distance(2, 2), Some(0));
+        assert_eq!(sut.distance(0, 4), None);
+        assert_eq!(sut.path(0, 3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(sut.path(2, 1), Some(vec![2, 3, 0, 1]));
+        assert_eq!(sut.path(1, 1), Some(vec![1]));
+        assert_eq!(sut.path(4, 0), None);
+    }
+
+    #[test]
+    fn distance_matrix_should_match_graph_dijkstra() {
+        let mut g = Graph::undirected();
+        for (a, b, w) in [
+            ("a", "b", 7),
+            ("a", "c", 9),
+            ("a", "f", 14),
+            ("b", "c", 10),
+            ("b", "d", 15),
+            ("c", "d", 11),
+            ("c", "f", 2),
+            ("d", "e", 6),
+            ("e", "f", 9),
+        ] {
+            g.add_weighted_edge(a, b, w);
+        }
+        let sut = DistanceMatrix::from(&g);
+
+        for from in 0..g.len() {
+            for (to, expected) in g.distances(from).into_iter().enumerate() {
+                assert_eq!(sut.distance(from, to), expected);
+            }
+        }
+        let (a, e) = (g.id("a").unwrap(), g.id("e").unwrap());
+        assert_eq!(g.labels(&sut.path(a, e).unwrap()), vec!["a", "c", "f", "e"]);
+    }
+
+    #[rstest]
+    #[case("AA", "HH", Some(5), vec!["AA", "DD", "EE", "HH"])]
+    #[case("JJ", "EE", Some(4), vec!["JJ", "AA", "DD", "EE"])]
+    #[case("BB", "BB", Some(0), vec!["BB"])]
+    fn compressed_graph_should_skip_uninteresting(
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] distance: Option<u64>,
+        #[case] path: Vec<&str>,
+    ) {
+        // aoc 2022 day 16 example, valves with rate > 0 and the start
+        let tunnels = HashMap::from([
+            ("AA", vec!["DD", "II", "BB"]),
+            ("BB", vec!["CC", "AA"]),
+            ("CC", vec!["DD", "BB"]),
+            ("DD", vec!["CC", "AA", "EE"]),
+            ("EE", vec!["FF", "DD"]),
+            ("FF", vec!["EE", "GG"]),
+            ("GG", vec!["FF", "HH"]),
+            ("HH", vec!["GG"]),
+            ("II", vec!["AA", "JJ"]),
+            ("JJ", vec!["II"]),
+        ]);
+        let valves = ["AA", "BB", "CC", "DD", "EE", "HH", "JJ"];
+        let sut = CompressedGraph::new(valves, |v| {
+            tunnels[v].iter().map(|&t| (t, 1)).collect::<Vec<_>>()
+        });
+
+        assert_eq!(sut.len(), 7);
+        assert_eq!(sut.distance(&from, &to), distance);
+        assert_eq!(sut.path(&from, &to), Some(path));
+        assert_eq!(sut.distance(&"AA", &"GG"), None);
+    }
+
+    #[test]
+    fn compress_grid_should_keep_junctions() {
+        let maze: Grid<char> = "#######
+                                #S....#
+                                #.###.#
+                                #...#E#
+                                #######"
+            .parse()
+            .unwrap();
+        let sut = compress_grid(&maze, |c| *c != '#', |_, c| *c == 'S' || *c == 'E');
+
+        // S (corner with 2 neighbours, but kept), dead end (3,3), E. Everything else is corridor.
+        assert_eq!(sut.len(), 3);
+        let (start, end, dead_end) = (IVec2::new(1, 1), IVec2::new(5, 3), IVec2::new(3, 3));
+        assert_eq!(sut.distance(&start, &end), Some(6));
+        assert_eq!(sut.distance(&start, &dead_end), Some(4));
+        assert_eq!(sut.distance(&dead_end, &end), Some(10));
+        assert_eq!(sut.path(&dead_end, &end), Some(vec![dead_end, start, end]));
+    }
+}
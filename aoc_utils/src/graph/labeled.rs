@@ -0,0 +1,468 @@
+/*! Graph with &str labels interned to dense ids
+ *
+ * Most graph puzzles name their nodes ("aaa: bbb ccc", "start-A", "AAA = (BBB, CCC)").
+ * Graph keeps the labels borrowed from the input and works on usize ids internally,
+ * so algorithms can use Vecs instead of HashMaps. Undirected edges are stored in both directions.
+ *
+ * to_dot renders the graph for graphviz, e.g.  dot -Tsvg -O -Kneato input.dot
+ * to_dot_named adds a graph name and defaults for all nodes like node[label=""].
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::graph::Graph;
+ * let mut g = Graph::undirected();
+ * for line in ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"] {
+ *     let (from, to) = line.split_once('-').unwrap();
+ *     g.add_edge(from, to);
+ * }
+ * let (start, cave) = (g.id("start").unwrap(), g.id("c").unwrap());
+ * let (distance, path) = g.shortest_path(start, cave).unwrap();
+ * assert_eq!(distance, 2);
+ * assert_eq!(g.labels(&path), vec!["start", "A", "c"]);
+ * assert_eq!(g.sccs().len(), 1);
+ * ```
+*/
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::Write,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Graph<'a> {
+    directed: bool,
+    labels: Vec<&'a str>,
+    ids: HashMap<&'a str, usize>,
+    /// outgoing (to, weight) per node
+    adjacency: Vec<Vec<(usize, u64)>>,
+    edge_count: usize,
+}
+
+impl<'a> Graph<'a> {
+    pub fn directed() -> Self {
+        Self {
+            directed: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// number of nodes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// number of edges as added, undirected edges count once
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// id of label, adds the node if it is new
+    pub fn add_node(&mut self, label: &'a str) -> usize {
+        *self.ids.entry(label).or_insert_with(|| {
+            self.labels.push(label);
+            self.adjacency.push(Vec::new());
+            self.labels.len() - 1
+        })
+    }
+
+    /// edge with weight 1
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    pub fn add_weighted_edge(&mut self, from: &'a str, to: &'a str, weight: u64) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.adjacency[from].push((to, weight));
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight));
+        }
+        self.edge_count += 1;
+    }
+
+    pub fn id(&self, label: &str) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    #[inline]
+    pub fn label(&self, id: usize) -> &'a str {
+        self.labels[id]
+    }
+
+    pub fn labels(&self, ids: &[usize]) -> Vec<&'a str> {
+        ids.iter().map(|&id| self.labels[id]).collect()
+    }
+
+    /// all labels in order of their ids
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.labels.iter().copied()
+    }
+
+    /// outgoing (to, weight) of node id
+    #[inline]
+    pub fn edges(&self, id: usize) -> &[(usize, u64)] {
+        &self.adjacency[id]
+    }
+
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adjacency[id].iter().map(|&(to, _)| to)
+    }
+
+    /// labels reachable by one edge from label, empty for unknown labels
+    pub fn successors(&self, label: &str) -> Vec<&'a str> {
+        self.id(label)
+            .map(|id| self.neighbours(id).map(|to| self.labels[to]).collect())
+            .unwrap_or_default()
+    }
+
+    /// number of edges to every node, ignoring weights. None if unreachable.
+    pub fn bfs(&self, start: usize) -> Vec<Option<usize>> {
+        let mut hops = vec![None; self.len()];
+        let mut queue = VecDeque::from([start]);
+        hops[start] = Some(0);
+        while let Some(v) = queue.pop_front() {
+            let next = hops[v].map(|h| h + 1);
+            for to in self.neighbours(v) {
+                if hops[to].is_none() {
+                    hops[to] = next;
+                    queue.push_back(to);
+                }
+            }
+        }
+        hops
+    }
+
+    /// all nodes reachable from start in depth first preorder, neighbours in order of insertion
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(v) = stack.pop() {
+            if visited[v] {
+                continue;
+            }
+            visited[v] = true;
+            order.push(v);
+            // reversed, so the first neighbour is visited first
+            stack.extend(
+                self.adjacency[v]
+                    .iter()
+                    .rev()
+                    .map(|&(to, _)| to)
+                    .filter(|&to| !visited[to]),
+            );
+        }
+        order
+    }
+
+    /// Strongly connected components (Kosaraju), connected components for undirected graphs.
+    /// Components are in topological order: no edge leads back to an earlier component.
+    pub fn sccs(&self) -> Vec<Vec<usize>> {
+        let n = self.len();
+
+        // post order of an iterative dfs over all nodes
+        let mut visited = vec![false; n];
+        let mut post_order = Vec::with_capacity(n);
+        for root in 0..n {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            let mut stack = vec![(root, 0)];
+            while let Some((v, next_edge)) = stack.pop() {
+                if let Some(&(to, _)) = self.adjacency[v].get(next_edge) {
+                    stack.push((v, next_edge + 1));
+                    if !visited[to] {
+                        visited[to] = true;
+                        stack.push((to, 0));
+                    }
+                } else {
+                    post_order.push(v);
+                }
+            }
+        }
+
+        // collect components on the reversed graph, latest finished first
+        let mut reversed = vec![Vec::new(); n];
+        for (from, edges) in self.adjacency.iter().enumerate() {
+            for &(to, _) in edges {
+                reversed[to].push(from);
+            }
+        }
+
+        let mut component = vec![usize::MAX; n];
+        let mut components = Vec::new();
+        for &root in post_order.iter().rev() {
+            if component[root] != usize::MAX {
+                continue;
+            }
+            let mut members = vec![root];
+            component[root] = components.len();
+            let mut i = 0;
+            while i < members.len() {
+                for &from in &reversed[members[i]] {
+                    if component[from] == usize::MAX {
+                        component[from] = components.len();
+                        members.push(from);
+                    }
+                }
+                i += 1;
+            }
+            components.push(members);
+        }
+        components
+    }
+
+    /// weighted distance to every node (Dijkstra). None if unreachable.
+    pub fn distances(&self, start: usize) -> Vec<Option<u64>> {
+        self.dijkstra(start, None).0
+    }
+
+    /// cheapest path from start to target including both ends
+    pub fn shortest_path(&self, start: usize, target: usize) -> Option<(u64, Vec<usize>)> {
+        let (distance, previous) = self.dijkstra(start, Some(target));
+        let total = distance[target]?;
+
+        let mut path = vec![target];
+        while let Some(v) = previous[*path.last().expect("path not empty")] {
+            path.push(v);
+        }
+        path.reverse();
+        Some((total, path))
+    }
+
+    fn dijkstra(
+        &self,
+        start: usize,
+        target: Option<usize>,
+    ) -> (Vec<Option<u64>>, Vec<Option<usize>>) {
+        let mut distance = vec![None; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut frontier = BinaryHeap::from([(Reverse(0), start)]);
+        distance[start] = Some(0);
+
+        while let Some((Reverse(d), v)) = frontier.pop() {
+            if distance[v].is_some_and(|known| known < d) {
+                continue; // outdated entry
+            }
+            if Some(v) == target {
+                break;
+            }
+            for &(to, weight) in &self.adjacency[v] {
+                let next = d + weight;
+                if distance[to].is_none_or(|known| next < known) {
+                    distance[to] = Some(next);
+                    previous[to] = Some(v);
+                    frontier.push((Reverse(next), to));
+                }
+            }
+        }
+        (distance, previous)
+    }
+
+    /// graphviz source, weights other than 1 become edge labels
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with(|_| None)
+    }
+
+    /// graphviz source with extra attributes for some nodes, e.g. "style=filled, fillcolor=green"
+    pub fn to_dot_with<F>(&self, node_attributes: F) -> String
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        self.to_dot_named("", "", node_attributes)
+    }
+
+    /// graphviz source of the graph called name, node_defaults apply to every node,
+    /// e.g. label="" for large graphs. Empty name or defaults are left out.
+    pub fn to_dot_named<F>(&self, name: &str, node_defaults: &str, node_attributes: F) -> String
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        // nodes without any edge must be listed on their own
+        let mut isolated = vec![true; self.len()];
+        for (from, edges) in self.adjacency.iter().enumerate() {
+            for &(to, _) in edges {
+                isolated[from] = false;
+                isolated[to] = false;
+            }
+        }
+
+        let mut dot = if name.is_empty() {
+            format!("{kind} {{\n")
+        } else {
+            format!("{kind} {name} {{\n")
+        };
+        if !node_defaults.is_empty() {
+            writeln!(dot, "    node[{node_defaults}];").unwrap();
+        }
+        for (id, label) in self.labels.iter().enumerate() {
+            if let Some(attributes) = node_attributes(label) {
+                writeln!(dot, "    \"{label}\" [{attributes}];").unwrap();
+            } else if isolated[id] {
+                writeln!(dot, "    \"{label}\";").unwrap();
+            }
+        }
+        for (from, edges) in self.adjacency.iter().enumerate() {
+            for &(to, weight) in edges {
+                // undirected edges are stored twice, draw them once
+                if !self.directed && to < from {
+                    continue;
+                }
+                write!(
+                    dot,
+                    "    \"{}\" {arrow} \"{}\"",
+                    self.labels[from], self.labels[to]
+                )
+                .unwrap();
+                if weight != 1 {
+                    write!(dot, " [label={weight}]").unwrap();
+                }
+                dot.push_str(";\n");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn directed(edges: &[(&'static str, &'static str, u64)]) -> Graph<'static> {
+        let mut g = Graph::directed();
+        for &(from, to, weight) in edges {
+            g.add_weighted_edge(from, to, weight);
+        }
+        g
+    }
+
+    #[test]
+    fn add_edge_should_intern_labels() {
+        let mut sut = Graph::undirected();
+        sut.add_edge("a", "b");
+        sut.add_edge("b", "c");
+        sut.add_edge("a", "c");
+
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.edge_count(), 3);
+        assert_eq!(sut.id("b"), Some(1));
+        assert_eq!(sut.id("x"), None);
+        assert_eq!(sut.successors("a"), vec!["b", "c"]);
+        assert_eq!(sut.successors("c"), vec!["b", "a"]);
+        assert_eq!(sut.nodes().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn bfs_and_dfs_should_follow_direction() {
+        let sut = directed(&[
+            ("a", "b", 1),
+            ("a", "c", 1),
+            ("b", "d", 1),
+            ("c", "d", 1),
+            ("e", "a", 1),
+        ]);
+
+        assert_eq!(sut.bfs(0), vec![Some(0), Some(1), Some(1), Some(2), None]);
+        assert_eq!(sut.labels(&sut.dfs(0)), vec!["a", "b", "d", "c"]);
+    }
+
+    #[test]
+    fn sccs_should_be_in_topological_order() {
+        // cycle a b c, feeding cycle d e, feeding f. g feeds a.
+        let sut = directed(&[
+            ("a", "b", 1),
+            ("b", "c", 1),
+            ("c", "a", 1),
+            ("c", "d", 1),
+            ("d", "e", 1),
+            ("e", "d", 1),
+            ("e", "f", 1),
+            ("g", "a", 1),
+        ]);
+
+        let components = sut
+            .sccs()
+            .iter()
+            .map(|c| {
+                let mut labels = sut.labels(c);
+                labels.sort();
+                labels
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            components,
+            vec![vec!["g"], vec!["a", "b", "c"], vec!["d", "e"], vec!["f"]]
+        );
+    }
+
+    #[rstest]
+    #[case("a", "d", Some((4, vec!["a", "c", "b", "d"])))]
+    #[case("a", "a", Some((0, vec!["a"])))]
+    #[case("d", "a", None)]
+    fn shortest_path_should(
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] expected: Option<(u64, Vec<&str>)>,
+    ) {
+        let sut = directed(&[
+            ("a", "b", 5),
+            ("a", "c", 1),
+            ("c", "b", 2),
+            ("b", "d", 1),
+            ("c", "d", 7),
+        ]);
+        let (from, to) = (sut.id(from).unwrap(), sut.id(to).unwrap());
+
+        let path = sut.shortest_path(from, to);
+        assert_eq!(path.map(|(d, p)| (d, sut.labels(&p))), expected);
+        assert_eq!(sut.distances(from)[to], expected.map(|(d, _)| d));
+    }
+
+    #[test]
+    fn to_dot_should_render_edges_once() {
+        let mut sut = Graph::undirected();
+        sut.add_weighted_edge("a", "b", 3);
+        sut.add_edge("b", "11A");
+        sut.add_node("x");
+
+        assert_eq!(
+            sut.to_dot_with(|l| l.ends_with('A').then(|| "color=green".to_string())),
+            "graph {\n    \"11A\" [color=green];\n    \"x\";\n    \"a\" -- \"b\" [label=3];\n    \"b\" -- \"11A\";\n}\n"
+        );
+    }
+
+    #[test]
+    fn to_dot_named_should_start_with_name_and_defaults() {
+        let mut sut = Graph::directed();
+        sut.add_edge("a", "b");
+
+        assert_eq!(
+            sut.to_dot_named("AOC", "label=\"\"", |_| None),
+            "digraph AOC {\n    node[label=\"\"];\n    \"a\" -> \"b\";\n}\n"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+/*! Graph algorithms over generic keys
+ *
+ * Most AoC graphs come as a list of edges between labels. toposort takes plain iterators
+ * of keys and (from, to) pairs, Graph interns &str labels for everything else.
+*/
+mod clique;
+mod compress;
+mod labeled;
+mod topo;
+
+pub use clique::{BitGraph, BitSet};
+pub use compress::{CompressedGraph, DistanceMatrix, compress_grid};
+pub use labeled::Graph;
+pub use topo::{TopoOrder, toposort, toposort_subset};
@@ -0,0 +1,266 @@
+/*! Topological sort (Kahn's algorithm) with levels
+ *
+ * The rank of a node is the length of the longest path from a node without predecessors,
+ * so all nodes of one rank can be processed together (gates of a circuit, steps of a plan).
+ * Nodes keep the order in which they were first seen within a rank, the result is deterministic.
+ *
+ * On failure the actual cycle is returned, not only the nodes which could not be sorted.
+ *
+ * AoC rule sets are often only acyclic for the part which is asked for (2024 day 5: the page
+ * ordering rules contain cycles, the rules between the pages of one print do not).
+ * toposort_subset sorts a subset by the rules between its members only.
+ *
+ * https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::graph::{toposort, toposort_subset};
+ * let rules = [(1, 2), (2, 3), (3, 1), (1, 4), (4, 3)];
+ * // 3 -> 1 closes the cycle, but 1 is not part of the subset
+ * let sorted = toposort_subset(&[4, 3, 2], &rules).unwrap();
+ * assert_eq!(sorted.order, vec![4, 2, 3]);
+ *
+ * let cycle = toposort([], rules).unwrap_err();
+ * assert_eq!(cycle.cycle, vec![1, 2, 3, 1]);
+ * ```
+*/
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::dp::CycleError;
+
+/// Result of a successful topological sort
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopoOrder<K: Eq + Hash> {
+    /// all nodes, every node after its predecessors. Sorted by rank.
+    pub order: Vec<K>,
+    /// 0 for nodes without predecessors, else 1 + highest rank of the predecessors
+    pub rank: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash + Clone> TopoOrder<K> {
+    /// nodes grouped by rank
+    pub fn levels(&self) -> Vec<Vec<K>> {
+        let mut levels: Vec<Vec<K>> = Vec::new();
+        for node in &self.order {
+            let rank = self.rank[node];
+            if levels.len() <= rank {
+                levels.push(Vec::new());
+            }
+            levels[rank].push(node.clone());
+        }
+        levels
+    }
+
+    /// index of node in order
+    pub fn position(&self, node: &K) -> Option<usize> {
+        self.order.iter().position(|n| n == node)
+    }
+}
+
+/// Sort all nodes by the edges (from, to): from is sorted before to.
+/// Nodes only mentioned in edges are included, so nodes may be empty.
+pub fn toposort<K, N, E>(nodes: N, edges: E) -> Result<TopoOrder<K>, CycleError<K>>
+where
+    K: Eq + Hash + Clone,
+    N: IntoIterator<Item = K>,
+    E: IntoIterator<Item = (K, K)>,
+{
+    // intern keys to dense ids in order of appearance
+    let mut keys: Vec<K> = Vec::new();
+    let mut ids: HashMap<K, usize> = HashMap::new();
+    let mut id_of = |key: K| {
+        *ids.entry(key.clone()).or_insert_with(|| {
+            keys.push(key);
+            keys.len() - 1
+        })
+    };
+
+    for node in nodes {
+        id_of(node);
+    }
+    let edges = edges
+        .into_iter()
+        .map(|(from, to)| (id_of(from), id_of(to)))
+        .collect::<Vec<_>>();
+
+    let n = keys.len();
+    let mut successors = vec![Vec::new(); n];
+    let mut predecessors = vec![Vec::new(); n];
+    let mut in_degree = vec![0; n];
+    for (from, to) in edges {
+        successors[from].push(to);
+        predecessors[to].push(from);
+        in_degree[to] += 1;
+    }
+
+    let mut order = Vec::with_capacity(n);
+    let mut rank = vec![0; n];
+    let mut level = (0..n).filter(|&v| in_degree[v] == 0).collect::<Vec<_>>();
+    let mut current_rank = 0;
+    while !level.is_empty() {
+        let mut next = Vec::new();
+        for v in level {
+            rank[v] = current_rank;
+            order.push(v);
+            for &w in &successors[v] {
+                in_degree[w] -= 1;
+                if in_degree[w] == 0 {
+                    next.push(w);
+                }
+            }
+        }
+        level = next;
+        current_rank += 1;
+    }
+
+    if order.len() < n {
+        let remaining = (0..n).find(|&v| in_degree[v] > 0).expect("unsorted node");
+        let cycle = find_cycle(remaining, &predecessors, &in_degree);
+        return Err(CycleError {
+            cycle: cycle.into_iter().map(|v| keys[v].clone()).collect(),
+        });
+    }
+
+    Ok(TopoOrder {
+        rank: order.iter().map(|&v| (keys[v].clone(), rank[v])).collect(),
+        order: order.into_iter().map(|v| keys[v].clone()).collect(),
+    })
+}
+
+/// Sort the nodes of subset by the rules between members of subset.
+/// Rules with an end outside of subset are ignored, so cycles through other nodes do not matter.
+pub fn toposort_subset<'r, K, R>(subset: &[K], rules: R) -> Result<TopoOrder<K>, CycleError<K>>
+where
+    K: Eq + Hash + Clone + 'r,
+    R: IntoIterator<Item = &'r (K, K)>,
+{
+    let members = subset.iter().collect::<HashSet<_>>();
+    let edges = rules
+        .into_iter()
+        .filter(|(from, to)| members.contains(from) && members.contains(to))
+        .cloned();
+
+    toposort(subset.iter().cloned(), edges)
+}
+
+/// Every node Kahn could not sort still has an unsorted predecessor.
+/// Walking predecessors must therefore run into a cycle.
+fn find_cycle(start: usize, predecessors: &[Vec<usize>], in_degree: &[usize]) -> Vec<usize> {
+    let mut path = vec![start];
+    let mut seen = HashMap::from([(start, 0)]);
+    let mut v = start;
+    loop {
+        v = *predecessors[v]
+            .iter()
+            .find(|&&p| in_degree[p] > 0)
+            .expect("unsorted predecessor");
+        if let Some(&i) = seen.get(&v) {
+            // path runs against the edges
+            let mut cycle = path[i..].to_vec();
+            cycle.push(v);
+            cycle.reverse();
+            return cycle;
+        }
+        seen.insert(v, path.len());
+        path.push(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn toposort_should_rank_nodes() {
+        // diamond with a tail and an isolated node
+        let edges = [
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("d", "e"),
+            ("a", "e"),
+        ];
+        let sut = toposort(["x"], edges).unwrap();
+
+        assert_eq!(sut.order, vec!["x", "a", "b", "c", "d", "e"]);
+        assert_eq!(sut.rank["e"], 3);
+        assert_eq!(
+            sut.levels(),
+            vec![vec!["x", "a"], vec!["b", "c"], vec!["d"], vec!["e"]]
+        );
+        assert_eq!(sut.position(&"d"), Some(4));
+    }
+
+    #[test]
+    fn toposort_should_respect_all_edges() {
+        let edges = (0..50u32)
+            .flat_map(|a| {
+                (a + 1..50)
+                    .filter(move |b| b % (a + 1) == 0)
+                    .map(move |b| (b, a))
+            })
+            .collect::<Vec<_>>();
+        let sut = toposort(0..50, edges.iter().cloned()).unwrap();
+
+        assert_eq!(sut.order.len(), 50);
+        for (from, to) in edges {
+            assert!(sut.position(&from) < sut.position(&to), "{from} -> {to}");
+            assert!(sut.rank[&from] < sut.rank[&to], "{from} -> {to}");
+        }
+    }
+
+    #[rstest]
+    #[case(&[(1, 1)], vec![1, 1])]
+    #[case(&[(1, 2), (2, 1)], vec![1, 2, 1])]
+    #[case(&[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)], vec![1, 2, 3, 1])]
+    #[case(&[(5, 6), (6, 7), (7, 8), (8, 6), (9, 5)], vec![6, 7, 8, 6])]
+    fn toposort_should_report_cycle(#[case] edges: &[(u32, u32)], #[case] expected: Vec<u32>) {
+        let sut = toposort([], edges.iter().cloned());
+        assert_eq!(sut, Err(CycleError { cycle: expected }));
+    }
+
+    #[rstest]
+    #[case(&[75, 97, 47, 61, 53], vec![97, 75, 47, 61, 53])]
+    #[case(&[61, 13, 29], vec![61, 29, 13])]
+    #[case(&[97, 13, 75, 29, 47], vec![97, 75, 47, 29, 13])]
+    fn toposort_subset_should_ignore_cycles_outside(
+        #[case] subset: &[u32],
+        #[case] expected: Vec<u32>,
+    ) {
+        // aoc 2024 day 5 example rules plus a cycle through pages which are never printed together
+        let rules = [
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (97, 47),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+            (97, 29),
+            (53, 29),
+            (61, 53),
+            (97, 53),
+            (61, 29),
+            (47, 13),
+            (75, 47),
+            (97, 75),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+            (75, 13),
+            (53, 13),
+            (13, 1),
+            (1, 97),
+        ];
+        assert!(toposort([], rules).is_err());
+
+        let sut = toposort_subset(subset, &rules).unwrap();
+        assert_eq!(sut.order, expected);
+    }
+}
@@ -0,0 +1,56 @@
+/*! Heap profiling of a part with dhat
+ *
+ * With the feature dhat-heap aoc_utils installs dhat as global allocator and profile() runs
+ * the part under a dhat::Profiler. It writes dhat-heap-{year}-{dd}-{part}.json (open it in
+ * https://nnethercote.github.io/dh_view/dh_view.html) and prints peak and total allocations.
+ * Without the feature profile() only runs the part.
+ *
+ * The day crates and the runner forward their own dhat-heap feature, e.g.
+ * cargo run --profile dhat --features dhat-heap -p aoc_2024_11 --bin aoc_2024_11_b
+ *
+ * ``` rust
+ * let stones = aoc_utils::heap::profile(2024, 11, 'b', || vec![125, 17].len());
+ * assert_eq!(stones, 2);
+ * ```
+*/
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+pub fn file_name(year: u16, day: u8, part: char) -> String {
+    format!("dhat-heap-{year}-{day:02}-{part}.json")
+}
+
+/// Run part, under the heap profiler if the feature dhat-heap is on
+#[cfg(feature = "dhat-heap")]
+pub fn profile<T>(year: u16, day: u8, part: char, run: impl FnOnce() -> T) -> T {
+    let file = file_name(year, day, part);
+    let profiler = dhat::Profiler::builder().file_name(&file).build();
+    let result = run();
+
+    let stats = dhat::HeapStats::get();
+    println!(
+        "heap {year} {day:02} {part}: peak {} bytes in {} blocks, {} allocations of {} bytes, see {file}",
+        stats.max_bytes, stats.max_blocks, stats.total_blocks, stats.total_bytes
+    );
+    // writes the file
+    drop(profiler);
+    result
+}
+
+/// Run part, under the heap profiler if the feature dhat-heap is on
+#[cfg(not(feature = "dhat-heap"))]
+pub fn profile<T>(_year: u16, _day: u8, _part: char, run: impl FnOnce() -> T) -> T {
+    run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_name_should_pad_day() {
+        assert_eq!(file_name(2024, 7, 'a'), "dhat-heap-2024-07-a.json");
+    }
+}
@@ -0,0 +1,508 @@
+/*! Small (integer) linear programming solver
+ *
+ * Pure Rust stand in for z3 / an ILP solver on "minimal number of presses" puzzles.
+ * Minimises c^T x subject to linear equality / inequality constraints and x >= 0.
+ *
+ * LP relaxation: two phase tableau simplex over exact rationals with Bland's rule,
+ * so no rounding errors and no cycling.
+ * Integer solutions: branch and bound on the first fractional variable (x <= floor | x >= ceil).
+ *
+ * Fine for a few dozen variables and constraints, not meant for anything bigger.
+ *
+ * https://en.wikipedia.org/wiki/Simplex_algorithm
+ * https://en.wikipedia.org/wiki/Branch_and_bound
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::ilp::{Problem, Relation, Outcome};
+ * // minimise x + y with x + 2y = 7, x <= 2
+ * let mut problem = Problem::minimize(&[1, 1]);
+ * problem.add_constraint(&[1, 2], Relation::Eq, 7);
+ * problem.add_constraint(&[1, 0], Relation::Le, 2);
+ * let Outcome::Optimal { x, .. } = problem.solve_integer() else { panic!("no solution") };
+ * assert_eq!(x, vec![1, 3]);
+ * ```
+*/
+use num::{Signed, Zero};
+
+use crate::linalg::{Matrix, Rational};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// lhs <= rhs
+    Le,
+    /// lhs == rhs
+    Eq,
+    /// lhs >= rhs
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    pub coefficients: Vec<Rational>,
+    pub relation: Relation,
+    pub rhs: Rational,
+}
+
+/// Result of an optimisation. T is Rational for the LP relaxation and i128 for integer solutions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Optimal { value: Rational, x: Vec<T> },
+    Infeasible,
+    Unbounded,
+}
+
+/// minimise objective^T x subject to constraints and x >= 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    pub objective: Vec<Rational>,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Problem {
+    /// New problem minimising objective^T x. The length of the objective defines the number of variables.
+    pub fn minimize<T: Copy + Into<i128>>(objective: &[T]) -> Self {
+        Self {
+            objective: to_rationals(objective),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// New problem maximising objective^T x (by minimising -objective^T x).
+    /// The value of an optimal outcome is still the minimised value, negate it to get the maximum.
+    pub fn maximize<T: Copy + Into<i128>>(objective: &[T]) -> Self {
+        Self {
+            objective: to_rationals(objective).into_iter().map(|c| -c).collect(),
+            constraints: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn variables(&self) -> usize {
+        self.objective.len()
+    }
+
+    /// add coefficients^T x (relation) rhs
+    pub fn add_constraint<T: Copy + Into<i128>>(
+        &mut self,
+        coefficients: &[T],
+        relation: Relation,
+        rhs: T,
+    ) {
+        self.add_rational_constraint(
+            to_rationals(coefficients),
+            relation,
+            Rational::from_integer(rhs.into()),
+        );
+    }
+
+    pub fn add_rational_constraint(
+        &mut self,
+        coefficients: Vec<Rational>,
+        relation: Relation,
+        rhs: Rational,
+    ) {
+        assert_eq!(
+            coefficients.len(),
+            self.variables(),
+            "need one coefficient per variable"
+        );
+        self.constraints.push(Constraint {
+            coefficients,
+            relation,
+            rhs,
+        });
+    }
+
+    /// bound a single variable: x\[var\] (relation) value
+    pub fn add_bound(&mut self, var: usize, relation: Relation, value: Rational) {
+        let mut coefficients = vec![Rational::zero(); self.variables()];
+        coefficients[var] = Rational::from_integer(1);
+        self.add_rational_constraint(coefficients, relation, value);
+    }
+
+    /// Solve the LP relaxation (x real, x >= 0)
+    pub fn solve_relaxed(&self) -> Outcome<Rational> {
+        Tableau::new(self).solve(&self.objective)
+    }
+
+    /// Solve with x restricted to non negative integers via branch and bound
+    pub fn solve_integer(&self) -> Outcome<i128> {
+        let integral_objective = self.objective.iter().all(|c| c.is_integer());
+        let mut best: Option<(Rational, Vec<i128>)> = None;
+        let mut stack = vec![self.clone()];
+
+        while let Some(problem) = stack.pop() {
+            let (value, x) = match problem.solve_relaxed() {
+                Outcome::Infeasible => continue,
+                // relaxation unbounded, integer problem is either unbounded or infeasible. Do not dig deeper.
+                Outcome::Unbounded => return Outcome::Unbounded,
+                Outcome::Optimal { value, x } => (value, x),
+            };
+
+            // no integer solution in this branch can beat the best one found so far
+            let bound = if integral_objective {
+                value.ceil()
+            } else {
+                value
+            };
+            if best
+                .as_ref()
+                .is_some_and(|(best_value, _)| bound >= *best_value)
+            {
+                continue;
+            }
+
+            match x.iter().position(|v| !v.is_integer()) {
+                None => best = Some((value, x.iter().map(|v| v.to_integer()).collect())),
+                Some(var) => {
+                    let mut down = problem.clone();
+                    down.add_bound(var, Relation::Le, x[var].floor());
+                    let mut up = problem;
+                    up.add_bound(var, Relation::Ge, x[var].ceil());
+                    stack.push(up);
+                    stack.push(down);
+                }
+            }
+        }
+
+        match best {
+            Some((value, x)) => Outcome::Optimal { value, x },
+            None => Outcome::Infeasible,
+        }
+    }
+}
+
+fn to_rationals<T: Copy + Into<i128>>(values: &[T]) -> Vec<Rational> {
+    values
+        .iter()
+        .map(|&v| Rational::from_integer(v.into()))
+        .collect()
+}
+
+/// Simplex tableau in canonical form. Columns: original variables, slack/surplus variables,
+/// artificial variables, rhs. One row per constraint.
+struct Tableau {
+    matrix: Matrix,
+    basis: Vec<usize>,
+    variables: usize,
+    /// first artificial column, artificial variables may never (re)enter the basis in phase 2
+    artificial: usize,
+}
+
+impl Tableau {
+    fn new(problem: &Problem) -> Self {
+        let n = problem.variables();
+        let m = problem.constraints.len();
+
+        // normalize to rhs >= 0 so that slack / artificial variables form a feasible start basis
+        let constraints: Vec<Constraint> = problem
+            .constraints
+            .iter()
+            .map(|c| {
+                if c.rhs.is_negative() {
+                    Constraint {
+                        coefficients: c.coefficients.iter().map(|v| -v).collect(),
+                        relation: match c.relation {
+                            Relation::Le => Relation::Ge,
+                            Relation::Eq => Relation::Eq,
+                            Relation::Ge => Relation::Le,
+                        },
+                        rhs: -c.rhs,
+                    }
+                } else {
+                    c.clone()
+                }
+            })
+            .collect();
+
+        let slacks = constraints
+            .iter()
+            .filter(|c| c.relation != Relation::Eq)
+            .count();
+        let artificials = constraints
+            .iter()
+            .filter(|c| c.relation != Relation::Le)
+            .count();
+        let artificial = n + slacks;
+        let rhs = artificial + artificials;
+
+        let mut matrix = Matrix::zeros(m, rhs + 1);
+        let mut basis = Vec::with_capacity(m);
+        let (mut slack, mut art) = (n, artificial);
+        let one = Rational::from_integer(1);
+
+        for (row, c) in constraints.iter().enumerate() {
+            for (col, &v) in c.coefficients.iter().enumerate() {
+                matrix.set(row, col, v);
+            }
+            matrix.set(row, rhs, c.rhs);
+
+            match c.relation {
+                Relation::Le => {
+                    matrix.set(row, slack, one);
+                    basis.push(slack);
+                    slack += 1;
+                }
+                Relation::Ge => {
+                    matrix.set(row, slack, -one);
+                    slack += 1;
+                    matrix.set(row, art, one);
+                    basis.push(art);
+                    art += 1;
+                }
+                Relation::Eq => {
+                    matrix.set(row, art, one);
+                    basis.push(art);
+                    art += 1;
+                }
+            }
+        }
+
+        Self {
+            matrix,
+            basis,
+            variables: n,
+            artificial,
+        }
+    }
+
+    #[inline]
+    fn rhs(&self) -> usize {
+        self.matrix.cols - 1
+    }
+
+    /// Phase 1 (find feasible basis) and phase 2 (optimise objective)
+    fn solve(mut self, objective: &[Rational]) -> Outcome<Rational> {
+        let rhs = self.rhs();
+
+        // phase 1: minimise the sum of the artificial variables
+        if self.artificial < rhs {
+            let cost: Vec<Rational> = (0..rhs)
+                .map(|c| Rational::from_integer((c >= self.artificial) as i128))
+                .collect();
+            // phase 1 is bounded below by 0
+            self.optimise(&cost, rhs);
+            if !self.value(&cost).is_zero() {
+                return Outcome::Infeasible;
+            }
+
+            // drive artificial variables left in the basis (at level 0) out if possible.
+            // if the row has no other non zero entry, it is redundant and can stay as it is.
+            for row in 0..self.basis.len() {
+                if self.basis[row] >= self.artificial
+                    && let Some(col) =
+                        (0..self.artificial).find(|&c| !self.matrix.get(row, c).is_zero())
+                {
+                    self.matrix.pivot(row, col);
+                    self.basis[row] = col;
+                }
+            }
+        }
+
+        // phase 2: original objective, artificial columns are locked out
+        let mut cost = objective.to_vec();
+        cost.resize(rhs, Rational::zero());
+        if !self.optimise(&cost, self.artificial) {
+            return Outcome::Unbounded;
+        }
+
+        let mut x = vec![Rational::zero(); self.variables];
+        for (row, &b) in self.basis.iter().enumerate() {
+            if b < self.variables {
+                x[b] = self.matrix.get(row, rhs);
+            }
+        }
+        Outcome::Optimal {
+            value: self.value(&cost),
+            x,
+        }
+    }
+
+    fn value(&self, cost: &[Rational]) -> Rational {
+        let rhs = self.rhs();
+        self.basis
+            .iter()
+            .enumerate()
+            .map(|(row, &b)| cost[b] * self.matrix.get(row, rhs))
+            .sum()
+    }
+
+    /// Pivot until no column below `allowed` has a negative reduced cost.
+    /// Bland's rule: lowest entering index, lowest leaving basis index on ties.
+    /// Returns false if the objective is unbounded.
+    fn optimise(&mut self, cost: &[Rational], allowed: usize) -> bool {
+        let rhs = self.rhs();
+        loop {
+            let reduced_cost = |col: usize| {
+                cost[col]
+                    - self
+                        .basis
+                        .iter()
+                        .enumerate()
+                        .map(|(row, &b)| cost[b] * self.matrix.get(row, col))
+                        .sum::<Rational>()
+            };
+
+            let Some(entering) = (0..allowed)
+                .filter(|c| !self.basis.contains(c))
+                .find(|&c| reduced_cost(c).is_negative())
+            else {
+                return true;
+            };
+
+            let leaving = (0..self.basis.len())
+                .filter(|&row| self.matrix.get(row, entering).is_positive())
+                .map(|row| {
+                    (
+                        self.matrix.get(row, rhs) / self.matrix.get(row, entering),
+                        self.basis[row],
+                        row,
+                    )
+                })
+                .min();
+
+            let Some((_, _, row)) = leaving else {
+                return false;
+            };
+
+            self.matrix.pivot(row, entering);
+            self.basis[row] = entering;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn q(n: i128) -> Rational {
+        Rational::from_integer(n)
+    }
+
+    #[test]
+    fn solve_relaxed_should_find_vertex() {
+        // max 3x + 2y, x + y <= 4, x + 3y <= 6, x <= 3 => x = 3, y = 1, value 11
+        let mut sut = Problem::maximize(&[3, 2]);
+        sut.add_constraint(&[1, 1], Relation::Le, 4);
+        sut.add_constraint(&[1, 3], Relation::Le, 6);
+        sut.add_constraint(&[1, 0], Relation::Le, 3);
+
+        assert_eq!(
+            sut.solve_relaxed(),
+            Outcome::Optimal {
+                value: q(-11),
+                x: vec![q(3), q(1)]
+            }
+        );
+    }
+
+    #[test]
+    fn solve_relaxed_should_give_fraction() {
+        // min x, 2x >= 1
+        let mut sut = Problem::minimize(&[1]);
+        sut.add_constraint(&[2], Relation::Ge, 1);
+
+        assert_eq!(
+            sut.solve_relaxed(),
+            Outcome::Optimal {
+                value: Rational::new(1, 2),
+                x: vec![Rational::new(1, 2)]
+            }
+        );
+    }
+
+    #[test]
+    fn solve_should_detect_infeasible() {
+        // x + y <= 1, x + y >= 2
+        let mut sut = Problem::minimize(&[1, 1]);
+        sut.add_constraint(&[1, 1], Relation::Le, 1);
+        sut.add_constraint(&[1, 1], Relation::Ge, 2);
+
+        assert_eq!(sut.solve_relaxed(), Outcome::Infeasible);
+        assert_eq!(sut.solve_integer(), Outcome::Infeasible);
+    }
+
+    #[test]
+    fn solve_should_detect_unbounded() {
+        // max x, x - y <= 1
+        let mut sut = Problem::maximize(&[1, 0]);
+        sut.add_constraint(&[1, -1], Relation::Le, 1);
+
+        assert_eq!(sut.solve_relaxed(), Outcome::Unbounded);
+        assert_eq!(sut.solve_integer(), Outcome::Unbounded);
+    }
+
+    #[test]
+    fn solve_integer_should_branch() {
+        // 2x = 3 has no integer solution but a rational one
+        let mut sut = Problem::minimize(&[1]);
+        sut.add_constraint(&[2], Relation::Eq, 3);
+        assert_eq!(sut.solve_integer(), Outcome::Infeasible);
+
+        // max x + y, 2x + 2y <= 7 => relaxation 3.5, integer 3
+        let mut sut = Problem::maximize(&[1, 1]);
+        sut.add_constraint(&[2, 2], Relation::Le, 7);
+        let Outcome::Optimal { value, x } = sut.solve_integer() else {
+            panic!("expected optimum");
+        };
+        assert_eq!(value, q(-3));
+        assert_eq!(x.iter().sum::<i128>(), 3);
+    }
+
+    #[test]
+    fn solve_should_handle_negative_rhs() {
+        // -x <= -2 => x >= 2
+        let mut sut = Problem::minimize(&[1]);
+        sut.add_constraint(&[-1], Relation::Le, -2);
+        assert_eq!(
+            sut.solve_integer(),
+            Outcome::Optimal {
+                value: q(2),
+                x: vec![2]
+            }
+        );
+    }
+
+    #[test]
+    fn solve_should_handle_redundant_equalities() {
+        // x + y = 2 twice, min x
+        let mut sut = Problem::minimize(&[1, 0]);
+        sut.add_constraint(&[1, 1], Relation::Eq, 2);
+        sut.add_constraint(&[2, 2], Relation::Eq, 4);
+        assert_eq!(
+            sut.solve_integer(),
+            Outcome::Optimal {
+                value: q(0),
+                x: vec![0, 2]
+            }
+        );
+    }
+
+    /// aoc 2025 day 10 part b examples: buttons as columns, minimal total presses
+    #[rstest]
+    #[case(vec![vec![0,0,0,0,1,1], vec![0,1,0,0,0,1], vec![0,0,1,1,1,0], vec![1,1,0,1,0,0]], vec![3,5,4,7], 10)]
+    #[case(vec![vec![1,0,1,1,0], vec![0,0,0,1,1], vec![1,1,0,1,1], vec![1,1,0,0,1], vec![1,0,1,0,1]], vec![7,5,12,7,2], 12)]
+    #[case(vec![vec![1,1,1,0], vec![1,0,1,1], vec![1,0,1,1], vec![1,1,0,0], vec![1,1,1,0], vec![0,0,1,0]], vec![10,11,11,5,10,5], 11)]
+    fn solve_integer_minimal_presses(
+        #[case] buttons: Vec<Vec<i64>>,
+        #[case] requirement: Vec<i64>,
+        #[case] expected: i128,
+    ) {
+        let mut sut = Problem::minimize(&vec![1i64; buttons[0].len()]);
+        for (row, &r) in buttons.iter().zip(&requirement) {
+            sut.add_constraint(row, Relation::Eq, r);
+        }
+
+        let Outcome::Optimal { value, x } = sut.solve_integer() else {
+            panic!("expected optimum");
+        };
+        assert_eq!(value, q(expected));
+        assert_eq!(x.iter().sum::<i128>(), expected);
+        for (row, &r) in buttons.iter().zip(&requirement) {
+            let lhs: i128 = row.iter().zip(&x).map(|(&a, &b)| a as i128 * b).sum();
+            assert_eq!(lhs, r as i128);
+        }
+    }
+}
@@ -0,0 +1,446 @@
+/*! Puzzle inputs at runtime
+ *
+ * The inputs differ by user and should not be part of the repository. They live in
+ * $AOC_INPUT_DIR/{year}/{day:02}.txt (e.g. 2024/07.txt) instead.
+ * With the feature fetch a missing input is downloaded once with the session cookie of
+ * $AOC_SESSION and cached there. Requests are spaced by at least MIN_INTERVAL,
+ * the puzzle author asks to throttle automated requests.
+ *
+ * ``` rust,no_run
+ * use aoc_utils::input::{Fetcher, InputDir};
+ *
+ * let inputs = InputDir::new("../inputs").with_fetcher(Fetcher::new("53616c7465645f5f"));
+ * let input = inputs.load(2024, 7).expect("input of 2024 day 7");
+ * ```
+ *
+ * The lib tests, bins and benches of the day crates read the input with workspace(), from
+ * $AOC_INPUT_DIR or else from src/input.txt of the day crate, which is not committed either.
+ * It panics without one, so the tests of the real input are ignored, --ignored runs them:
+ *
+ * ``` rust,no_run
+ * let input = aoc_utils::input::workspace(2024, 7);
+ * ```
+*/
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// value of the session cookie, new-aoc.ps1 reads it from aoc_session
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// only needed to point the fetcher to a stand-in server
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = concat!(
+    "aoc_utils/",
+    env!("CARGO_PKG_VERSION"),
+    " by gbegerow+aoc@gmail.com"
+);
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// the answer instead of an input if the session cookie is missing or expired
+const LOGIN_PAGE: &str = "Puzzle inputs differ by user";
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(PathBuf, io::Error),
+    /// not cached and nothing to fetch it with
+    Missing(PathBuf),
+    LoggedOut,
+    /// day is not unlocked yet or does not exist
+    NotFound(u16, u8),
+    Http(String),
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            InputError::Missing(path) => write!(
+                f,
+                "no input {}, set {SESSION_VAR} to fetch it",
+                path.display()
+            ),
+            InputError::LoggedOut => write!(f, "not logged in, renew the session in {SESSION_VAR}"),
+            InputError::NotFound(year, day) => write!(f, "no input for {year} day {day} yet"),
+            InputError::Http(e) => write!(f, "fetching input failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// time of the last request of this process, shared by every fetcher
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+fn throttle(interval: Duration) {
+    let mut last = LAST_REQUEST.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(wait) = last.map(|last| interval.saturating_sub(last.elapsed())) {
+        std::thread::sleep(wait);
+    }
+    *last = Some(Instant::now());
+}
+
+/// Downloads inputs with a session cookie
+#[derive(Clone, PartialEq, Eq)]
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    interval: Duration,
+}
+
+// the session is a secret, keep it out of logs
+impl std::fmt::Debug for Fetcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Fetcher")
+            .field("base_url", &self.base_url)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Fetcher {
+    pub fn new(session: &str) -> Self {
+        Self {
+            base_url: BASE_URL.to_string(),
+            session: session.to_string(),
+            interval: MIN_INTERVAL,
+        }
+    }
+
+    /// None without a session in AOC_SESSION (or aoc_session)
+    pub fn from_env() -> Option<Self> {
+        let session = std::env::var(SESSION_VAR)
+            .or_else(|_| std::env::var("aoc_session"))
+            .ok()
+            .filter(|s| !s.trim().is_empty())?;
+        let fetcher = Self::new(session.trim());
+        Some(match std::env::var(BASE_URL_VAR) {
+            Ok(url) => fetcher.with_base_url(&url),
+            Err(_) => fetcher,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+
+    pub fn page_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    #[cfg(feature = "fetch")]
+    fn get(&self, url: &str) -> Result<(u16, String), InputError> {
+        let http = |e: reqwest::Error| InputError::Http(e.to_string());
+        let response = reqwest::blocking::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .map_err(http)?
+            .get(url)
+            .header(reqwest::header::COOKIE, format!("session={}", self.session))
+            .send()
+            .map_err(http)?;
+        let status = response.status().as_u16();
+        Ok((status, response.text().map_err(http)?))
+    }
+
+    #[cfg(not(feature = "fetch"))]
+    fn get(&self, _url: &str) -> Result<(u16, String), InputError> {
+        Err(InputError::Http(
+            "aoc_utils built without feature fetch".to_string(),
+        ))
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, InputError> {
+        tracing::info!("fetching input of {year} day {day}");
+        self.request(&self.url(year, day), || InputError::NotFound(year, day))
+    }
+
+    /// puzzle description, part two is only part of it if the session solved part one
+    pub fn page(&self, year: u16, day: u8) -> Result<String, InputError> {
+        tracing::info!("fetching puzzle page of {year} day {day}");
+        self.request(&self.page_url(year, day), || {
+            InputError::NotFound(year, day)
+        })
+    }
+
+    /// any url with the session cookie, e.g. a stand-in of a puzzle page
+    pub fn fetch_url(&self, url: &str) -> Result<String, InputError> {
+        self.request(url, || InputError::Http(format!("{url} not found")))
+    }
+
+    fn request(
+        &self,
+        url: &str,
+        not_found: impl FnOnce() -> InputError,
+    ) -> Result<String, InputError> {
+        throttle(self.interval);
+        let (status, body) = self.get(url)?;
+        match status {
+            _ if body.starts_with(LOGIN_PAGE) => Err(InputError::LoggedOut),
+            200 => Ok(body),
+            404 => Err(not_found()),
+            _ => Err(InputError::Http(format!("status {status} {}", body.trim()))),
+        }
+    }
+}
+
+/// Cache of the inputs, fetches the missing ones if it has a fetcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDir {
+    dir: PathBuf,
+    fetcher: Option<Fetcher>,
+}
+
+impl InputDir {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher: None,
+        }
+    }
+
+    /// AOC_INPUT_DIR, fetching with AOC_SESSION if it is set
+    pub fn from_env() -> Option<Self> {
+        let dir = Self::new(std::env::var_os(INPUT_DIR_VAR)?);
+        Some(match Fetcher::from_env() {
+            Some(fetcher) => dir.with_fetcher(fetcher),
+            None => dir,
+        })
+    }
+
+    pub fn with_fetcher(mut self, fetcher: Fetcher) -> Self {
+        self.fetcher = Some(fetcher);
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn fetcher(&self) -> Option<&Fetcher> {
+        self.fetcher.as_ref()
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    /// cached input, a missing one is fetched and cached
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Ok(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let Some(fetcher) = &self.fetcher else {
+                    return Err(InputError::Missing(path));
+                };
+                let input = fetcher.fetch(year, day)?;
+                let dir = path.parent().expect("input has a year directory");
+                std::fs::create_dir_all(dir).map_err(|e| InputError::Io(dir.to_path_buf(), e))?;
+                std::fs::write(&path, &input).map_err(|e| InputError::Io(path, e))?;
+                Ok(input)
+            }
+            Err(e) => Err(InputError::Io(path, e)),
+        }
+    }
+}
+
+/// src/input.txt of the day crate below the workspace root
+pub fn workspace_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("aoc_{year}_{day:02}"))
+        .join("src")
+        .join("input.txt")
+}
+
+/// Input of a day for its lib tests, bins and benches, $AOC_INPUT_DIR/{year}/{day:02}.txt or
+/// else src/input.txt of the day crate. Nothing is fetched, panics without an input.
+#[track_caller]
+pub fn workspace(year: u16, day: u8) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc_utils lives in the workspace");
+    let paths = std::env::var_os(INPUT_DIR_VAR)
+        .map(|dir| InputDir::new(dir).path(year, day))
+        .into_iter()
+        .chain([workspace_path(root, year, day)])
+        .collect::<Vec<_>>();
+    read_first(&paths).unwrap_or_else(|| {
+        let paths = paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>();
+        panic!("no input for {year} day {day} in {}", paths.join(" or "))
+    })
+}
+
+/// first of the paths with an input, empty files are placeholders
+fn read_first(paths: &[PathBuf]) -> Option<String> {
+    paths.iter().find_map(|path| {
+        std::fs::read_to_string(path)
+            .ok()
+            .filter(|input| !input.trim().is_empty())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn path_should_pad_day() {
+        let sut = InputDir::new("inputs");
+        assert_eq!(sut.path(2024, 7), PathBuf::from("inputs/2024/07.txt"));
+    }
+
+    #[test]
+    fn workspace_path_should_point_into_day_crate() {
+        let sut = workspace_path(Path::new("aoc"), 2024, 7);
+        assert_eq!(sut, PathBuf::from("aoc/aoc_2024_07/src/input.txt"));
+    }
+
+    #[test]
+    fn read_first_should_skip_missing_and_empty() {
+        let dir = temp_dir("aoc_utils_read_first");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("empty.txt"), "\n").unwrap();
+        std::fs::write(dir.join("input.txt"), "42\n").unwrap();
+
+        let sut = read_first(&["missing.txt", "empty.txt", "input.txt"].map(|f| dir.join(f)));
+
+        assert_eq!(sut.as_deref(), Some("42\n"));
+        assert_eq!(read_first(&[dir.join("missing.txt")]), None);
+    }
+
+    #[test]
+    fn load_should_read_cache() {
+        let dir = temp_dir("aoc_utils_input_cache");
+        std::fs::create_dir_all(dir.join("2016")).unwrap();
+        std::fs::write(dir.join("2016").join("01.txt"), "R2, L3").unwrap();
+
+        let sut = InputDir::new(&dir);
+        assert_eq!(sut.load(2016, 1).unwrap(), "R2, L3");
+        assert!(matches!(sut.load(2016, 2), Err(InputError::Missing(_))));
+    }
+
+    #[test]
+    fn fetcher_should_hide_session() {
+        let sut = Fetcher::new("53616c7465645f5f");
+        assert!(!format!("{sut:?}").contains("5361"));
+        assert_eq!(
+            sut.url(2024, 7),
+            "https://adventofcode.com/2024/day/7/input"
+        );
+    }
+
+    #[cfg(feature = "fetch")]
+    mod fetch {
+        use super::*;
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            thread::{self, JoinHandle},
+        };
+
+        /// stand-in for adventofcode.com, answers one request per response
+        /// and returns the request heads it got
+        fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let server = thread::spawn(move || {
+                responses
+                    .into_iter()
+                    .map(|(status, body)| {
+                        let (mut stream, _) = listener.accept().unwrap();
+                        let mut head = String::new();
+                        let mut reader = BufReader::new(stream.try_clone().unwrap());
+                        while reader.read_line(&mut head).unwrap() > 2 {}
+                        write!(
+                            stream,
+                            "HTTP/1.1 {status} Whatever\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                            body.len()
+                        )
+                        .unwrap();
+                        head.to_lowercase()
+                    })
+                    .collect()
+            });
+            (url, server)
+        }
+
+        fn fetcher(url: &str) -> Fetcher {
+            Fetcher::new("abc")
+                .with_base_url(url)
+                .with_interval(Duration::ZERO)
+        }
+
+        #[test]
+        fn load_should_fetch_once() {
+            let (url, server) = serve(vec![(200, "3   4\n4   3\n")]);
+            let dir = temp_dir("aoc_utils_input_fetch");
+            let sut = InputDir::new(&dir).with_fetcher(fetcher(&url));
+
+            assert_eq!(sut.load(2024, 1).unwrap(), "3   4\n4   3\n");
+            assert_eq!(sut.load(2024, 1).unwrap(), "3   4\n4   3\n");
+
+            let heads = server.join().unwrap();
+            assert_eq!(heads.len(), 1);
+            assert!(heads[0].starts_with("get /2024/day/1/input "));
+            assert!(heads[0].contains("cookie: session=abc"));
+            assert!(heads[0].contains("user-agent: aoc_utils/"));
+            assert!(dir.join("2024").join("01.txt").exists());
+        }
+
+        #[test]
+        fn fetch_should_detect_login_page() {
+            let (url, _) = serve(vec![(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            )]);
+            let sut = fetcher(&url);
+            assert!(matches!(sut.fetch(2024, 1), Err(InputError::LoggedOut)));
+        }
+
+        #[test]
+        fn fetch_should_report_locked_day() {
+            let (url, _) = serve(vec![(
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            )]);
+            let sut = fetcher(&url);
+            assert!(matches!(
+                sut.fetch(2099, 1),
+                Err(InputError::NotFound(2099, 1))
+            ));
+        }
+
+        #[test]
+        fn fetch_should_space_requests() {
+            let (url, _) = serve(vec![(200, "1"), (200, "2")]);
+            let sut = fetcher(&url).with_interval(Duration::from_millis(200));
+
+            let start = Instant::now();
+            sut.fetch(2024, 1).unwrap();
+            sut.fetch(2024, 2).unwrap();
+            assert!(start.elapsed() >= Duration::from_millis(200));
+        }
+    }
+}
@@ -0,0 +1,275 @@
+/*! Answer ledger in aoc_data.json
+ *
+ * aoc_data.json started as list of titles and stars (scraped by get-aocTitles.ps1).
+ * Every entry may also hold the verified answers and a baseline time per part:
+ *
+ * {
+ *   "year": 2024, "day": 5, "title": "Print Queue", "a": "*", "b": "*",
+ *   "answer_a": "143", "answer_b": "123", "time_a_ns": 81234, "time_b_ns": 112345
+ * }
+ *
+ * The runner records answers (aoc run --record) and checks them (aoc verify).
+ * Lib tests of the days check their answers with assert_answer() instead of
+ * repeating them:
+ *
+ * ``` rust,no_run
+ * fn aoc_2024_05_a(input: &str) -> u32 { 143 }
+ * aoc_utils::ledger::assert_answer(2024, 5, 'a', aoc_2024_05_a(""));
+ * ```
+*/
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::IntoAnswer;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+    /// "*" if the star is earned
+    pub a: String,
+    pub b: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer_a: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer_b: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_a_ns: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_b_ns: Option<u64>,
+}
+
+impl Entry {
+    pub fn new(year: u16, day: u8, title: &str) -> Self {
+        Self {
+            year,
+            day,
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// part is 'a' or 'b'
+    pub fn answer(&self, part: char) -> Option<&str> {
+        match part {
+            'a' => self.answer_a.as_deref(),
+            'b' => self.answer_b.as_deref(),
+            _ => panic!("invalid part {part}"),
+        }
+    }
+
+    pub fn set_answer(&mut self, part: char, answer: &str) {
+        let answer = Some(answer.to_string());
+        match part {
+            'a' => self.answer_a = answer,
+            'b' => self.answer_b = answer,
+            _ => panic!("invalid part {part}"),
+        }
+    }
+
+    pub fn baseline(&self, part: char) -> Option<Duration> {
+        match part {
+            'a' => self.time_a_ns,
+            'b' => self.time_b_ns,
+            _ => panic!("invalid part {part}"),
+        }
+        .map(Duration::from_nanos)
+    }
+
+    pub fn set_baseline(&mut self, part: char, time: Duration) {
+        let nanos = Some(time.as_nanos() as u64);
+        match part {
+            'a' => self.time_a_ns = nanos,
+            'b' => self.time_b_ns = nanos,
+            _ => panic!("invalid part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ledger {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+    Io(PathBuf, std::io::Error),
+    Json(PathBuf, serde_json::Error),
+}
+
+impl std::fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LedgerError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            LedgerError::Json(path, e) => write!(f, "{}: invalid ledger {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+impl Ledger {
+    /// aoc_data.json in the root of this workspace
+    pub fn workspace_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc_utils lives in the workspace")
+            .join("aoc_data.json")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, LedgerError> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| LedgerError::Io(path.to_path_buf(), e))?;
+        json.parse()
+            .map_err(|e| LedgerError::Json(path.to_path_buf(), e))
+    }
+
+    /// same layout as get-aocTitles.ps1 writes, so diffs stay small
+    pub fn save(&self, path: &Path) -> Result<(), LedgerError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| LedgerError::Json(path.to_path_buf(), e))?;
+        std::fs::write(path, json).map_err(|e| LedgerError::Io(path.to_path_buf(), e))
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.year == year && e.day == day)
+    }
+
+    /// entry of the day, created (in order) if the ledger does not know it yet
+    pub fn entry(&mut self, year: u16, day: u8, title: &str) -> &mut Entry {
+        let index = match self
+            .entries
+            .binary_search_by_key(&(year, day), |e| (e.year, e.day))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.entries.insert(index, Entry::new(year, day, title));
+                index
+            }
+        };
+        let entry = &mut self.entries[index];
+        if entry.title.is_empty() {
+            entry.title = title.to_string();
+        }
+        entry
+    }
+
+    pub fn answer(&self, year: u16, day: u8, part: char) -> Option<&str> {
+        self.get(year, day).and_then(|e| e.answer(part))
+    }
+}
+
+impl FromStr for Ledger {
+    type Err = serde_json::Error;
+
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Ledger of the workspace, loaded once
+pub fn workspace() -> &'static Ledger {
+    static LEDGER: OnceLock<Ledger> = OnceLock::new();
+    LEDGER.get_or_init(|| Ledger::load(&Ledger::workspace_path()).unwrap_or_else(|e| panic!("{e}")))
+}
+
+/// Assert the answer of a lib test against the workspace ledger.
+/// Compares the same form aoc verify uses, so a Result of the solution is fine too.
+/// Panics if the ledger has no answer, the test can not pass without it.
+#[track_caller]
+pub fn assert_answer(year: u16, day: u8, part: char, actual: impl IntoAnswer) {
+    let expected = workspace()
+        .answer(year, day, part)
+        .unwrap_or_else(|| panic!("no answer for {year} {day:02} {part} in aoc_data.json"));
+    match actual.into_answer() {
+        Ok(actual) => assert_eq!(actual.to_string(), expected, "{year} {day:02} {part}"),
+        Err(e) => panic!("{year} {day:02} {part}: {}", e.render()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const LEDGER: &str = r#"[
+  {
+    "year": 2016,
+    "day": 1,
+    "title": "No Time for a Taxicab",
+    "a": "*",
+    "b": "",
+    "answer_a": "262",
+    "time_a_ns": 1500
+  },
+  {
+    "year": 2016,
+    "day": 3,
+    "title": "Squares With Three Sides",
+    "a": "",
+    "b": ""
+  }
+]"#;
+
+    #[test]
+    fn ledger_should_round_trip_layout() {
+        let sut = LEDGER.parse::<Ledger>().unwrap();
+        assert_eq!(serde_json::to_string_pretty(&sut).unwrap(), LEDGER);
+    }
+
+    #[rstest]
+    #[case(2016, 1, 'a', Some("262"))]
+    #[case(2016, 1, 'b', None)]
+    #[case(2016, 3, 'a', None)]
+    #[case(2016, 2, 'a', None)]
+    fn answer_should_find_part(
+        #[case] year: u16,
+        #[case] day: u8,
+        #[case] part: char,
+        #[case] expected: Option<&str>,
+    ) {
+        let sut = LEDGER.parse::<Ledger>().unwrap();
+        assert_eq!(sut.answer(year, day, part), expected);
+    }
+
+    #[test]
+    fn entry_should_insert_in_order() {
+        let mut sut = LEDGER.parse::<Ledger>().unwrap();
+        sut.entry(2016, 2, "Bathroom Security")
+            .set_answer('b', "46C92");
+        sut.entry(2016, 1, "")
+            .set_baseline('a', Duration::from_micros(3));
+
+        let days = sut.entries().iter().map(|e| e.day).collect::<Vec<_>>();
+        assert_eq!(days, vec![1, 2, 3]);
+        assert_eq!(sut.answer(2016, 2, 'b'), Some("46C92"));
+        assert_eq!(sut.get(2016, 1).unwrap().title, "No Time for a Taxicab");
+        assert_eq!(
+            sut.get(2016, 1).unwrap().baseline('a'),
+            Some(Duration::from_nanos(3000))
+        );
+    }
+
+    #[test]
+    fn workspace_ledger_should_load() {
+        assert!(!workspace().entries().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "2016 01 a")]
+    fn assert_answer_should_fail_on_wrong_answer() {
+        assert_answer(2016, 1, 'a', 0);
+    }
+}
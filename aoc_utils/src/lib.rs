@@ -1,3 +1,20 @@
+pub mod check;
+pub mod cycle;
+pub mod dp;
+pub mod dsu;
+pub mod error;
+pub mod graph;
 pub mod grid;
+pub mod heap;
+pub mod ilp;
+pub mod input;
 pub mod k_d_tree;
+pub mod ledger;
+pub mod linalg;
+pub mod ocr;
+pub mod solution;
+pub mod synth;
+pub mod telemetry;
 // pub mod number_parser;
+
+pub use solution::{Answer, Params, Solution};
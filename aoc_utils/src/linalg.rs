@@ -0,0 +1,394 @@
+/*! Exact linear algebra over rationals
+ *
+ * Gaussian elimination (Gauss-Jordan to reduced row echelon form) with exact
+ * rational arithmetic. No floating point, no rounding, no native libraries.
+ * Good enough for the small systems AoC throws at us (a handful of variables,
+ * coefficients well below i64).
+ *
+ * https://en.wikipedia.org/wiki/Gaussian_elimination
+ * https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form
+ *
+ * example:
+ * ``` rust
+ * use aoc_utils::linalg::{solve_integer, Solution};
+ * // 94a + 22b = 8400, 34a + 67b = 5400
+ * let solution = solve_integer(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+ * assert_eq!(solution.unique_integer(), Some(vec![80, 40]));
+ * ```
+*/
+use num::{One, Signed, Zero, rational::Ratio};
+
+/// Rational number with i128 numerator and denominator. Always kept reduced by num.
+pub type Rational = Ratio<i128>;
+
+/// Result of solving A x = b
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    /// exactly one solution
+    Unique(Vec<Rational>),
+    /// infinitely many solutions x = particular + sum(t_i * directions\[i\]) for any t_i.
+    /// `free[i]` is the index of the free variable belonging to `directions[i]`,
+    /// i.e. x\[free\[i\]\] == t_i
+    Parametric {
+        particular: Vec<Rational>,
+        directions: Vec<Vec<Rational>>,
+        free: Vec<usize>,
+    },
+    /// no solution at all
+    Inconsistent,
+}
+
+impl Solution {
+    /// the unique solution if it exists and consists of integers only
+    pub fn unique_integer(&self) -> Option<Vec<i128>> {
+        match self {
+            Solution::Unique(x) => x
+                .iter()
+                .map(|q| q.is_integer().then(|| q.to_integer()))
+                .collect(),
+            _ => None,
+        }
+    }
+
+    /// evaluate a parametric solution for concrete values of the free variables.
+    /// For a unique solution `params` is ignored. Inconsistent systems give None.
+    pub fn evaluate(&self, params: &[Rational]) -> Option<Vec<Rational>> {
+        match self {
+            Solution::Unique(x) => Some(x.clone()),
+            Solution::Parametric {
+                particular,
+                directions,
+                ..
+            } => {
+                assert_eq!(
+                    params.len(),
+                    directions.len(),
+                    "need one value per free variable"
+                );
+                let mut x = particular.clone();
+                for (t, dir) in params.iter().zip(directions) {
+                    for (xi, di) in x.iter_mut().zip(dir) {
+                        *xi += *t * *di;
+                    }
+                }
+                Some(x)
+            }
+            Solution::Inconsistent => None,
+        }
+    }
+}
+
+/// Dense matrix of rationals, row major.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Matrix {
+    values: Vec<Rational>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            values: vec![Rational::zero(); rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// build from rows of integers. All rows must have the same length.
+    pub fn from_rows<T>(rows: &[Vec<T>]) -> Self
+    where
+        T: Copy + Into<i128>,
+    {
+        let cols = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == cols),
+            "all rows must have the same length"
+        );
+        Self {
+            values: rows
+                .iter()
+                .flat_map(|r| r.iter().map(|&v| Rational::from_integer(v.into())))
+                .collect(),
+            rows: rows.len(),
+            cols,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Rational {
+        self.values[row * self.cols + col]
+    }
+
+    #[inline]
+    pub fn set(&mut self, row: usize, col: usize, value: Rational) {
+        self.values[row * self.cols + col] = value;
+    }
+
+    pub fn row(&self, row: usize) -> &[Rational] {
+        &self.values[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for c in 0..self.cols {
+            self.values.swap(a * self.cols + c, b * self.cols + c);
+        }
+    }
+
+    /// Matrix with column b appended: [A | b]
+    pub fn augment(&self, b: &[Rational]) -> Matrix {
+        assert_eq!(self.rows, b.len(), "right hand side must match row count");
+        let cols = self.cols + 1;
+        let mut values = Vec::with_capacity(self.rows * cols);
+        for (r, &b_r) in b.iter().enumerate() {
+            values.extend_from_slice(self.row(r));
+            values.push(b_r);
+        }
+        Matrix {
+            values,
+            rows: self.rows,
+            cols,
+        }
+    }
+
+    /// Gauss-Jordan step: scale `row` so that (row, col) becomes 1 and eliminate `col` in all other rows.
+    /// The entry at (row, col) must not be zero.
+    pub fn pivot(&mut self, row: usize, col: usize) {
+        let pivot = self.get(row, col);
+        debug_assert!(!pivot.is_zero(), "pivot at ({row}, {col}) is zero");
+
+        // normalize pivot row
+        for c in 0..self.cols {
+            let v = self.get(row, c) / pivot;
+            self.set(row, c, v);
+        }
+
+        // eliminate column in all other rows
+        for r in 0..self.rows {
+            if r == row {
+                continue;
+            }
+            let factor = self.get(r, col);
+            if factor.is_zero() {
+                continue;
+            }
+            for c in 0..self.cols {
+                let v = self.get(r, c) - factor * self.get(row, c);
+                self.set(r, c, v);
+            }
+        }
+    }
+
+    /// Transform in place to reduced row echelon form considering only the first `cols` columns
+    /// as pivot candidates (use `cols - 1` for an augmented matrix).
+    /// Returns the pivot column of every non zero row in order.
+    pub fn rref(&mut self, pivot_cols: usize) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        let mut row = 0;
+
+        for col in 0..pivot_cols.min(self.cols) {
+            if row >= self.rows {
+                break;
+            }
+
+            // any non zero pivot will do as we calculate exact, no need for partial pivoting
+            let Some(pivot_row) = (row..self.rows).find(|&r| !self.get(r, col).is_zero()) else {
+                continue;
+            };
+            self.swap_rows(row, pivot_row);
+            self.pivot(row, col);
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        pivots
+    }
+
+    /// rank of the matrix
+    pub fn rank(&self) -> usize {
+        self.clone().rref(self.cols).len()
+    }
+
+    /// Solve self * x = b exactly
+    pub fn solve(&self, b: &[Rational]) -> Solution {
+        let n = self.cols;
+        let mut aug = self.augment(b);
+        let pivots = aug.rref(n);
+
+        // a row 0 = c with c != 0 below the pivots means the system is inconsistent
+        if (pivots.len()..aug.rows).any(|r| !aug.get(r, n).is_zero()) {
+            return Solution::Inconsistent;
+        }
+
+        let mut particular = vec![Rational::zero(); n];
+        for (r, &c) in pivots.iter().enumerate() {
+            particular[c] = aug.get(r, n);
+        }
+
+        if pivots.len() == n {
+            return Solution::Unique(particular);
+        }
+
+        // every non pivot column is a free variable, its direction vector has 1 at the free
+        // variable and minus the coefficients of that column at the pivot variables
+        let free: Vec<usize> = (0..n).filter(|c| !pivots.contains(c)).collect();
+        let directions = free
+            .iter()
+            .map(|&f| {
+                let mut dir = vec![Rational::zero(); n];
+                dir[f] = Rational::one();
+                for (r, &c) in pivots.iter().enumerate() {
+                    dir[c] = -aug.get(r, f);
+                }
+                dir
+            })
+            .collect();
+
+        Solution::Parametric {
+            particular,
+            directions,
+            free,
+        }
+    }
+}
+
+impl std::fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Matrix[{}x{}]", self.rows, self.cols)?;
+        for r in 0..self.rows {
+            let row = self
+                .row(r)
+                .iter()
+                .map(|q| format!("{q:>5}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(f, "[{row}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Solve A x = b for integer coefficients. A is given as rows.
+pub fn solve_integer<T>(a: &[Vec<T>], b: &[T]) -> Solution
+where
+    T: Copy + Into<i128>,
+{
+    let b: Vec<Rational> = b
+        .iter()
+        .map(|&v| Rational::from_integer(v.into()))
+        .collect();
+    Matrix::from_rows(a).solve(&b)
+}
+
+/// true if q is an integer >= 0
+#[inline]
+pub fn is_natural(q: &Rational) -> bool {
+    q.is_integer() && !q.is_negative()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn q(n: i128) -> Rational {
+        Rational::from_integer(n)
+    }
+
+    #[rstest]
+    #[case(vec![vec![94, 22], vec![34, 67]], vec![8400, 5400], Some(vec![80, 40]))]
+    #[case(vec![vec![17, 84], vec![86, 37]], vec![7870, 6450], Some(vec![38, 86]))]
+    #[case(vec![vec![1, 2], vec![4, 5]], vec![3, 6], Some(vec![-1, 2]))]
+    // unique but not integer
+    #[case(vec![vec![26, 67], vec![66, 21]], vec![12748, 12176], None)]
+    fn solve_integer_should(
+        #[case] a: Vec<Vec<i64>>,
+        #[case] b: Vec<i64>,
+        #[case] expected: Option<Vec<i128>>,
+    ) {
+        assert_eq!(solve_integer(&a, &b).unique_integer(), expected);
+    }
+
+    #[test]
+    fn solve_should_find_fraction() {
+        // 2x = 1
+        assert_eq!(
+            solve_integer(&[vec![2]], &[1]),
+            Solution::Unique(vec![Rational::new(1, 2)])
+        );
+    }
+
+    #[test]
+    fn solve_should_detect_inconsistent() {
+        // x + y = 1, 2x + 2y = 3
+        assert_eq!(
+            solve_integer(&[vec![1, 1], vec![2, 2]], &[1, 3]),
+            Solution::Inconsistent
+        );
+    }
+
+    #[test]
+    fn solve_should_handle_overdetermined_consistent() {
+        // x = 1, y = 2, x + y = 3
+        assert_eq!(
+            solve_integer(&[vec![1, 0], vec![0, 1], vec![1, 1]], &[1, 2, 3]),
+            Solution::Unique(vec![q(1), q(2)])
+        );
+    }
+
+    #[test]
+    fn solve_should_give_parametric_form() {
+        // x + y + z = 6, y - z = 1  => free z
+        let sut = solve_integer(&[vec![1, 1, 1], vec![0, 1, -1]], &[6, 1]);
+        let Solution::Parametric {
+            particular,
+            directions,
+            free,
+        } = &sut
+        else {
+            panic!("expected parametric solution, got {sut:?}");
+        };
+        assert_eq!(free, &vec![2]);
+        assert_eq!(particular, &vec![q(5), q(1), q(0)]);
+        assert_eq!(directions, &vec![vec![q(-2), q(1), q(1)]]);
+
+        // every choice of the free variable solves the system
+        for t in -3..=3 {
+            let x = sut.evaluate(&[q(t)]).unwrap();
+            assert_eq!(x[0] + x[1] + x[2], q(6));
+            assert_eq!(x[1] - x[2], q(1));
+        }
+    }
+
+    #[test]
+    fn rank_should() {
+        let m = Matrix::from_rows(&[vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+        assert_eq!(m.rank(), 2);
+    }
+
+    #[test]
+    fn solve_aoc_2025_10_example() {
+        // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}, buttons as columns
+        let a = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        let sut = solve_integer(&a, &[3, 5, 4, 7]);
+        let Solution::Parametric { free, .. } = &sut else {
+            panic!("expected parametric solution, got {sut:?}");
+        };
+        assert_eq!(free.len(), 2);
+
+        // known solution from puzzle text: (3) once, (1,3) three times, (2,3) three times,
+        // (0,2) once, (0,1) twice. Fixing the free variables to it must give the rest.
+        let known = vec![q(1), q(3), q(0), q(3), q(1), q(2)];
+        let params: Vec<_> = free.iter().map(|&f| known[f]).collect();
+        assert_eq!(sut.evaluate(&params).unwrap(), known);
+    }
+}
@@ -0,0 +1,334 @@
+/*! Read the letters of pixel-art answers
+ *
+ * Some puzzles draw the answer on a screen (2016 08, 2018 10, 2019 08, 2021 13, 2022 10).
+ * AoC uses two fonts for that: 4 pixels wide and 6 high (Y is 5 wide) with a blank column
+ * between the letters, and 6x10 with two blank columns (2018 day 10).
+ *
+ * read() takes anything implementing Pixels: a Grid<bool>, a Framebuffer over the bytes of
+ * a screen or the text of an Answer::Ocr via read_str(). The lit pixels are cut into glyphs
+ * at blank columns, the height of the lit area chooses the font. Letters must not touch.
+ *
+ * ``` rust
+ * let screen = "\
+ * .####..##..###...##.
+ * ....#.#..#.#..#.#..#
+ * ...#..#....###..#..#
+ * ..#...#....#..#.####
+ * .#....#..#.#..#.#..#
+ * .####..##..###..#..#";
+ * assert_eq!(aoc_utils::ocr::read_str(screen).unwrap(), "ZCBA");
+ * ```
+*/
+use std::fmt::Display;
+
+use glam::IVec2;
+
+use crate::{error::AocError, grid::Grid};
+
+/// A screen of pixels which are on or off
+pub trait Pixels {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn lit(&self, x: usize, y: usize) -> bool;
+}
+
+impl Pixels for Grid<bool> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn lit(&self, x: usize, y: usize) -> bool {
+        self.get(IVec2::new(x as i32, y as i32))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Row major bytes of a screen, every byte but b'.', b' ' and 0 is lit
+#[derive(Debug, Clone, Copy)]
+pub struct Framebuffer<'a> {
+    bytes: &'a [u8],
+    width: usize,
+}
+
+impl<'a> Framebuffer<'a> {
+    pub fn new(bytes: &'a [u8], width: usize) -> Self {
+        Self { bytes, width }
+    }
+}
+
+impl Pixels for Framebuffer<'_> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.bytes.len().div_ceil(self.width.max(1))
+    }
+
+    fn lit(&self, x: usize, y: usize) -> bool {
+        x < self.width
+            && self
+                .bytes
+                .get(y * self.width + x)
+                .is_some_and(|b| !matches!(b, b'.' | b' ' | 0))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// not a single lit pixel
+    Empty,
+    /// lit area is neither 6 nor 10 pixels high
+    Height(usize),
+    /// glyphs which are no letter of the font, text has a ? for each of them
+    Unknown { text: String, columns: Vec<usize> },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "screen is dark"),
+            OcrError::Height(height) => {
+                write!(f, "letters are {height} pixels high, known are 6 and 10")
+            }
+            OcrError::Unknown { text, columns } => {
+                write!(f, "unknown glyphs in {text} at columns {columns:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+impl From<OcrError> for AocError {
+    fn from(e: OcrError) -> Self {
+        AocError::no_answer(e)
+    }
+}
+
+impl From<OcrError> for crate::solution::Error {
+    fn from(e: OcrError) -> Self {
+        AocError::from(e).into()
+    }
+}
+
+const FONT_6: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const FONT_10: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Letters of the screen
+pub fn read(pixels: &impl Pixels) -> Result<String, OcrError> {
+    let lit_rows = (0..pixels.height())
+        .filter(|&y| (0..pixels.width()).any(|x| pixels.lit(x, y)))
+        .collect::<Vec<_>>();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(OcrError::Empty);
+    };
+    let font = match bottom - top + 1 {
+        6 => FONT_6,
+        10 => FONT_10,
+        height => return Err(OcrError::Height(height)),
+    };
+
+    let column = |x: usize| (top..=bottom).map(move |y| pixels.lit(x, y));
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    let mut x = 0;
+    while x < pixels.width() {
+        if !column(x).any(|lit| lit) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < pixels.width() && column(x).any(|lit| lit) {
+            x += 1;
+        }
+        let glyph = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if pixels.lit(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        match font.iter().find(|(_, g)| *g == glyph) {
+            Some(&(letter, _)) => text.push(letter),
+            None => {
+                text.push('?');
+                unknown.push(start);
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unknown {
+            text,
+            columns: unknown,
+        })
+    }
+}
+
+/// Letters of a screen as text, one line per row. '#' and '█' are lit.
+pub fn read_str(screen: &str) -> Result<String, OcrError> {
+    let rows = screen.lines().collect::<Vec<_>>();
+    let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+    let values = rows
+        .iter()
+        .flat_map(|r| {
+            r.chars()
+                .map(|c| c == '#' || c == '█')
+                .chain(std::iter::repeat(false))
+                .take(width)
+        })
+        .collect::<Vec<_>>();
+    read(&Grid::new(values, width, rows.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const ZCBAJFJZ: &str = "\
+####..##..###...##....##.####...##.####.
+...#.#..#.#..#.#..#....#.#.......#....#.
+..#..#....###..#..#....#.###.....#...#..
+.#...#....#..#.####....#.#.......#..#...
+#....#..#.#..#.#..#.#..#.#....#..#.#....
+####..##..###..#..#..##..#.....##..####.";
+
+    const NX: &str = "\
+.................
+.#....#..#....#..
+.##...#..#....#..
+.##...#...#..#...
+.#.#..#...#..#...
+.#.#..#....##....
+.#..#.#....##....
+.#..#.#...#..#...
+.#...##...#..#...
+.#...##..#....#..
+.#....#..#....#..
+.................";
+
+    #[rstest]
+    #[case(ZCBAJFJZ, Ok("ZCBAJFJZ".to_string()))]
+    #[case(NX, Ok("NX".to_string()))]
+    #[case("#...#.####\n#...#.#...\n.#.#..###.\n..#...#...\n..#...#...\n..#...####", Ok("YE".to_string()))]
+    #[case("....\n....", Err(OcrError::Empty))]
+    #[case("#\n#\n#", Err(OcrError::Height(3)))]
+    fn read_str_should_find_letters(
+        #[case] screen: &str,
+        #[case] expected: Result<String, OcrError>,
+    ) {
+        assert_eq!(read_str(screen), expected);
+    }
+
+    #[test]
+    fn read_should_report_unknown_columns() {
+        let sut = read_str("#...#.###\n#...#.#..\n.#.#..###\n..#....#.\n..#....#.\n..#...###");
+        assert_eq!(
+            sut,
+            Err(OcrError::Unknown {
+                text: "Y?".to_string(),
+                columns: vec![6]
+            })
+        );
+    }
+
+    #[test]
+    fn read_should_work_on_framebuffer_and_grid() {
+        let bytes = ZCBAJFJZ.replace('\n', "").into_bytes();
+        assert_eq!(read(&Framebuffer::new(&bytes, 40)).unwrap(), "ZCBAJFJZ");
+
+        let grid = Grid::new(bytes.iter().map(|&b| b == b'#').collect(), 40, 6);
+        assert_eq!(read(&grid).unwrap(), "ZCBAJFJZ");
+    }
+}
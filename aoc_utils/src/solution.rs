@@ -0,0 +1,546 @@
+/*! One interface for all days
+ *
+ * Every day crate exports its own free functions, mostly `aoc_yyyy_dd_a(&str) -> usize`.
+ * Some need extra arguments (a row, the size of the grid) which differ between example
+ * and real input, some return a String, a Result or draw letters on a screen.
+ *
+ * The solution! macro wraps those functions into a `Puzzle` struct implementing Solution.
+ * Plain functions are given by path, everything else as a closure of (input, params).
+ * Parts without a solution are left out and answer Error::NotSolved.
+ * Extra arguments come from Params, with the values of the real input as default.
+ * A part may fail with a String or an error::AocError, the latter becomes Error::Input and
+ * keeps the offending input for the runner.
+ *
+ * A day may name a generator of synthetic inputs, `generate: fn(seed, scale) -> String` or
+ * a closure returning the input with the Params it needs. Runners use it to test without
+ * the personal input and to time growing inputs.
+ *
+ * registry! collects the Puzzles of a list of day crates, so a runner can enumerate them
+ * without matching on year and day by hand.
+ *
+ * example:
+ * ``` rust
+ * mod aoc_2022_15 {
+ *     pub fn aoc_2022_15_a(input: &str, sensor_row: i64) -> usize {
+ *         input.len() + sensor_row as usize
+ *     }
+ *     pub fn aoc_2022_15_b(input: &str) -> Result<String, String> {
+ *         Ok(input.to_uppercase())
+ *     }
+ *     aoc_utils::solution! {
+ *         year: 2022,
+ *         day: 15,
+ *         title: "Beacon Exclusion Zone",
+ *         a: |input, params| aoc_2022_15_a(input, params.get_or("row", 2_000_000)?),
+ *         b: aoc_2022_15_b,
+ *     }
+ * }
+ * use aoc_utils::{Answer, Params};
+ * let registry = aoc_utils::registry![aoc_2022_15];
+ * let day = registry.get(2022, 15).unwrap();
+ * assert_eq!(day.part_a("abc", &Params::new()), Ok(Answer::Int(2_000_003)));
+ * assert_eq!(day.part_a("abc", &"row=10".parse().unwrap()), Ok(Answer::Int(13)));
+ * assert_eq!(day.part_b("abc", &Params::new()).unwrap().to_string(), "ABC");
+ * ```
+*/
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use crate::error::AocError;
+
+/// A day of Advent of Code with both parts
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+
+    fn part_a(&self, _input: &str, _params: &Params) -> Result<Answer, Error> {
+        Err(Error::NotSolved)
+    }
+
+    fn part_b(&self, _input: &str, _params: &Params) -> Result<Answer, Error> {
+        Err(Error::NotSolved)
+    }
+
+    /// synthetic input of size scale, the same seed gives the same input. None without generator.
+    fn generate(&self, _seed: u64, _scale: usize) -> Option<Generated> {
+        None
+    }
+}
+
+impl Debug for dyn Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {:02}: {}", self.year(), self.day(), self.title())
+    }
+}
+
+/// What the puzzle asks for
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// letters drawn on a screen, one line per row of pixels
+    Ocr(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Ocr(pixels) => write!(f, "{pixels}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// the part has no solution (yet)
+    NotSolved,
+    /// a parameter could not be parsed into the type the solution needs
+    Param { key: String, value: String },
+    /// the solution gave up, e.g. invalid input
+    Failed(String),
+    /// the solution returned an AocError, render() shows the offending input
+    Input(Box<AocError>),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotSolved => write!(f, "not solved"),
+            Error::Param { key, value } => write!(f, "invalid parameter {key}={value}"),
+            Error::Failed(reason) => write!(f, "failed: {reason}"),
+            Error::Input(e) => write!(f, "failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    /// like Display, an AocError as miette diagnostic with the offending input
+    pub fn render(&self) -> String {
+        match self {
+            Error::Input(e) => e.render(),
+            e => e.to_string(),
+        }
+    }
+}
+
+impl From<AocError> for Error {
+    fn from(e: AocError) -> Self {
+        Error::Input(Box::new(e))
+    }
+}
+
+impl From<String> for Error {
+    fn from(reason: String) -> Self {
+        Error::Failed(reason)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(reason: &str) -> Self {
+        Error::Failed(reason.to_string())
+    }
+}
+
+/// Named extra arguments of a solution, e.g. the row in 2022 day 15
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set(&mut self, key: &str, value: impl ToString) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// add the values of other, they win over the own ones
+    pub fn extend(&mut self, other: &Params) {
+        self.values.extend(other.values.clone());
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, Error> {
+        self.values
+            .get(key)
+            .map(|value| {
+                value.parse().map_err(|_| Error::Param {
+                    key: key.to_string(),
+                    value: value.clone(),
+                })
+            })
+            .transpose()
+    }
+
+    /// value of key or default if it is not given
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> Result<T, Error> {
+        Ok(self.get(key)?.unwrap_or(default))
+    }
+}
+
+impl FromStr for Params {
+    type Err = Error;
+
+    /// comma separated key=value pairs: "row=10,max=20"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::new();
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').ok_or_else(|| Error::Param {
+                key: pair.to_string(),
+                value: String::new(),
+            })?;
+            params.set(key.trim(), value.trim());
+        }
+        Ok(params)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pairs = self
+            .values
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", pairs.join(","))
+    }
+}
+
+/// A synthetic input with the params the solution needs for it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub params: Params,
+}
+
+impl From<String> for Generated {
+    fn from(input: String) -> Self {
+        Self {
+            input,
+            params: Params::new(),
+        }
+    }
+}
+
+impl From<(String, Params)> for Generated {
+    fn from((input, params): (String, Params)) -> Self {
+        Self { input, params }
+    }
+}
+
+/// Return types of the part functions
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, Error>;
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<Answer, Error> {
+                Ok(Answer::Int(self as i128))
+            }
+        })*
+    };
+}
+
+int_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Result<Answer, Error> {
+        Ok(self)
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Result<Answer, Error> {
+        Ok(Answer::Text(self))
+    }
+}
+
+impl IntoAnswer for &str {
+    fn into_answer(self) -> Result<Answer, Error> {
+        Ok(Answer::Text(self.to_string()))
+    }
+}
+
+/// errors of the parts: AocError, String or the Error of Params::get
+impl<T: IntoAnswer, E: Into<Error>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, Error> {
+        self.map_err(Into::into)?.into_answer()
+    }
+}
+
+/// All registered days, ordered by year and day
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    solutions: Vec<&'static dyn Solution>,
+}
+
+impl Registry {
+    pub fn new(mut solutions: Vec<&'static dyn Solution>) -> Self {
+        solutions.sort_by_key(|s| (s.year(), s.day()));
+        Self { solutions }
+    }
+
+    /// this and the days of other, e.g. days behind a feature
+    pub fn with(self, other: Registry) -> Self {
+        Self::new([self.solutions, other.solutions].concat())
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.solutions.iter().copied()
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&'static dyn Solution> {
+        self.iter().find(|s| s.year() == year && s.day() == day)
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.iter().filter(move |s| s.year() == year)
+    }
+
+    /// years with at least one registered day, ascending
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self.iter().map(|s| s.year()).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
+
+/// Implement Solution for a day crate as `Puzzle`.
+/// Parts are given as path of a fn(&str) or as closure |input, params| with a body
+/// that may use ? on Params::get. generate as path of a fn(u64, usize) -> String or as
+/// closure |seed, scale| returning the input or (input, Params).
+#[macro_export]
+macro_rules! solution {
+    (year: $year:literal, day: $day:literal, title: $title:literal $(, $($parts:tt)*)?) => {
+        /// Solution of this day for runners, see aoc_utils::solution
+        pub struct Puzzle;
+
+        impl $crate::Solution for Puzzle {
+            fn year(&self) -> u16 {
+                $year
+            }
+
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn title(&self) -> &'static str {
+                $title
+            }
+
+            $crate::solution!(@parts $($($parts)*)?);
+        }
+    };
+
+    (@parts) => {};
+    (@parts a: |$input:pat_param, $params:pat_param| $body:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@part part_a, $input, $params, $body);
+        $crate::solution!(@parts $($($rest)*)?);
+    };
+    (@parts a: $f:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@part part_a, input, _, $f(input));
+        $crate::solution!(@parts $($($rest)*)?);
+    };
+    (@parts b: |$input:pat_param, $params:pat_param| $body:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@part part_b, $input, $params, $body);
+        $crate::solution!(@parts $($($rest)*)?);
+    };
+    (@parts b: $f:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@part part_b, input, _, $f(input));
+        $crate::solution!(@parts $($($rest)*)?);
+    };
+    (@parts generate: |$seed:pat_param, $scale:pat_param| $body:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@generate $seed, $scale, $body);
+        $crate::solution!(@parts $($($rest)*)?);
+    };
+    (@parts generate: $f:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@generate seed, scale, $f(seed, scale));
+        $crate::solution!(@parts $($($rest)*)?);
+    };
+
+    (@part $name:ident, $input:pat_param, $params:pat_param, $body:expr) => {
+        fn $name(
+            &self,
+            $input: &str,
+            $params: &$crate::Params,
+        ) -> ::core::result::Result<$crate::Answer, $crate::solution::Error> {
+            $crate::solution::IntoAnswer::into_answer($body)
+        }
+    };
+
+    (@generate $seed:pat_param, $scale:pat_param, $body:expr) => {
+        fn generate(
+            &self,
+            $seed: u64,
+            $scale: usize,
+        ) -> ::core::option::Option<$crate::solution::Generated> {
+            ::core::option::Option::Some($crate::solution::Generated::from($body))
+        }
+    };
+}
+
+/// Registry of the Puzzles of the given day crates
+#[macro_export]
+macro_rules! registry {
+    ($($day:ident),* $(,)?) => {
+        $crate::solution::Registry::new(vec![
+            $(&$day::Puzzle as &'static dyn $crate::Solution),*
+        ])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    mod aoc_9999_98 {
+        pub fn aoc_9999_98_a(input: &str) -> usize {
+            input.len()
+        }
+
+        pub fn generate(seed: u64, scale: usize) -> String {
+            format!("{seed}").repeat(scale)
+        }
+
+        crate::solution! {
+            year: 9999,
+            day: 98,
+            title: "Only A",
+            a: aoc_9999_98_a,
+            generate: generate,
+        }
+    }
+
+    mod aoc_9999_99 {
+        pub fn aoc_9999_99_b(input: &str, factor: i64) -> Result<i64, String> {
+            input
+                .trim()
+                .parse::<i64>()
+                .map(|n| n * factor)
+                .map_err(|e| e.to_string())
+        }
+
+        crate::solution! {
+            year: 9999,
+            day: 99,
+            title: "Params",
+            a: |_, _| "constant",
+            b: |input, params| aoc_9999_99_b(input, params.get_or("factor", 2)?),
+            generate: |_, scale| (scale.to_string(), crate::Params::new().with("factor", 3)),
+        }
+    }
+
+    #[test]
+    fn registry_should_find_days_ordered() {
+        let sut = crate::registry![aoc_9999_99, aoc_9999_98];
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.years(), vec![9999]);
+        assert_eq!(
+            sut.iter().map(|s| s.day()).collect::<Vec<_>>(),
+            vec![98, 99]
+        );
+        assert_eq!(sut.get(9999, 99).unwrap().title(), "Params");
+        assert!(sut.get(9999, 1).is_none());
+    }
+
+    #[test]
+    fn registry_with_should_keep_order() {
+        let sut = crate::registry![aoc_9999_99].with(crate::registry![aoc_9999_98]);
+        assert_eq!(
+            sut.iter().map(|s| s.day()).collect::<Vec<_>>(),
+            vec![98, 99]
+        );
+    }
+
+    #[rstest]
+    #[case("", Ok(Answer::Int(42)))]
+    #[case("factor=3", Ok(Answer::Int(63)))]
+    #[case("factor=x", Err(Error::Param { key: "factor".into(), value: "x".into() }))]
+    fn part_should_use_params(#[case] params: &str, #[case] expected: Result<Answer, Error>) {
+        let sut = crate::registry![aoc_9999_99];
+        let day = sut.get(9999, 99).unwrap();
+        assert_eq!(day.part_b("21", &params.parse().unwrap()), expected);
+    }
+
+    #[test]
+    fn part_should_report_errors() {
+        let sut = crate::registry![aoc_9999_98, aoc_9999_99];
+        let params = Params::new();
+        assert_eq!(
+            sut.get(9999, 98).unwrap().part_b("", &params),
+            Err(Error::NotSolved)
+        );
+        assert!(matches!(
+            sut.get(9999, 99).unwrap().part_b("x", &params),
+            Err(Error::Failed(_))
+        ));
+        assert_eq!(
+            sut.get(9999, 99).unwrap().part_a("", &params),
+            Ok(Answer::Text("constant".into()))
+        );
+    }
+
+    #[test]
+    fn into_answer_should_keep_aoc_error() {
+        let sut: Result<usize, AocError> = Err(AocError::parse_line("1\nx\n", 1, "not a number"));
+
+        let answer = sut.into_answer();
+
+        assert!(
+            matches!(&answer, Err(Error::Input(e)) if matches!(**e, AocError::Parse { line: 2, .. }))
+        );
+        assert!(answer.unwrap_err().render().contains("not a number"));
+    }
+
+    #[test]
+    fn generate_should_give_input_and_params() {
+        let sut = crate::registry![aoc_9999_98, aoc_9999_99];
+
+        let only_a = sut.get(9999, 98).unwrap().generate(7, 3).unwrap();
+        assert_eq!(only_a, Generated::from("777".to_string()));
+
+        let with_params = sut.get(9999, 99).unwrap().generate(0, 14).unwrap();
+        let day = sut.get(9999, 99).unwrap();
+        assert_eq!(
+            day.part_b(&with_params.input, &with_params.params),
+            Ok(Answer::Int(42))
+        );
+    }
+
+    #[test]
+    fn params_should_round_trip() {
+        let sut = "row=10, max = 20".parse::<Params>().unwrap();
+        assert_eq!(sut, Params::new().with("max", 20).with("row", 10));
+        assert_eq!(sut.to_string(), "max=20,row=10");
+        assert!("row".parse::<Params>().is_err());
+    }
+}
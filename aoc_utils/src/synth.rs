@@ -0,0 +1,120 @@
+/*! Building blocks for synthetic puzzle inputs
+ *
+ * The generators of the days (Solution::generate) build inputs of a given scale from a seed.
+ * Mazes show up in many puzzles, maze() carves a perfect maze (exactly one path between
+ * two cells) by randomized depth first search, open_walls() adds loops to it.
+ *
+ * Cells are at even coordinates, the tiles between them are walls or passages. A maze of
+ * 36 x 36 cells has 71 x 71 tiles, like the memory space of 2024 day 18.
+ *
+ * ``` rust
+ * use aoc_utils::{check::Rng, synth::maze};
+ *
+ * let sut = maze(&mut Rng::new(42), 3, 2);
+ * assert_eq!((sut.width, sut.height), (5, 3));
+ * // 6 cells and the 5 passages of a spanning tree
+ * assert_eq!(sut.iter().filter(|open| **open).count(), 11);
+ * ```
+*/
+use glam::IVec2;
+
+use crate::{check::Rng, grid::Grid};
+
+const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y];
+
+/// Perfect maze of width x height cells on (2 width - 1) x (2 height - 1) tiles, true is open
+pub fn maze(rng: &mut Rng, width: usize, height: usize) -> Grid<bool> {
+    assert!(width > 0 && height > 0, "a maze needs at least one cell");
+    let (w, h) = (2 * width - 1, 2 * height - 1);
+    let mut grid = Grid::new(vec![false; w * h], w, h);
+
+    grid[IVec2::ZERO] = true;
+    let mut stack = vec![IVec2::ZERO];
+    while let Some(&cell) = stack.last() {
+        let unvisited = DIRECTIONS
+            .iter()
+            .map(|d| cell + 2 * *d)
+            .filter(|next| grid.get(*next) == Some(&false))
+            .collect::<Vec<_>>();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = *rng.pick(&unvisited);
+        grid[(cell + next) / 2] = true;
+        grid[next] = true;
+        stack.push(next);
+    }
+    grid
+}
+
+/// open count walls between two cells, every opened wall makes a loop
+pub fn open_walls(rng: &mut Rng, grid: &mut Grid<bool>, count: usize) {
+    // walls between cells have one odd and one even coordinate
+    let mut walls = grid
+        .iter_with_positions()
+        .filter(|(pos, open)| !**open && (pos.x + pos.y) % 2 == 1)
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut walls);
+    for wall in walls.into_iter().take(count) {
+        grid[wall] = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// open tiles reachable from the top left corner
+    fn reachable(grid: &Grid<bool>) -> usize {
+        let mut seen = vec![IVec2::ZERO];
+        let mut todo = vec![IVec2::ZERO];
+        while let Some(pos) = todo.pop() {
+            for (next, open) in grid.iter_axis_neighbours_with_positions(pos) {
+                if *open && !seen.contains(&next) {
+                    seen.push(next);
+                    todo.push(next);
+                }
+            }
+        }
+        seen.len()
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(5, 3)]
+    #[case(12, 12)]
+    fn maze_should_connect_every_cell_without_loops(#[case] width: usize, #[case] height: usize) {
+        let sut = maze(&mut Rng::new(width as u64), width, height);
+
+        let open = sut.iter().filter(|open| **open).count();
+        // a spanning tree of the cells has one passage less than cells
+        assert_eq!(open, 2 * width * height - 1);
+        assert_eq!(reachable(&sut), open);
+    }
+
+    #[test]
+    fn maze_should_repeat_for_same_seed() {
+        let a = maze(&mut Rng::new(7), 8, 8);
+        let b = maze(&mut Rng::new(7), 8, 8);
+        assert_eq!(a.iter().collect::<Vec<_>>(), b.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn open_walls_should_only_open_walls() {
+        let mut rng = Rng::new(3);
+        let mut sut = maze(&mut rng, 6, 6);
+
+        open_walls(&mut rng, &mut sut, 5);
+
+        assert_eq!(sut.iter().filter(|open| **open).count(), 2 * 36 - 1 + 5);
+        // corners between walls stay closed
+        assert!(
+            sut.iter_with_positions()
+                .all(|(pos, open)| { !(*open && pos.x % 2 == 1 && pos.y % 2 == 1) })
+        );
+    }
+}
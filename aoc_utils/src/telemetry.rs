@@ -0,0 +1,516 @@
+/*! Tracing setup shared by the day binaries and the runner
+ *
+ * The output is chosen by $AOC_TRACE (or --trace of the runner):
+ *
+ * fmt                  log lines on stderr, the default
+ * json                 one JSON object per line
+ * chrome[:<file>]      Chrome trace events, open the file in https://ui.perfetto.dev
+ *                      or chrome://tracing. Default file is trace-<timestamp>.json
+ * tracy                Tracy profiler, needs the feature tracy
+ * off                  nothing
+ *
+ * Which spans and events are recorded is up to $RUST_LOG as before, chrome and tracy record
+ * everything at info level without it. #[tracing::instrument] records every argument, so
+ * long ones (the puzzle input) are shortened to their length by default.
+ * When the guard is dropped a summary of the time spent per span goes to stderr.
+ *
+ * ``` rust,no_run
+ * // first thing in main, the summary is printed when _telemetry goes out of scope
+ * let _telemetry = aoc_utils::telemetry::init();
+ * ```
+*/
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write as _},
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use tracing::{
+    Subscriber,
+    field::{Field, Visit},
+    span,
+};
+use tracing_subscriber::{
+    EnvFilter, Layer, Registry,
+    field::RecordFields,
+    fmt::{FormatFields, FormattedFields, format::Writer},
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    util::SubscriberInitExt,
+};
+
+pub const TRACE_VAR: &str = "AOC_TRACE";
+/// longer field values are replaced by their length
+pub const MAX_FIELD_LEN: usize = 64;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Output {
+    #[default]
+    Fmt,
+    Json,
+    /// None writes trace-<timestamp>.json
+    Chrome(Option<PathBuf>),
+    Tracy,
+    Off,
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("chrome", file)) if !file.is_empty() => Ok(Output::Chrome(Some(file.into()))),
+            _ => match s {
+                "fmt" => Ok(Output::Fmt),
+                "json" => Ok(Output::Json),
+                "chrome" => Ok(Output::Chrome(None)),
+                "tracy" => Ok(Output::Tracy),
+                "off" => Ok(Output::Off),
+                _ => Err(format!(
+                    "invalid trace output {s}, expected fmt, json, chrome[:<file>], tracy or off"
+                )),
+            },
+        }
+    }
+}
+
+/// Keeps the outputs alive, prints the span summary and flushes the trace file on drop
+#[must_use = "the summary is printed and the trace is flushed when the guard is dropped"]
+pub struct Guard {
+    summary: Option<Summary>,
+    _chrome: Option<tracing_chrome::FlushGuard>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(summary) = &self.summary
+            && !summary.is_empty()
+        {
+            eprintln!("{summary}");
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Telemetry {
+    output: Output,
+    max_field_len: usize,
+    summary: bool,
+}
+
+impl Telemetry {
+    pub fn new(output: Output) -> Self {
+        Self {
+            output,
+            max_field_len: MAX_FIELD_LEN,
+            summary: true,
+        }
+    }
+
+    /// output of $AOC_TRACE, fmt if it is not set or invalid
+    pub fn from_env() -> Self {
+        let output = std::env::var(TRACE_VAR)
+            .ok()
+            .and_then(|s| {
+                s.parse()
+                    .inspect_err(|e| eprintln!("{TRACE_VAR}: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self::new(output)
+    }
+
+    /// usize::MAX records every field in full
+    pub fn with_max_field_len(mut self, max_field_len: usize) -> Self {
+        self.max_field_len = max_field_len;
+        self
+    }
+
+    pub fn with_summary(mut self, summary: bool) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Install the global subscriber. Only the first call of a process installs one.
+    pub fn init(self) -> Guard {
+        let filter = match (std::env::var_os(EnvFilter::DEFAULT_ENV), &self.output) {
+            (None, Output::Chrome(_) | Output::Tracy) => EnvFilter::new("info"),
+            _ => EnvFilter::from_default_env(),
+        };
+        let fields = ShortFields {
+            max_len: self.max_field_len,
+            json: self.output == Output::Json,
+        };
+
+        let summary = (self.summary && self.output != Output::Off).then(Summary::default);
+
+        let mut layers: Vec<Box<dyn Layer<Registry> + Send + Sync>> = Vec::new();
+        let mut chrome = None;
+        match self.output {
+            Output::Fmt => layers.push(
+                tracing_subscriber::fmt::layer()
+                    .with_writer(std::io::stderr)
+                    .fmt_fields(fields)
+                    .boxed(),
+            ),
+            Output::Json => layers.push(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_writer(std::io::stderr)
+                    .fmt_fields(fields)
+                    .boxed(),
+            ),
+            Output::Chrome(file) => {
+                let builder = tracing_chrome::ChromeLayerBuilder::new();
+                let (layer, guard) = match file {
+                    Some(file) => builder.file(file).build(),
+                    None => builder.build(),
+                };
+                layers.push(layer.boxed());
+                chrome = Some(guard);
+            }
+            #[cfg(feature = "tracy")]
+            Output::Tracy => layers.push(tracing_tracy::TracyLayer::default().boxed()),
+            #[cfg(not(feature = "tracy"))]
+            Output::Tracy => eprintln!("tracing to tracy needs the feature tracy of aoc_utils"),
+            Output::Off => {}
+        }
+        if let Some(summary) = &summary {
+            layers.push(summary.clone().boxed());
+        }
+
+        // a second init (e.g. in tests) keeps the first subscriber
+        let _ = tracing_subscriber::registry()
+            .with(layers.with_filter(filter))
+            .try_init();
+        Guard {
+            summary,
+            _chrome: chrome,
+        }
+    }
+}
+
+/// Telemetry as configured by $AOC_TRACE and $RUST_LOG
+pub fn init() -> Guard {
+    Telemetry::from_env().init()
+}
+
+/// Keeps the first max_len bytes of what is written, counts the rest
+struct Bounded {
+    text: String,
+    len: usize,
+    max_len: usize,
+}
+
+impl fmt::Write for Bounded {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.text.len() + s.len() <= self.max_len {
+            self.text.push_str(s);
+        }
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct ShortVisitor {
+    max_len: usize,
+    values: Vec<(&'static str, serde_json::Value)>,
+}
+
+impl ShortVisitor {
+    fn shorten(&self, value: fmt::Arguments) -> serde_json::Value {
+        let mut bounded = Bounded {
+            text: String::new(),
+            len: 0,
+            max_len: self.max_len,
+        };
+        let _ = bounded.write_fmt(value);
+        if bounded.len > self.max_len {
+            format!("<{} bytes>", bounded.len).into()
+        } else {
+            bounded.text.into()
+        }
+    }
+}
+
+impl Visit for ShortVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let value = self.shorten(format_args!("{value:?}"));
+        self.values.push((field.name(), value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let value = self.shorten(format_args!("{value}"));
+        self.values.push((field.name(), value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.values.push((field.name(), value.into()));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.values.push((field.name(), value.into()));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.values.push((field.name(), value.into()));
+    }
+}
+
+/// Field formatter of fmt and json which replaces long values by their length
+struct ShortFields {
+    max_len: usize,
+    json: bool,
+}
+
+impl ShortFields {
+    fn visit(&self, fields: impl RecordFields) -> Vec<(&'static str, serde_json::Value)> {
+        let mut visitor = ShortVisitor {
+            max_len: self.max_len,
+            ..Default::default()
+        };
+        fields.record(&mut visitor);
+        visitor.values
+    }
+}
+
+impl<'w> FormatFields<'w> for ShortFields {
+    fn format_fields<R: RecordFields>(&self, mut writer: Writer<'w>, fields: R) -> fmt::Result {
+        let values = self.visit(fields);
+        if self.json {
+            let object = values
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect::<serde_json::Map<_, _>>();
+            return write!(writer, "{}", serde_json::Value::Object(object));
+        }
+        let text = values
+            .into_iter()
+            .map(|(name, value)| match (name, value) {
+                ("message", serde_json::Value::String(s)) => s,
+                (name, serde_json::Value::String(s)) => format!("{name}={s}"),
+                (name, value) => format!("{name}={value}"),
+            })
+            .collect::<Vec<_>>();
+        write!(writer, "{}", text.join(" "))
+    }
+
+    fn add_fields(
+        &self,
+        current: &'w mut FormattedFields<Self>,
+        fields: &span::Record<'_>,
+    ) -> fmt::Result {
+        if !self.json || current.fields.is_empty() {
+            if !current.fields.is_empty() {
+                current.fields.push(' ');
+            }
+            return self.format_fields(current.as_writer(), fields);
+        }
+        // the json formatter parses the fields of a span as one object
+        let mut object =
+            serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&current.fields)
+                .unwrap_or_default();
+        object.extend(
+            self.visit(fields)
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v)),
+        );
+        current.fields = serde_json::Value::Object(object).to_string();
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpanStats {
+    pub calls: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+/// time of a span from its creation, in the extensions of the span
+struct Created(Instant);
+
+/// Layer which sums the time of every span by target and name
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    spans: Arc<Mutex<BTreeMap<(&'static str, &'static str), SpanStats>>>,
+}
+
+impl Summary {
+    pub fn is_empty(&self) -> bool {
+        self.stats().is_empty()
+    }
+
+    /// per target and span name
+    pub fn stats(&self) -> BTreeMap<(&'static str, &'static str), SpanStats> {
+        self.spans
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl<S> Layer<S> for Summary
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Created(Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(elapsed) = span.extensions().get::<Created>().map(|c| c.0.elapsed()) else {
+            return;
+        };
+        let mut spans = self.spans.lock().unwrap_or_else(PoisonError::into_inner);
+        let stats = spans
+            .entry((span.metadata().target(), span.name()))
+            .or_default();
+        stats.calls += 1;
+        stats.total += elapsed;
+        stats.max = stats.max.max(elapsed);
+    }
+}
+
+/// spans sorted by total time, longest first
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stats = self
+            .stats()
+            .into_iter()
+            .map(|((target, name), stats)| (format!("{target}::{name}"), stats))
+            .collect::<Vec<_>>();
+        stats.sort_by_key(|(_, s)| std::cmp::Reverse(s.total));
+
+        let width = stats.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        write!(
+            f,
+            "{:<width$} {:>8} {:>12} {:>12}",
+            "span", "calls", "total", "max"
+        )?;
+        for (name, s) in stats {
+            write!(
+                f,
+                "\n{name:<width$} {:>8} {:>12} {:>12}",
+                s.calls,
+                format!("{:.2?}", s.total),
+                format!("{:.2?}", s.max)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[rstest]
+    #[case("fmt", Ok(Output::Fmt))]
+    #[case("json", Ok(Output::Json))]
+    #[case("chrome", Ok(Output::Chrome(None)))]
+    #[case("chrome:out/trace.json", Ok(Output::Chrome(Some("out/trace.json".into()))))]
+    #[case("tracy", Ok(Output::Tracy))]
+    #[case("off", Ok(Output::Off))]
+    fn output_should_parse(#[case] input: &str, #[case] expected: Result<Output, String>) {
+        assert_eq!(input.parse::<Output>(), expected);
+    }
+
+    #[rstest]
+    #[case("chrome:")]
+    #[case("flame")]
+    fn output_should_reject(#[case] input: &str) {
+        assert!(input.parse::<Output>().is_err());
+    }
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    impl Buffer {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[tracing::instrument]
+    fn part(input: &str, row: i64) -> usize {
+        tracing::info!("solving");
+        input.len()
+    }
+
+    #[rstest]
+    #[case(false, "part{input=<1001 bytes> row=10}")]
+    #[case(true, r#""span":{"input":"<1001 bytes>","row":10,"name":"part"}"#)]
+    fn short_fields_should_shorten_input(#[case] json: bool, #[case] expected: &str) {
+        let buffer = Buffer::default();
+        let fields = ShortFields {
+            max_len: MAX_FIELD_LEN,
+            json,
+        };
+        let layer = tracing_subscriber::fmt::layer()
+            .with_writer(buffer.clone())
+            .with_ansi(false);
+        let layer = if json {
+            layer.json().fmt_fields(fields).boxed()
+        } else {
+            layer.fmt_fields(fields).boxed()
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || part(&"x".repeat(1001), 10));
+
+        let sut = buffer.text();
+        assert!(sut.contains(expected), "{sut}");
+        assert!(sut.contains("solving"));
+        assert!(!sut.contains("xxxx"));
+    }
+
+    #[test]
+    fn summary_should_count_spans() {
+        let sut = Summary::default();
+        let subscriber = tracing_subscriber::registry().with(sut.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            part("abc", 1);
+            part("abc", 2);
+        });
+
+        let stats = sut.stats();
+        let part = stats[&("aoc_utils::telemetry::tests", "part")];
+        assert_eq!(part.calls, 2);
+        assert!(part.max <= part.total);
+        assert!(
+            sut.to_string()
+                .contains("aoc_utils::telemetry::tests::part        2")
+        );
+    }
+}
@@ -1,16 +1,22 @@
-use aoc_2024_22::*;
+use std::sync::LazyLock;
+
+use {{crate}}::*;
+
+/// the input of the day, read before the benches
+static INPUT: LazyLock<String> = LazyLock::new(|| aoc_utils::input::workspace({{year}}, {{day}}));
 
 fn main() {
+    LazyLock::force(&INPUT);
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
 fn part1() {
-    aoc_2024_22_a(divan::black_box(INPUT));
+    {{crate}}_a(divan::black_box(&INPUT));
 }
 
 #[divan::bench]
 fn part2() {
-    aoc_2024_22_b(divan::black_box(INPUT));
+    {{crate}}_b(divan::black_box(&INPUT));
 }
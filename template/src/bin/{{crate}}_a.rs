@@ -0,0 +1,13 @@
+// binary for perf tests etc.
+use {{crate}}::{{crate}}_a;
+
+#[tracing::instrument]
+fn main() {
+    let _telemetry = aoc_utils::telemetry::init();
+    let input = aoc_utils::input::workspace({{year}}, {{day}});
+
+    println!(
+        "Part A {}",
+        aoc_utils::heap::profile({{year}}, {{day}}, 'a', || {{crate}}_a(&input))
+    );
+}
@@ -1,12 +1,12 @@
 // #[allow(dead_code)]
-/* Find the task under https://adventofcode.com/9999/day/99
+/* Find the task under https://adventofcode.com/{{year}}/day/{{day}}
     Solution idea:
 
 */
 // use aoc_utils::grid::Grid;
 
 #[tracing::instrument]
-pub fn part_a(input: &str) -> usize {
+pub fn {{crate}}_a(input: &str) -> usize {
     // let grid = input.parse::<Grid<char>>().expect("valid grid");
     // let x =input.trim().lines().map(|l| .... )
     // for line in input.trim().lines() {
@@ -16,11 +16,18 @@ pub fn part_a(input: &str) -> usize {
 }
 
 #[tracing::instrument]
-pub fn part_b(_input: &str) -> usize {
+pub fn {{crate}}_b(_input: &str) -> usize {
     0
 }
 
-pub const INPUT: &str = include_str!("input.txt");
+aoc_utils::solution! {
+    year: {{year}},
+    day: {{day}},
+    title: "{{title}}",
+    // register the parts once they are solved
+    // a: {{crate}}_a,
+    // b: {{crate}}_b,
+}
 
 #[cfg(test)]
 mod tests {
@@ -28,24 +35,28 @@ mod tests {
 
     #[rstest]
     #[case(TEST_INPUT, 0)]
-    fn part_a_example(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(super::aoc_2016_01_a(input), expected);
+    fn {{crate}}_a_example(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(super::{{crate}}_a(input), expected);
     }
 
     #[test]
-    fn part_a() {
-        assert_eq!(super::part_a(super::INPUT), 0);
+    #[ignore = "needs the puzzle input"]
+    fn {{crate}}_a() {
+        let input = aoc_utils::input::workspace({{year}}, {{day}});
+        aoc_utils::ledger::assert_answer({{year}}, {{day}}, 'a', super::{{crate}}_a(&input));
     }
 
     #[rstest]
     #[case(TEST_INPUT, 0)]
-    fn part_b_example(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(super::aoc_2016_01_b(input), expected);
+    fn {{crate}}_b_example(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(super::{{crate}}_b(input), expected);
     }
 
     #[test]
-    fn part_b() {
-        assert_eq!(super::part_b(super::INPUT), 0);
+    #[ignore = "needs the puzzle input"]
+    fn {{crate}}_b() {
+        let input = aoc_utils::input::workspace({{year}}, {{day}});
+        aoc_utils::ledger::assert_answer({{year}}, {{day}}, 'b', super::{{crate}}_b(&input));
     }
 
     const TEST_INPUT: &str = "";
@@ -0,0 +1,109 @@
+use aoc_utils::solution::Registry;
+
+/// Days of the playground, those of aoc_runner which build for wasm
+pub fn registry() -> Registry {
+    #[allow(unused_mut)]
+    let mut registry = aoc_utils::registry![
+        aoc_2016_01,
+        aoc_2016_02,
+        aoc_2016_03,
+        aoc_2016_04,
+        aoc_2016_05,
+        aoc_2016_06,
+        aoc_2016_07,
+        aoc_2016_08,
+        aoc_2016_09,
+        aoc_2016_10,
+        aoc_2016_11,
+        aoc_2020_01,
+        aoc_2020_02,
+        aoc_2021_01,
+        aoc_2021_12,
+        aoc_2021_13,
+        aoc_2021_14,
+        aoc_2021_15,
+        aoc_2021_16,
+        aoc_2021_19,
+        aoc_2022_01,
+        aoc_2022_02,
+        aoc_2022_03,
+        aoc_2022_04,
+        aoc_2022_05,
+        aoc_2022_06,
+        aoc_2022_07,
+        aoc_2022_08,
+        aoc_2022_09,
+        aoc_2022_11,
+        aoc_2022_12,
+        aoc_2022_13,
+        aoc_2022_14,
+        aoc_2022_16,
+        aoc_2022_18,
+        aoc_2022_20,
+        aoc_2022_21,
+        aoc_2022_24,
+        aoc_2023_01,
+        aoc_2023_02,
+        aoc_2023_03,
+        aoc_2023_04,
+        aoc_2023_05,
+        aoc_2023_06,
+        aoc_2023_07,
+        aoc_2023_08,
+        aoc_2023_09,
+        aoc_2023_10,
+        aoc_2023_11,
+        aoc_2023_12,
+        aoc_2023_13,
+        aoc_2023_14,
+        aoc_2023_15,
+        aoc_2023_16,
+        aoc_2023_21,
+        aoc_2024_01,
+        aoc_2024_02,
+        aoc_2024_03,
+        aoc_2024_04,
+        aoc_2024_05,
+        aoc_2024_06,
+        aoc_2024_07,
+        aoc_2024_08,
+        aoc_2024_09,
+        aoc_2024_11,
+        aoc_2024_13,
+        aoc_2024_14,
+        aoc_2024_15,
+        aoc_2024_16,
+        aoc_2024_17,
+        aoc_2024_18,
+        aoc_2024_19,
+        aoc_2024_20,
+        aoc_2024_21,
+        aoc_2024_22,
+        aoc_2024_23,
+        aoc_2024_24,
+        aoc_2024_25,
+        aoc_2025_01,
+        aoc_2025_02,
+        aoc_2025_03,
+        aoc_2025_04,
+        aoc_2025_05,
+        aoc_2025_06,
+        aoc_2025_07,
+        aoc_2025_08,
+        aoc_2025_09,
+        aoc_2025_11,
+    ];
+    #[cfg(feature = "terminal")]
+    {
+        registry = registry.with(aoc_utils::registry![
+            aoc_2022_10,
+            aoc_2022_15,
+            aoc_2022_17,
+        ]);
+    }
+    #[cfg(feature = "ilp")]
+    {
+        registry = registry.with(aoc_utils::registry![aoc_2025_10]);
+    }
+    registry
+}
@@ -0,0 +1,76 @@
+/*! Browser playground, the solutions compiled to WebAssembly
+ *
+ * just www-build compiles this crate for wasm32-unknown-unknown with the wasm-release profile
+ * and writes the bindings of wasm-bindgen to www/pkg, just www-serve serves index.html.
+ * The page picks a day of days(), solve() answers a part for the pasted input and the
+ * page measures the time.
+ *
+ * aoc new registers a day in days.rs like in the runner. Days drawing on a terminal
+ * (crossterm) do not build for wasm, they are only registered with the feature terminal in
+ * a native build. 2025 10 is left out for a smaller bundle, --features ilp adds it.
+ * A panic aborts in the wasm-release profile, the page has to be reloaded after one.
+*/
+use aoc_utils::{Answer, Params};
+use wasm_bindgen::prelude::*;
+
+mod days;
+pub use days::registry;
+
+/// Answer of part 'a' or 'b' of a day, an error as the runner prints it
+#[wasm_bindgen]
+pub fn solve(year: u16, day: u8, part: char, input: &str) -> String {
+    let Some(solution) = registry().get(year, day) else {
+        return format!("error: no solution for {year} {day:02}");
+    };
+    let params = Params::new();
+    let answer = match part {
+        'a' => solution.part_a(input, &params),
+        'b' => solution.part_b(input, &params),
+        _ => return format!("error: invalid part {part}, expected a or b"),
+    };
+    match answer {
+        Ok(Answer::Ocr(pixels)) => pixels,
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e.render()),
+    }
+}
+
+/// JSON array of the days for the picker: [{"year":2024,"day":5,"title":"Print Queue"},..]
+#[wasm_bindgen]
+pub fn days() -> String {
+    let days = registry()
+        .iter()
+        .map(|s| serde_json::json!({ "year": s.year(), "day": s.day(), "title": s.title() }))
+        .collect::<Vec<_>>();
+    serde_json::Value::Array(days).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const AOC_2024_01: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[rstest]
+    #[case(2024, 1, 'a', AOC_2024_01, "11")]
+    #[case(2024, 1, 'b', AOC_2024_01, "31")]
+    #[case(2024, 1, 'c', AOC_2024_01, "error: invalid part c, expected a or b")]
+    #[case(1999, 1, 'a', "", "error: no solution for 1999 01")]
+    fn solve_should_answer(
+        #[case] year: u16,
+        #[case] day: u8,
+        #[case] part: char,
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(solve(year, day, part, input), expected);
+    }
+
+    #[test]
+    fn days_should_list_titles() {
+        let sut = days();
+        assert!(sut.starts_with("[{"));
+        assert!(sut.contains(r#"{"day":1,"title":"Historian Hysteria","year":2024}"#));
+    }
+}